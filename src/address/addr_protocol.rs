@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+const IFAPROT_UNSPEC: u8 = 0;
+const IFAPROT_KERNEL_LO: u8 = 1;
+const IFAPROT_KERNEL_RA: u8 = 2;
+const IFAPROT_KERNEL_LL: u8 = 3;
+
+/// Originator of the address stored in `AddressAttribute::Protocol`,
+/// values other than the kernel ones could be used by userspace daemons.
+#[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+#[non_exhaustive]
+pub enum AddressProtocol {
+    #[default]
+    Unspec,
+    /// Loopback address set by kernel
+    KernelLo,
+    /// Set by kernel from router announcement
+    KernelRa,
+    /// Link-local address set by kernel
+    KernelLl,
+    Other(u8),
+}
+
+impl From<u8> for AddressProtocol {
+    fn from(d: u8) -> Self {
+        match d {
+            IFAPROT_UNSPEC => Self::Unspec,
+            IFAPROT_KERNEL_LO => Self::KernelLo,
+            IFAPROT_KERNEL_RA => Self::KernelRa,
+            IFAPROT_KERNEL_LL => Self::KernelLl,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<AddressProtocol> for u8 {
+    fn from(v: AddressProtocol) -> u8 {
+        match v {
+            AddressProtocol::Unspec => IFAPROT_UNSPEC,
+            AddressProtocol::KernelLo => IFAPROT_KERNEL_LO,
+            AddressProtocol::KernelRa => IFAPROT_KERNEL_RA,
+            AddressProtocol::KernelLl => IFAPROT_KERNEL_LL,
+            AddressProtocol::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for AddressProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unspec => write!(f, "unspec"),
+            Self::KernelLo => write!(f, "kernel_lo"),
+            Self::KernelRa => write!(f, "kernel_ra"),
+            Self::KernelLl => write!(f, "kernel_ll"),
+            Self::Other(d) => write!(f, "{d}"),
+        }
+    }
+}
@@ -7,11 +7,13 @@ use anyhow::Context;
 use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
-    parsers::{parse_string, parse_u32},
+    parsers::{parse_i32, parse_string, parse_u32, parse_u8},
     DecodeError, Emitable, Parseable,
 };
 
-use crate::address::{AddressFlag, AddressFlags, CacheInfo, CacheInfoBuffer};
+use crate::address::{
+    AddressFlag, AddressFlags, AddressProtocol, CacheInfo, CacheInfoBuffer,
+};
 
 const IFA_ADDRESS: u16 = 1;
 const IFA_LOCAL: u16 = 2;
@@ -21,10 +23,9 @@ const IFA_ANYCAST: u16 = 5;
 const IFA_CACHEINFO: u16 = 6;
 const IFA_MULTICAST: u16 = 7;
 const IFA_FLAGS: u16 = 8;
-// TODO(Gris Ge)
-// const IFA_RT_PRIORITY: u16 = 9;
-// const IFA_TARGET_NETNSID: u16 = 10,
-// const IFA_PROTO: u16 = 11;
+const IFA_RT_PRIORITY: u16 = 9;
+const IFA_TARGET_NETNSID: u16 = 10;
+const IFA_PROTO: u16 = 11;
 
 // 32 bites
 const IPV4_ADDR_LEN: usize = 4;
@@ -45,6 +46,12 @@ pub enum AddressAttribute {
     /// IPv6 only
     Multicast(Ipv6Addr),
     Flags(Vec<AddressFlag>),
+    /// Metric of the prefix route created for this address
+    RtPriority(u32),
+    /// Request addresses of the network namespace with this NSID when
+    /// dumping
+    TargetNetnsId(i32),
+    Protocol(AddressProtocol),
     Other(DefaultNla),
 }
 
@@ -62,7 +69,10 @@ impl Nla for AddressAttribute {
             }
             Self::Label(ref string) => string.as_bytes().len() + 1,
 
-            Self::Flags(_) => size_of::<u32>(),
+            Self::Flags(_) | Self::RtPriority(_) | Self::TargetNetnsId(_) => {
+                size_of::<u32>()
+            }
+            Self::Protocol(_) => size_of::<u8>(),
 
             Self::CacheInfo(ref attr) => attr.buffer_len(),
 
@@ -88,6 +98,11 @@ impl Nla for AddressAttribute {
                 buffer,
                 u32::from(&AddressFlags(value.to_vec())),
             ),
+            Self::RtPriority(value) => NativeEndian::write_u32(buffer, value),
+            Self::TargetNetnsId(value) => {
+                NativeEndian::write_i32(buffer, value)
+            }
+            Self::Protocol(value) => buffer[0] = value.into(),
             Self::CacheInfo(ref attr) => attr.emit(buffer),
             Self::Other(ref attr) => attr.emit_value(buffer),
         }
@@ -103,6 +118,9 @@ impl Nla for AddressAttribute {
             Self::CacheInfo(_) => IFA_CACHEINFO,
             Self::Multicast(_) => IFA_MULTICAST,
             Self::Flags(_) => IFA_FLAGS,
+            Self::RtPriority(_) => IFA_RT_PRIORITY,
+            Self::TargetNetnsId(_) => IFA_TARGET_NETNSID,
+            Self::Protocol(_) => IFA_PROTO,
             Self::Other(ref nla) => nla.kind(),
         }
     }
@@ -200,6 +218,16 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                 )
                 .0,
             ),
+            IFA_RT_PRIORITY => Self::RtPriority(
+                parse_u32(payload).context("invalid IFA_RT_PRIORITY value")?,
+            ),
+            IFA_TARGET_NETNSID => Self::TargetNetnsId(
+                parse_i32(payload)
+                    .context("invalid IFA_TARGET_NETNSID value")?,
+            ),
+            IFA_PROTO => Self::Protocol(
+                parse_u8(payload).context("invalid IFA_PROTO value")?.into(),
+            ),
             kind => Self::Other(
                 DefaultNla::parse(buf)
                     .context(format!("unknown NLA type {kind}"))?,
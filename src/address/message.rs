@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::IpAddr;
+
 use anyhow::Context;
 use netlink_packet_utils::{
     nla::{NlaBuffer, NlasIterator},
@@ -11,7 +13,7 @@ use crate::{
     address::{
         AddressAttribute, AddressHeaderFlag, AddressHeaderFlags, AddressScope,
     },
-    AddressFamily,
+    AddressFamily, IpPrefix,
 };
 
 const ADDRESS_HEADER_LEN: usize = 8;
@@ -64,6 +66,47 @@ impl Emitable for AddressHeader {
     }
 }
 
+impl AddressMessage {
+    /// Network prefix of `AddressAttribute::Address` with
+    /// `AddressHeader::prefix_len`, e.g. `192.0.2.0/24` for address
+    /// `192.0.2.1/24`. For IPv4 point-to-point address, this is the prefix of
+    /// peer address.
+    pub fn address_prefix(&self) -> Option<IpPrefix> {
+        self.attributes.iter().find_map(|attr| {
+            if let AddressAttribute::Address(v) = attr {
+                IpPrefix::new_truncate(*v, self.header.prefix_len).ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Set address family, `AddressHeader::prefix_len` and
+    /// `AddressAttribute::Address`. For IPv4, `AddressAttribute::Local` is
+    /// also set to the same address like `ip addr add` does.
+    pub fn set_address(
+        &mut self,
+        address: IpAddr,
+        prefix_len: u8,
+    ) -> Result<(), DecodeError> {
+        // Validate prefix length only
+        IpPrefix::new_truncate(address, prefix_len)?;
+        self.header.family = AddressFamily::from(&address);
+        self.header.prefix_len = prefix_len;
+        self.attributes.retain(|attr| {
+            !matches!(
+                attr,
+                AddressAttribute::Address(_) | AddressAttribute::Local(_)
+            )
+        });
+        if address.is_ipv4() {
+            self.attributes.push(AddressAttribute::Local(address));
+        }
+        self.attributes.push(AddressAttribute::Address(address));
+        Ok(())
+    }
+}
+
 impl Emitable for AddressMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.attributes.as_slice().buffer_len()
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 mod addr_flags;
+mod addr_protocol;
 mod addr_scope;
 mod attribute;
 mod cache_info;
@@ -12,6 +13,7 @@ mod tests;
 pub use self::addr_flags::{
     AddressFlag, AddressFlags, AddressHeaderFlag, AddressHeaderFlags,
 };
+pub use self::addr_protocol::AddressProtocol;
 pub use self::addr_scope::AddressScope;
 pub use self::attribute::AddressAttribute;
 pub use self::cache_info::{CacheInfo, CacheInfoBuffer};
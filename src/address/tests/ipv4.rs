@@ -8,7 +8,7 @@ use crate::address::{
     AddressAttribute, AddressFlag, AddressHeader, AddressHeaderFlag,
     AddressMessage, AddressMessageBuffer, AddressScope, CacheInfo,
 };
-use crate::AddressFamily;
+use crate::{AddressFamily, IpPrefix};
 
 // TODO(Gris Ge): Need test for `AddressAttribute::Broadcast`
 
@@ -55,3 +55,28 @@ fn test_ipv4_get_loopback_address() {
 
     assert_eq!(buf, raw);
 }
+
+#[test]
+fn test_ipv4_address_prefix() {
+    let mut message = AddressMessage::default();
+    message
+        .set_address(Ipv4Addr::new(192, 0, 2, 1).into(), 24)
+        .unwrap();
+
+    assert_eq!(message.header.family, AddressFamily::Inet);
+    assert_eq!(message.header.prefix_len, 24);
+    assert_eq!(
+        message.attributes,
+        vec![
+            AddressAttribute::Local(Ipv4Addr::new(192, 0, 2, 1).into()),
+            AddressAttribute::Address(Ipv4Addr::new(192, 0, 2, 1).into()),
+        ]
+    );
+    assert_eq!(
+        message.address_prefix(),
+        Some(IpPrefix::new(Ipv4Addr::new(192, 0, 2, 0).into(), 24).unwrap())
+    );
+    assert!(message
+        .set_address(Ipv4Addr::new(192, 0, 2, 1).into(), 33)
+        .is_err());
+}
@@ -2,11 +2,15 @@
 
 use std::net::{IpAddr, Ipv6Addr};
 
-use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    Emitable, Parseable,
+};
 
 use crate::address::{
     AddressAttribute, AddressFlag, AddressHeader, AddressHeaderFlag,
-    AddressMessage, AddressMessageBuffer, AddressScope, CacheInfo,
+    AddressMessage, AddressMessageBuffer, AddressProtocol, AddressScope,
+    CacheInfo,
 };
 use crate::AddressFamily;
 
@@ -80,3 +84,82 @@ fn test_get_loopback_ipv6_addr() {
 
     assert_eq!(buf, raw);
 }
+
+// Setup:
+//      ip -6 addr add fe80::1/64 dev eth1 proto kernel_ll metric 1024
+// Equivalent to `RTM_NEWADDR` dumped by command:
+//      ip -6 addr show dev eth1
+#[test]
+fn test_ipv6_addr_rt_priority_and_proto() {
+    let raw = vec![
+        0x0a, 0x40, 0x80, 0xfd, 0x02, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00,
+        0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x09, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x14, 0x00, 0x06, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x2c, 0x01, 0x00, 0x00, 0x2c, 0x01, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00,
+        0x80, 0x00, 0x00, 0x00, 0x05, 0x00, 0x0b, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = AddressMessage {
+        header: AddressHeader {
+            family: AddressFamily::Inet6,
+            prefix_len: 64,
+            flags: vec![AddressHeaderFlag::Permanent],
+            scope: AddressScope::Link,
+            index: 2,
+        },
+        attributes: vec![
+            AddressAttribute::Address(IpAddr::V6(Ipv6Addr::new(
+                0xfe80, 0, 0, 0, 0, 0, 0, 1,
+            ))),
+            AddressAttribute::RtPriority(1024),
+            AddressAttribute::CacheInfo(CacheInfo {
+                ifa_preferred: u32::MAX,
+                ifa_valid: u32::MAX,
+                cstamp: 300,
+                tstamp: 300,
+            }),
+            AddressAttribute::Flags(vec![AddressFlag::Permanent]),
+            AddressAttribute::Protocol(AddressProtocol::KernelLl),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        AddressMessage::parse(&AddressMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_addr_target_netnsid_and_user_proto() {
+    let raw: [u8; 16] = [
+        0x08, 0x00, // length 8
+        0x0a, 0x00, // IFA_TARGET_NETNSID
+        0x05, 0x00, 0x00, 0x00, // nsid 5
+        0x05, 0x00, // length 5
+        0x0b, 0x00, // IFA_PROTO
+        0x63, 0x00, 0x00, 0x00, // user defined 99 with padding
+    ];
+    let expected = vec![
+        AddressAttribute::TargetNetnsId(5),
+        AddressAttribute::Protocol(AddressProtocol::Other(99)),
+    ];
+
+    let parsed: Vec<AddressAttribute> = NlasIterator::new(&raw)
+        .map(|nla| AddressAttribute::parse(&nla.unwrap()).unwrap())
+        .collect();
+    assert_eq!(parsed, expected);
+
+    let mut buffer = [0u8; 16];
+    expected.as_slice().emit(&mut buffer);
+    assert_eq!(buffer, raw);
+
+    assert_eq!(AddressProtocol::KernelRa.to_string(), "kernel_ra");
+    assert_eq!(u8::from(AddressProtocol::from(2)), 2);
+}
@@ -5,6 +5,9 @@ const AF_QIPCRTR: u8 = 42;
 const AF_SMC: u8 = 43;
 const AF_XDP: u8 = 44;
 const AF_MCTP: u8 = 45;
+// Not socket address family, used by rtnetlink for multicast routes
+const RTNL_FAMILY_IPMR: u8 = 128;
+const RTNL_FAMILY_IP6MR: u8 = 129;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
 #[non_exhaustive]
@@ -58,6 +61,10 @@ pub enum AddressFamily {
     Smc,
     Xdp,
     Mctp,
+    /// `RTNL_FAMILY_IPMR`, IPv4 multicast forwarding cache routes
+    Ipmr,
+    /// `RTNL_FAMILY_IP6MR`, IPv6 multicast forwarding cache routes
+    Ip6mr,
     Other(u8),
 }
 
@@ -112,6 +119,8 @@ impl From<u8> for AddressFamily {
             d if d == AF_SMC => Self::Smc,
             d if d == AF_XDP => Self::Xdp,
             d if d == AF_MCTP => Self::Mctp,
+            d if d == RTNL_FAMILY_IPMR => Self::Ipmr,
+            d if d == RTNL_FAMILY_IP6MR => Self::Ip6mr,
             _ => Self::Other(d),
         }
     }
@@ -168,6 +177,8 @@ impl From<AddressFamily> for u8 {
             AddressFamily::Smc => AF_SMC,
             AddressFamily::Xdp => AF_XDP,
             AddressFamily::Mctp => AF_MCTP,
+            AddressFamily::Ipmr => RTNL_FAMILY_IPMR,
+            AddressFamily::Ip6mr => RTNL_FAMILY_IP6MR,
             AddressFamily::Other(d) => d,
         }
     }
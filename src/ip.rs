@@ -4,6 +4,8 @@ use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use netlink_packet_utils::DecodeError;
 
+use crate::AddressFamily;
+
 pub(crate) const IPV4_ADDR_LEN: usize = 4;
 pub(crate) const IPV6_ADDR_LEN: usize = 16;
 
@@ -72,6 +74,150 @@ pub(crate) fn emit_ip_addr(addr: &IpAddr, buffer: &mut [u8]) {
     }
 }
 
+/// IPv4 or IPv6 network prefix, e.g. `192.0.2.0/24`, with all host bits
+/// zeroed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct IpPrefix {
+    address: IpAddr,
+    prefix_length: u8,
+}
+
+impl IpPrefix {
+    /// Return error if prefix length is too big for the address family or
+    /// any host bit is set.
+    pub fn new(
+        address: IpAddr,
+        prefix_length: u8,
+    ) -> Result<Self, DecodeError> {
+        let prefix = Self::new_truncate(address, prefix_length)?;
+        if prefix.address != address {
+            return Err(format!(
+                "Invalid prefix {address}/{prefix_length}, host bits should \
+                be zero, expecting {prefix}"
+            )
+            .into());
+        }
+        Ok(prefix)
+    }
+
+    /// Zero the host bits of specified address, e.g. the prefix of
+    /// address `192.0.2.1/24` is `192.0.2.0/24`.
+    pub fn new_truncate(
+        address: IpAddr,
+        prefix_length: u8,
+    ) -> Result<Self, DecodeError> {
+        let max_prefix_length = max_prefix_length(&address);
+        if prefix_length > max_prefix_length {
+            return Err(format!(
+                "Invalid prefix length {prefix_length} for {address}, \
+                should be in range of 0-{max_prefix_length}"
+            )
+            .into());
+        }
+        let address = match address {
+            IpAddr::V4(v) => IpAddr::V4(Ipv4Addr::from(
+                u32::from(v)
+                    & !u32::MAX.checked_shr(prefix_length.into()).unwrap_or(0),
+            )),
+            IpAddr::V6(v) => IpAddr::V6(Ipv6Addr::from(
+                u128::from(v)
+                    & !u128::MAX.checked_shr(prefix_length.into()).unwrap_or(0),
+            )),
+        };
+        Ok(Self {
+            address,
+            prefix_length,
+        })
+    }
+
+    /// Default route `0.0.0.0/0` or `::/0`
+    pub fn default_route(ipv6: bool) -> Self {
+        Self {
+            address: if ipv6 {
+                Ipv6Addr::UNSPECIFIED.into()
+            } else {
+                Ipv4Addr::UNSPECIFIED.into()
+            },
+            prefix_length: 0,
+        }
+    }
+
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    pub fn prefix_length(&self) -> u8 {
+        self.prefix_length
+    }
+
+    pub fn is_ipv4(&self) -> bool {
+        self.address.is_ipv4()
+    }
+
+    pub fn is_ipv6(&self) -> bool {
+        self.address.is_ipv6()
+    }
+
+    /// `AddressFamily::Inet` or `AddressFamily::Inet6`
+    pub fn address_family(&self) -> AddressFamily {
+        AddressFamily::from(&self.address)
+    }
+
+    /// Whether specified address is inside of this prefix. Always false
+    /// for address in different address family.
+    pub fn contains_addr(&self, address: &IpAddr) -> bool {
+        if address.is_ipv4() != self.is_ipv4() {
+            return false;
+        }
+        Self::new_truncate(*address, self.prefix_length)
+            .map(|p| p.address == self.address)
+            .unwrap_or_default()
+    }
+
+    /// Whether specified prefix is a subnet of (or equal to) this prefix.
+    pub fn contains(&self, other: &IpPrefix) -> bool {
+        other.prefix_length >= self.prefix_length
+            && self.contains_addr(&other.address)
+    }
+}
+
+impl std::fmt::Display for IpPrefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_length)
+    }
+}
+
+impl std::str::FromStr for IpPrefix {
+    type Err = DecodeError;
+
+    /// Parse `address/prefix_length` string, the prefix length is optional
+    /// and default to host prefix(32 or 128).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_length) = match s.split_once('/') {
+            Some((address, prefix_length)) => (address, Some(prefix_length)),
+            None => (s, None),
+        };
+        let address = IpAddr::from_str(address).map_err(|e| {
+            DecodeError::from(format!("Invalid IP address in {s}: {e}"))
+        })?;
+        let prefix_length = match prefix_length {
+            Some(p) => u8::from_str(p).map_err(|e| {
+                DecodeError::from(format!("Invalid prefix length in {s}: {e}"))
+            })?,
+            None => max_prefix_length(&address),
+        };
+        Self::new(address, prefix_length)
+    }
+}
+
+pub(crate) fn max_prefix_length(address: &IpAddr) -> u8 {
+    if address.is_ipv4() {
+        32
+    } else {
+        128
+    }
+}
+
 // These is defined by Assigned Internet Protocol Numbers, no need to use libc
 // as they are supposed to identical between all operating system.
 const IPPROTO_HOPOPTS: i32 = 0;
@@ -209,3 +355,12 @@ impl From<IpProtocol> for i32 {
         }
     }
 }
+
+impl From<&IpAddr> for AddressFamily {
+    fn from(v: &IpAddr) -> Self {
+        match v {
+            IpAddr::V4(_) => AddressFamily::Inet,
+            IpAddr::V6(_) => AddressFamily::Inet6,
+        }
+    }
+}
@@ -38,7 +38,7 @@ mod address_family_fallback;
 )))]
 pub use self::address_family_fallback::AddressFamily;
 
-pub use self::ip::IpProtocol;
+pub use self::ip::{IpPrefix, IpProtocol};
 pub use self::message::{RouteNetlinkMessage, RouteNetlinkMessageBuffer};
 
 /// The `netlink-packet-route` crate is designed to abstract Netlink route
@@ -57,6 +57,7 @@ pub use self::message::{RouteNetlinkMessage, RouteNetlinkMessageBuffer};
 ///
 /// At the top level of this crate, we also provide:
 ///  * [AddressFamily]
+///  * [IpPrefix]
 ///
 /// Normally, you should use [`rtnetlink`][rtnetlink_url] instead of using this
 /// crate directly.
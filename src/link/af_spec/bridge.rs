@@ -5,20 +5,30 @@ use std::convert::TryFrom;
 use anyhow::Context;
 use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
-    nla::{self, DefaultNla, NlaBuffer},
-    parsers::parse_u16,
-    traits::Parseable,
+    nla::{self, DefaultNla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
+use super::{BridgeCfm, BridgeMrp};
+
 const IFLA_BRIDGE_FLAGS: u16 = 0;
+const IFLA_BRIDGE_MODE: u16 = 1;
 const IFLA_BRIDGE_VLAN_INFO: u16 = 2;
+const IFLA_BRIDGE_VLAN_TUNNEL_INFO: u16 = 3;
+const IFLA_BRIDGE_MRP: u16 = 4;
+const IFLA_BRIDGE_CFM: u16 = 5;
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 #[non_exhaustive]
 pub enum AfSpecBridge {
-    Flags(u16),
+    Flags(Vec<BridgeFlag>),
+    Mode(BridgeMode),
     VlanInfo(BridgeVlanInfo),
+    VlanTunnelInfo(Vec<BridgeVlanTunnelInfo>),
+    Mrp(Vec<BridgeMrp>),
+    Cfm(Vec<BridgeCfm>),
     Other(DefaultNla),
 }
 
@@ -27,7 +37,10 @@ impl nla::Nla for AfSpecBridge {
         use self::AfSpecBridge::*;
         match *self {
             VlanInfo(_) => 4,
-            Flags(_) => 2,
+            Flags(_) | Mode(_) => 2,
+            VlanTunnelInfo(ref nlas) => nlas.as_slice().buffer_len(),
+            Mrp(ref nlas) => nlas.as_slice().buffer_len(),
+            Cfm(ref nlas) => nlas.as_slice().buffer_len(),
             Other(ref nla) => nla.value_len(),
         }
     }
@@ -35,10 +48,17 @@ impl nla::Nla for AfSpecBridge {
     fn emit_value(&self, buffer: &mut [u8]) {
         use self::AfSpecBridge::*;
         match *self {
-            Flags(value) => NativeEndian::write_u16(buffer, value),
+            Flags(ref flags) => NativeEndian::write_u16(
+                buffer,
+                u16::from(&VecBridgeFlag(flags.to_vec())),
+            ),
+            Mode(value) => NativeEndian::write_u16(buffer, value.into()),
             VlanInfo(ref info) => {
                 buffer[..4].copy_from_slice(<[u8; 4]>::from(info).as_slice())
             }
+            VlanTunnelInfo(ref nlas) => nlas.as_slice().emit(buffer),
+            Mrp(ref nlas) => nlas.as_slice().emit(buffer),
+            Cfm(ref nlas) => nlas.as_slice().emit(buffer),
             Other(ref nla) => nla.emit_value(buffer),
         }
     }
@@ -47,7 +67,11 @@ impl nla::Nla for AfSpecBridge {
         use self::AfSpecBridge::*;
         match *self {
             Flags(_) => IFLA_BRIDGE_FLAGS,
+            Mode(_) => IFLA_BRIDGE_MODE,
             VlanInfo(_) => IFLA_BRIDGE_VLAN_INFO,
+            VlanTunnelInfo(_) => IFLA_BRIDGE_VLAN_TUNNEL_INFO,
+            Mrp(_) => IFLA_BRIDGE_MRP | NLA_F_NESTED,
+            Cfm(_) => IFLA_BRIDGE_CFM | NLA_F_NESTED,
             Other(ref nla) => nla.kind(),
         }
     }
@@ -64,9 +88,30 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecBridge {
                     .context("Invalid IFLA_BRIDGE_VLAN_INFO value")?,
             ),
             IFLA_BRIDGE_FLAGS => Flags(
+                VecBridgeFlag::from(
+                    parse_u16(payload)
+                        .context("invalid IFLA_BRIDGE_FLAGS value")?,
+                )
+                .0,
+            ),
+            IFLA_BRIDGE_MODE => Mode(
                 parse_u16(payload)
-                    .context("invalid IFLA_BRIDGE_FLAGS value")?,
+                    .context("invalid IFLA_BRIDGE_MODE value")?
+                    .into(),
             ),
+            IFLA_BRIDGE_VLAN_TUNNEL_INFO => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(format!(
+                        "invalid IFLA_BRIDGE_VLAN_TUNNEL_INFO value {:?}",
+                        payload
+                    ))?;
+                    nlas.push(BridgeVlanTunnelInfo::parse(nla)?);
+                }
+                VlanTunnelInfo(nlas)
+            }
+            IFLA_BRIDGE_MRP => Mrp(parse_nlas(payload, "IFLA_BRIDGE_MRP")?),
+            IFLA_BRIDGE_CFM => Cfm(parse_nlas(payload, "IFLA_BRIDGE_CFM")?),
             kind => Other(
                 DefaultNla::parse(buf)
                     .context(format!("Unknown NLA type {kind}"))?,
@@ -85,9 +130,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
         let mut nlas = vec![];
         let err = "Invalid AF_INET NLA for IFLA_AF_SPEC(AF_BRIDGE)";
-        for nla in
-            netlink_packet_utils::nla::NlasIterator::new(buf.into_inner())
-        {
+        for nla in NlasIterator::new(buf.into_inner()) {
             let nla = nla.context(err)?;
             nlas.push(AfSpecBridge::parse(&nla).context(err)?);
         }
@@ -95,13 +138,289 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
     }
 }
 
+// Parse nested NLAs of bridge `IFLA_AF_SPEC`, `name` is used in error
+// message.
+pub(super) fn parse_nlas<T>(
+    payload: &[u8],
+    name: &str,
+) -> Result<Vec<T>, DecodeError>
+where
+    T: for<'a> Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        let nla = nla.context(format!("invalid {name} value"))?;
+        nlas.push(T::parse(&nla).context(format!("invalid {name} value"))?);
+    }
+    Ok(nlas)
+}
+
+const BRIDGE_FLAGS_MASTER: u16 = 1;
+const BRIDGE_FLAGS_SELF: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeFlag {
+    /// Bridge command to/from master device
+    Master,
+    /// Bridge command to/from the lowerdev itself, e.g. offloaded to
+    /// hardware
+    LowerDev,
+    Other(u16),
+}
+
+impl From<u16> for BridgeFlag {
+    fn from(d: u16) -> Self {
+        match d {
+            BRIDGE_FLAGS_MASTER => Self::Master,
+            BRIDGE_FLAGS_SELF => Self::LowerDev,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeFlag> for u16 {
+    fn from(v: BridgeFlag) -> u16 {
+        match v {
+            BridgeFlag::Master => BRIDGE_FLAGS_MASTER,
+            BridgeFlag::LowerDev => BRIDGE_FLAGS_SELF,
+            BridgeFlag::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Master => write!(f, "master"),
+            Self::LowerDev => write!(f, "self"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const ALL_BRIDGE_FLAGS: [BridgeFlag; 2] =
+    [BridgeFlag::Master, BridgeFlag::LowerDev];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecBridgeFlag(Vec<BridgeFlag>);
+
+impl From<u16> for VecBridgeFlag {
+    fn from(d: u16) -> Self {
+        let mut got: u16 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_BRIDGE_FLAGS {
+            if (d & (u16::from(flag))) > 0 {
+                ret.push(flag);
+                got += u16::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(BridgeFlag::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecBridgeFlag> for u16 {
+    fn from(v: &VecBridgeFlag) -> u16 {
+        let mut d: u16 = 0;
+        for flag in &v.0 {
+            d |= u16::from(*flag);
+        }
+        d
+    }
+}
+
+const BRIDGE_MODE_VEB: u16 = 0;
+const BRIDGE_MODE_VEPA: u16 = 1;
+const BRIDGE_MODE_UNDEF: u16 = 0xFFFF;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeMode {
+    /// Virtual Ethernet Bridge
+    Veb,
+    /// Virtual Ethernet Port Aggregator
+    Vepa,
+    Undefined,
+    Other(u16),
+}
+
+impl From<u16> for BridgeMode {
+    fn from(d: u16) -> Self {
+        match d {
+            BRIDGE_MODE_VEB => Self::Veb,
+            BRIDGE_MODE_VEPA => Self::Vepa,
+            BRIDGE_MODE_UNDEF => Self::Undefined,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeMode> for u16 {
+    fn from(v: BridgeMode) -> u16 {
+        match v {
+            BridgeMode::Veb => BRIDGE_MODE_VEB,
+            BridgeMode::Vepa => BRIDGE_MODE_VEPA,
+            BridgeMode::Undefined => BRIDGE_MODE_UNDEF,
+            BridgeMode::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Veb => write!(f, "veb"),
+            Self::Vepa => write!(f, "vepa"),
+            Self::Undefined => write!(f, "undef"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
 #[non_exhaustive]
 pub struct BridgeVlanInfo {
+    /// Bitmap of `BridgeVlanInfoFlag`, use [BridgeVlanInfo::flag_list] and
+    /// [BridgeVlanInfo::set_flags] for typed access.
     pub flags: u16,
     pub vid: u16,
 }
 
+impl BridgeVlanInfo {
+    pub fn new(vid: u16, flags: &[BridgeVlanInfoFlag]) -> Self {
+        let mut ret = Self { flags: 0, vid };
+        ret.set_flags(flags);
+        ret
+    }
+
+    pub fn flag_list(&self) -> Vec<BridgeVlanInfoFlag> {
+        VecBridgeVlanInfoFlag::from(self.flags).0
+    }
+
+    pub fn set_flags(&mut self, flags: &[BridgeVlanInfoFlag]) {
+        self.flags = u16::from(&VecBridgeVlanInfoFlag(flags.to_vec()));
+    }
+
+    /// Compress VLANs into `BridgeVlanInfoFlag::RangeBegin` and
+    /// `BridgeVlanInfoFlag::RangeEnd` pairs to reduce the netlink message
+    /// size. The input is sorted by VLAN ID, duplicate entries are removed
+    /// and any existing range flags are ignored. Consecutive VLANs with
+    /// identical flags are merged into a range, except VLAN holding
+    /// `BridgeVlanInfoFlag::Pvid` which kernel refuses to be in a range.
+    /// Error out if the same VLAN ID is listed with different flags.
+    pub fn compress(
+        vlans: &[BridgeVlanInfo],
+    ) -> Result<Vec<BridgeVlanInfo>, DecodeError> {
+        let mut vlans: Vec<(u16, u16)> = vlans
+            .iter()
+            .map(|v| (v.vid, v.flags & !BRIDGE_VLAN_INFO_RANGE_MASK))
+            .collect();
+        vlans.sort_by_key(|(vid, _)| *vid);
+        if let Some(w) = vlans
+            .windows(2)
+            .find(|w| w[0].0 == w[1].0 && w[0].1 != w[1].1)
+        {
+            return Err(format!(
+                "VLAN {} is listed with conflicting flags {:?} and {:?}",
+                w[0].0,
+                VecBridgeVlanInfoFlag::from(w[0].1).0,
+                VecBridgeVlanInfoFlag::from(w[1].1).0,
+            )
+            .into());
+        }
+        vlans.dedup();
+
+        let mut ret = Vec::new();
+        let mut iter = vlans.into_iter().peekable();
+        while let Some((start, flags)) = iter.next() {
+            let mut end = start;
+            if flags & BRIDGE_VLAN_INFO_PVID == 0 {
+                while let Some((vid, _)) = iter.next_if(|(vid, f)| {
+                    Some(*vid) == end.checked_add(1) && *f == flags
+                }) {
+                    end = vid;
+                }
+            }
+            if start == end {
+                ret.push(Self { flags, vid: start });
+            } else {
+                ret.push(Self {
+                    flags: flags | BRIDGE_VLAN_INFO_RANGE_BEGIN,
+                    vid: start,
+                });
+                ret.push(Self {
+                    flags: flags | BRIDGE_VLAN_INFO_RANGE_END,
+                    vid: end,
+                });
+            }
+        }
+        Ok(ret)
+    }
+
+    /// Expand `BridgeVlanInfoFlag::RangeBegin` and
+    /// `BridgeVlanInfoFlag::RangeEnd` pairs into one entry per VLAN. The
+    /// range flags are removed from the output.
+    pub fn expand(
+        vlans: &[BridgeVlanInfo],
+    ) -> Result<Vec<BridgeVlanInfo>, DecodeError> {
+        let mut ret = Vec::new();
+        let mut range_begin: Option<&BridgeVlanInfo> = None;
+        for vlan in vlans {
+            if vlan.flags & BRIDGE_VLAN_INFO_RANGE_BEGIN > 0 {
+                if range_begin.is_some() {
+                    return Err(format!(
+                        "BRIDGE_VLAN_INFO_RANGE_BEGIN of VLAN {} is not \
+                        followed by BRIDGE_VLAN_INFO_RANGE_END",
+                        vlan.vid
+                    )
+                    .into());
+                }
+                range_begin = Some(vlan);
+            } else if vlan.flags & BRIDGE_VLAN_INFO_RANGE_END > 0 {
+                let begin = range_begin.take().ok_or_else(|| {
+                    DecodeError::from(format!(
+                        "BRIDGE_VLAN_INFO_RANGE_END of VLAN {} is not \
+                        preceded by BRIDGE_VLAN_INFO_RANGE_BEGIN",
+                        vlan.vid
+                    ))
+                })?;
+                if begin.vid >= vlan.vid {
+                    return Err(format!(
+                        "Invalid VLAN range {}-{}",
+                        begin.vid, vlan.vid
+                    )
+                    .into());
+                }
+                let flags = begin.flags & !BRIDGE_VLAN_INFO_RANGE_MASK;
+                for vid in begin.vid..=vlan.vid {
+                    ret.push(Self { flags, vid });
+                }
+            } else if let Some(begin) = range_begin {
+                return Err(format!(
+                    "BRIDGE_VLAN_INFO_RANGE_BEGIN of VLAN {} is not \
+                    followed by BRIDGE_VLAN_INFO_RANGE_END",
+                    begin.vid
+                )
+                .into());
+            } else {
+                ret.push(*vlan);
+            }
+        }
+        if let Some(begin) = range_begin {
+            return Err(format!(
+                "BRIDGE_VLAN_INFO_RANGE_BEGIN of VLAN {} is not \
+                followed by BRIDGE_VLAN_INFO_RANGE_END",
+                begin.vid
+            )
+            .into());
+        }
+        Ok(ret)
+    }
+}
+
 impl From<&BridgeVlanInfo> for [u8; 4] {
     fn from(d: &BridgeVlanInfo) -> Self {
         let mut ret = [0u8; 4];
@@ -131,3 +450,180 @@ impl TryFrom<&[u8]> for BridgeVlanInfo {
         }
     }
 }
+
+const BRIDGE_VLAN_INFO_MASTER: u16 = 1 << 0;
+const BRIDGE_VLAN_INFO_PVID: u16 = 1 << 1;
+const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 1 << 2;
+const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 1 << 3;
+const BRIDGE_VLAN_INFO_RANGE_END: u16 = 1 << 4;
+const BRIDGE_VLAN_INFO_BRENTRY: u16 = 1 << 5;
+const BRIDGE_VLAN_INFO_ONLINK: u16 = 1 << 6;
+
+const BRIDGE_VLAN_INFO_RANGE_MASK: u16 =
+    BRIDGE_VLAN_INFO_RANGE_BEGIN | BRIDGE_VLAN_INFO_RANGE_END;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeVlanInfoFlag {
+    /// Operate on bridge device as well
+    Master,
+    /// VLAN is PVID, ingress untagged
+    Pvid,
+    /// VLAN egresses untagged
+    Untagged,
+    /// VLAN is start of VLAN range
+    RangeBegin,
+    /// VLAN is end of VLAN range
+    RangeEnd,
+    /// Global bridge VLAN entry
+    Brentry,
+    /// Skip create/delete/flags
+    Onlink,
+    Other(u16),
+}
+
+impl From<BridgeVlanInfoFlag> for u16 {
+    fn from(v: BridgeVlanInfoFlag) -> u16 {
+        match v {
+            BridgeVlanInfoFlag::Master => BRIDGE_VLAN_INFO_MASTER,
+            BridgeVlanInfoFlag::Pvid => BRIDGE_VLAN_INFO_PVID,
+            BridgeVlanInfoFlag::Untagged => BRIDGE_VLAN_INFO_UNTAGGED,
+            BridgeVlanInfoFlag::RangeBegin => BRIDGE_VLAN_INFO_RANGE_BEGIN,
+            BridgeVlanInfoFlag::RangeEnd => BRIDGE_VLAN_INFO_RANGE_END,
+            BridgeVlanInfoFlag::Brentry => BRIDGE_VLAN_INFO_BRENTRY,
+            BridgeVlanInfoFlag::Onlink => BRIDGE_VLAN_INFO_ONLINK,
+            BridgeVlanInfoFlag::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeVlanInfoFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Master => write!(f, "master"),
+            Self::Pvid => write!(f, "pvid"),
+            Self::Untagged => write!(f, "untagged"),
+            Self::RangeBegin => write!(f, "range_begin"),
+            Self::RangeEnd => write!(f, "range_end"),
+            Self::Brentry => write!(f, "brentry"),
+            Self::Onlink => write!(f, "onlink"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const ALL_BRIDGE_VLAN_INFO_FLAGS: [BridgeVlanInfoFlag; 7] = [
+    BridgeVlanInfoFlag::Master,
+    BridgeVlanInfoFlag::Pvid,
+    BridgeVlanInfoFlag::Untagged,
+    BridgeVlanInfoFlag::RangeBegin,
+    BridgeVlanInfoFlag::RangeEnd,
+    BridgeVlanInfoFlag::Brentry,
+    BridgeVlanInfoFlag::Onlink,
+];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecBridgeVlanInfoFlag(Vec<BridgeVlanInfoFlag>);
+
+impl From<u16> for VecBridgeVlanInfoFlag {
+    fn from(d: u16) -> Self {
+        let mut got: u16 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_BRIDGE_VLAN_INFO_FLAGS {
+            if (d & (u16::from(flag))) > 0 {
+                ret.push(flag);
+                got += u16::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(BridgeVlanInfoFlag::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecBridgeVlanInfoFlag> for u16 {
+    fn from(v: &VecBridgeVlanInfoFlag) -> u16 {
+        let mut d: u16 = 0;
+        for flag in &v.0 {
+            d |= u16::from(*flag);
+        }
+        d
+    }
+}
+
+const IFLA_BRIDGE_VLAN_TUNNEL_ID: u16 = 1;
+const IFLA_BRIDGE_VLAN_TUNNEL_VID: u16 = 2;
+const IFLA_BRIDGE_VLAN_TUNNEL_FLAGS: u16 = 3;
+
+/// VLAN to tunnel ID(e.g. VxLAN VNI) mapping of bridge port.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanTunnelInfo {
+    Id(u32),
+    Vid(u16),
+    /// Only `BridgeVlanInfoFlag::RangeBegin` and
+    /// `BridgeVlanInfoFlag::RangeEnd` are used by kernel.
+    Flags(Vec<BridgeVlanInfoFlag>),
+    Other(DefaultNla),
+}
+
+impl nla::Nla for BridgeVlanTunnelInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) => 4,
+            Self::Vid(_) | Self::Flags(_) => 2,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Vid(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Flags(flags) => NativeEndian::write_u16(
+                buffer,
+                u16::from(&VecBridgeVlanInfoFlag(flags.to_vec())),
+            ),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => IFLA_BRIDGE_VLAN_TUNNEL_ID,
+            Self::Vid(_) => IFLA_BRIDGE_VLAN_TUNNEL_VID,
+            Self::Flags(_) => IFLA_BRIDGE_VLAN_TUNNEL_FLAGS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanTunnelInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_VLAN_TUNNEL_ID => Self::Id(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_VLAN_TUNNEL_ID value")?,
+            ),
+            IFLA_BRIDGE_VLAN_TUNNEL_VID => Self::Vid(
+                parse_u16(payload)
+                    .context("invalid IFLA_BRIDGE_VLAN_TUNNEL_VID value")?,
+            ),
+            IFLA_BRIDGE_VLAN_TUNNEL_FLAGS => {
+                Self::Flags(
+                    VecBridgeVlanInfoFlag::from(parse_u16(payload).context(
+                        "invalid IFLA_BRIDGE_VLAN_TUNNEL_FLAGS value",
+                    )?)
+                    .0,
+                )
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_VLAN_TUNNEL_INFO"
+            ))?),
+        })
+    }
+}
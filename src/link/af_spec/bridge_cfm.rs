@@ -0,0 +1,1089 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NLA_F_NESTED},
+    parsers::{parse_mac, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::bridge::parse_nlas;
+
+const CFM_MAID_LENGTH: usize = 48;
+
+fn parse_maid(payload: &[u8]) -> Result<[u8; CFM_MAID_LENGTH], DecodeError> {
+    if payload.len() != CFM_MAID_LENGTH {
+        return Err(format!(
+            "invalid MAID, expecting {CFM_MAID_LENGTH} bytes, but got {}",
+            payload.len()
+        )
+        .into());
+    }
+    let mut maid = [0u8; CFM_MAID_LENGTH];
+    maid.copy_from_slice(payload);
+    Ok(maid)
+}
+
+const IFLA_BRIDGE_CFM_MEP_CREATE: u16 = 1;
+const IFLA_BRIDGE_CFM_MEP_DELETE: u16 = 2;
+const IFLA_BRIDGE_CFM_MEP_CONFIG: u16 = 3;
+const IFLA_BRIDGE_CFM_CC_CONFIG: u16 = 4;
+const IFLA_BRIDGE_CFM_CC_PEER_MEP_ADD: u16 = 5;
+const IFLA_BRIDGE_CFM_CC_PEER_MEP_REMOVE: u16 = 6;
+const IFLA_BRIDGE_CFM_CC_RDI: u16 = 7;
+const IFLA_BRIDGE_CFM_CC_CCM_TX: u16 = 8;
+const IFLA_BRIDGE_CFM_MEP_CREATE_INFO: u16 = 9;
+const IFLA_BRIDGE_CFM_MEP_CONFIG_INFO: u16 = 10;
+const IFLA_BRIDGE_CFM_CC_CONFIG_INFO: u16 = 11;
+const IFLA_BRIDGE_CFM_CC_RDI_INFO: u16 = 12;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_INFO: u16 = 13;
+const IFLA_BRIDGE_CFM_CC_PEER_MEP_INFO: u16 = 14;
+const IFLA_BRIDGE_CFM_MEP_STATUS_INFO: u16 = 15;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_INFO: u16 = 16;
+
+/// Connectivity Fault Management(IEEE 802.1Q) of bridge. The `*Info` variants are
+/// reported by kernel, others are used for changing configuration.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfm {
+    MepCreate(Vec<BridgeCfmMepCreate>),
+    MepDelete(Vec<BridgeCfmMepDelete>),
+    MepConfig(Vec<BridgeCfmMepConfig>),
+    CcConfig(Vec<BridgeCfmCcConfig>),
+    CcPeerMepAdd(Vec<BridgeCfmCcPeerMep>),
+    CcPeerMepRemove(Vec<BridgeCfmCcPeerMep>),
+    CcRdi(Vec<BridgeCfmCcRdi>),
+    CcCcmTx(Vec<BridgeCfmCcCcmTx>),
+    MepCreateInfo(Vec<BridgeCfmMepCreate>),
+    MepConfigInfo(Vec<BridgeCfmMepConfig>),
+    CcConfigInfo(Vec<BridgeCfmCcConfig>),
+    CcRdiInfo(Vec<BridgeCfmCcRdi>),
+    CcCcmTxInfo(Vec<BridgeCfmCcCcmTx>),
+    CcPeerMepInfo(Vec<BridgeCfmCcPeerMep>),
+    MepStatusInfo(Vec<BridgeCfmMepStatus>),
+    CcPeerStatusInfo(Vec<BridgeCfmCcPeerStatus>),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfm {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::MepCreate(nlas) | Self::MepCreateInfo(nlas) => {
+                nlas.as_slice().buffer_len()
+            }
+            Self::MepDelete(nlas) => nlas.as_slice().buffer_len(),
+            Self::MepConfig(nlas) | Self::MepConfigInfo(nlas) => {
+                nlas.as_slice().buffer_len()
+            }
+            Self::CcConfig(nlas) | Self::CcConfigInfo(nlas) => {
+                nlas.as_slice().buffer_len()
+            }
+            Self::CcPeerMepAdd(nlas)
+            | Self::CcPeerMepRemove(nlas)
+            | Self::CcPeerMepInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcRdi(nlas) | Self::CcRdiInfo(nlas) => {
+                nlas.as_slice().buffer_len()
+            }
+            Self::CcCcmTx(nlas) | Self::CcCcmTxInfo(nlas) => {
+                nlas.as_slice().buffer_len()
+            }
+            Self::MepStatusInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcPeerStatusInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::MepCreate(nlas) | Self::MepCreateInfo(nlas) => {
+                nlas.as_slice().emit(buffer)
+            }
+            Self::MepDelete(nlas) => nlas.as_slice().emit(buffer),
+            Self::MepConfig(nlas) | Self::MepConfigInfo(nlas) => {
+                nlas.as_slice().emit(buffer)
+            }
+            Self::CcConfig(nlas) | Self::CcConfigInfo(nlas) => {
+                nlas.as_slice().emit(buffer)
+            }
+            Self::CcPeerMepAdd(nlas)
+            | Self::CcPeerMepRemove(nlas)
+            | Self::CcPeerMepInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcRdi(nlas) | Self::CcRdiInfo(nlas) => {
+                nlas.as_slice().emit(buffer)
+            }
+            Self::CcCcmTx(nlas) | Self::CcCcmTxInfo(nlas) => {
+                nlas.as_slice().emit(buffer)
+            }
+            Self::MepStatusInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcPeerStatusInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::MepCreate(_) => IFLA_BRIDGE_CFM_MEP_CREATE | NLA_F_NESTED,
+            Self::MepDelete(_) => IFLA_BRIDGE_CFM_MEP_DELETE | NLA_F_NESTED,
+            Self::MepConfig(_) => IFLA_BRIDGE_CFM_MEP_CONFIG | NLA_F_NESTED,
+            Self::CcConfig(_) => IFLA_BRIDGE_CFM_CC_CONFIG | NLA_F_NESTED,
+            Self::CcPeerMepAdd(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_MEP_ADD | NLA_F_NESTED
+            }
+            Self::CcPeerMepRemove(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_MEP_REMOVE | NLA_F_NESTED
+            }
+            Self::CcRdi(_) => IFLA_BRIDGE_CFM_CC_RDI | NLA_F_NESTED,
+            Self::CcCcmTx(_) => IFLA_BRIDGE_CFM_CC_CCM_TX | NLA_F_NESTED,
+            Self::MepCreateInfo(_) => {
+                IFLA_BRIDGE_CFM_MEP_CREATE_INFO | NLA_F_NESTED
+            }
+            Self::MepConfigInfo(_) => {
+                IFLA_BRIDGE_CFM_MEP_CONFIG_INFO | NLA_F_NESTED
+            }
+            Self::CcConfigInfo(_) => {
+                IFLA_BRIDGE_CFM_CC_CONFIG_INFO | NLA_F_NESTED
+            }
+            Self::CcRdiInfo(_) => IFLA_BRIDGE_CFM_CC_RDI_INFO | NLA_F_NESTED,
+            Self::CcCcmTxInfo(_) => {
+                IFLA_BRIDGE_CFM_CC_CCM_TX_INFO | NLA_F_NESTED
+            }
+            Self::CcPeerMepInfo(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_MEP_INFO | NLA_F_NESTED
+            }
+            Self::MepStatusInfo(_) => {
+                IFLA_BRIDGE_CFM_MEP_STATUS_INFO | NLA_F_NESTED
+            }
+            Self::CcPeerStatusInfo(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_STATUS_INFO | NLA_F_NESTED
+            }
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BridgeCfm {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_CREATE => Self::MepCreate(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_MEP_CREATE",
+            )?),
+            IFLA_BRIDGE_CFM_MEP_DELETE => Self::MepDelete(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_MEP_DELETE",
+            )?),
+            IFLA_BRIDGE_CFM_MEP_CONFIG => Self::MepConfig(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_MEP_CONFIG",
+            )?),
+            IFLA_BRIDGE_CFM_CC_CONFIG => Self::CcConfig(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_CC_CONFIG",
+            )?),
+            IFLA_BRIDGE_CFM_CC_PEER_MEP_ADD => Self::CcPeerMepAdd(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_CC_PEER_MEP_ADD",
+            )?),
+            IFLA_BRIDGE_CFM_CC_PEER_MEP_REMOVE => Self::CcPeerMepRemove(
+                parse_nlas(payload, "IFLA_BRIDGE_CFM_CC_PEER_MEP_REMOVE")?,
+            ),
+            IFLA_BRIDGE_CFM_CC_RDI => {
+                Self::CcRdi(parse_nlas(payload, "IFLA_BRIDGE_CFM_CC_RDI")?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX => {
+                Self::CcCcmTx(parse_nlas(payload, "IFLA_BRIDGE_CFM_CC_CCM_TX")?)
+            }
+            IFLA_BRIDGE_CFM_MEP_CREATE_INFO => Self::MepCreateInfo(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_MEP_CREATE_INFO",
+            )?),
+            IFLA_BRIDGE_CFM_MEP_CONFIG_INFO => Self::MepConfigInfo(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_MEP_CONFIG_INFO",
+            )?),
+            IFLA_BRIDGE_CFM_CC_CONFIG_INFO => Self::CcConfigInfo(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_CC_CONFIG_INFO",
+            )?),
+            IFLA_BRIDGE_CFM_CC_RDI_INFO => Self::CcRdiInfo(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_CC_RDI_INFO",
+            )?),
+            IFLA_BRIDGE_CFM_CC_CCM_TX_INFO => Self::CcCcmTxInfo(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_CC_CCM_TX_INFO",
+            )?),
+            IFLA_BRIDGE_CFM_CC_PEER_MEP_INFO => Self::CcPeerMepInfo(
+                parse_nlas(payload, "IFLA_BRIDGE_CFM_CC_PEER_MEP_INFO")?,
+            ),
+            IFLA_BRIDGE_CFM_MEP_STATUS_INFO => Self::MepStatusInfo(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_CFM_MEP_STATUS_INFO",
+            )?),
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_INFO => Self::CcPeerStatusInfo(
+                parse_nlas(payload, "IFLA_BRIDGE_CFM_CC_PEER_STATUS_INFO")?,
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_CFM_MEP_CREATE_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_MEP_CREATE_DOMAIN: u16 = 2;
+const IFLA_BRIDGE_CFM_MEP_CREATE_DIRECTION: u16 = 3;
+const IFLA_BRIDGE_CFM_MEP_CREATE_IFINDEX: u16 = 4;
+
+/// Maintenance association End Point(MEP) creation
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfmMepCreate {
+    Instance(u32),
+    Domain(BridgeCfmDomain),
+    Direction(BridgeCfmMepDirection),
+    Ifindex(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmMepCreate {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_)
+            | Self::Domain(_)
+            | Self::Direction(_)
+            | Self::Ifindex(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(v) | Self::Ifindex(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Domain(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Direction(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_MEP_CREATE_INSTANCE,
+            Self::Domain(_) => IFLA_BRIDGE_CFM_MEP_CREATE_DOMAIN,
+            Self::Direction(_) => IFLA_BRIDGE_CFM_MEP_CREATE_DIRECTION,
+            Self::Ifindex(_) => IFLA_BRIDGE_CFM_MEP_CREATE_IFINDEX,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmMepCreate
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_CREATE_INSTANCE => {
+                Self::Instance(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_MEP_CREATE_INSTANCE value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_MEP_CREATE_DOMAIN => Self::Domain(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_CFM_MEP_CREATE_DOMAIN value")?
+                    .into(),
+            ),
+            IFLA_BRIDGE_CFM_MEP_CREATE_DIRECTION => Self::Direction(
+                parse_u32(payload)
+                    .context(
+                        "invalid IFLA_BRIDGE_CFM_MEP_CREATE_DIRECTION value",
+                    )?
+                    .into(),
+            ),
+            IFLA_BRIDGE_CFM_MEP_CREATE_IFINDEX => {
+                Self::Ifindex(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_MEP_CREATE_IFINDEX value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM_MEP_CREATE"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_CFM_MEP_DELETE_INSTANCE: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfmMepDelete {
+    Instance(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmMepDelete {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_MEP_DELETE_INSTANCE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmMepDelete
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_DELETE_INSTANCE => {
+                Self::Instance(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_MEP_DELETE_INSTANCE value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM_MEP_DELETE"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_CFM_MEP_CONFIG_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_MEP_CONFIG_UNICAST_MAC: u16 = 2;
+const IFLA_BRIDGE_CFM_MEP_CONFIG_MDLEVEL: u16 = 3;
+const IFLA_BRIDGE_CFM_MEP_CONFIG_MEPID: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfmMepConfig {
+    Instance(u32),
+    UnicastMac([u8; 6]),
+    /// Maintenance Domain level
+    Mdlevel(u32),
+    Mepid(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmMepConfig {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_) | Self::Mdlevel(_) | Self::Mepid(_) => 4,
+            Self::UnicastMac(_) => 6,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(v) | Self::Mdlevel(v) | Self::Mepid(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::UnicastMac(v) => buffer.copy_from_slice(v.as_slice()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_MEP_CONFIG_INSTANCE,
+            Self::UnicastMac(_) => IFLA_BRIDGE_CFM_MEP_CONFIG_UNICAST_MAC,
+            Self::Mdlevel(_) => IFLA_BRIDGE_CFM_MEP_CONFIG_MDLEVEL,
+            Self::Mepid(_) => IFLA_BRIDGE_CFM_MEP_CONFIG_MEPID,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmMepConfig
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_CONFIG_INSTANCE => {
+                Self::Instance(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_MEP_CONFIG_INSTANCE value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_MEP_CONFIG_UNICAST_MAC => {
+                Self::UnicastMac(parse_mac(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_MEP_CONFIG_UNICAST_MAC value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_MEP_CONFIG_MDLEVEL => {
+                Self::Mdlevel(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_MEP_CONFIG_MDLEVEL value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_MEP_CONFIG_MEPID => {
+                Self::Mepid(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_MEP_CONFIG_MEPID value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM_MEP_CONFIG"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_CFM_CC_CONFIG_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_CONFIG_ENABLE: u16 = 2;
+const IFLA_BRIDGE_CFM_CC_CONFIG_EXP_INTERVAL: u16 = 3;
+const IFLA_BRIDGE_CFM_CC_CONFIG_EXP_MAID: u16 = 4;
+
+/// Continuity Check(CC) configuration
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfmCcConfig {
+    Instance(u32),
+    Enable(u32),
+    ExpInterval(BridgeCfmCcmInterval),
+    /// Expected Maintenance Association Identifier
+    ExpMaid([u8; CFM_MAID_LENGTH]),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcConfig {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_) | Self::Enable(_) | Self::ExpInterval(_) => 4,
+            Self::ExpMaid(_) => CFM_MAID_LENGTH,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(v) | Self::Enable(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::ExpInterval(v) => {
+                NativeEndian::write_u32(buffer, (*v).into())
+            }
+            Self::ExpMaid(v) => buffer.copy_from_slice(v.as_slice()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_CONFIG_INSTANCE,
+            Self::Enable(_) => IFLA_BRIDGE_CFM_CC_CONFIG_ENABLE,
+            Self::ExpInterval(_) => IFLA_BRIDGE_CFM_CC_CONFIG_EXP_INTERVAL,
+            Self::ExpMaid(_) => IFLA_BRIDGE_CFM_CC_CONFIG_EXP_MAID,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcConfig
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_CONFIG_INSTANCE => {
+                Self::Instance(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CONFIG_INSTANCE value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_CC_CONFIG_ENABLE => {
+                Self::Enable(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CONFIG_ENABLE value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_CC_CONFIG_EXP_INTERVAL => Self::ExpInterval(
+                parse_u32(payload)
+                    .context(
+                        "invalid IFLA_BRIDGE_CFM_CC_CONFIG_EXP_INTERVAL value",
+                    )?
+                    .into(),
+            ),
+            IFLA_BRIDGE_CFM_CC_CONFIG_EXP_MAID => {
+                Self::ExpMaid(parse_maid(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CONFIG_EXP_MAID value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM_CC_CONFIG"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_CFM_CC_PEER_MEP_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_PEER_MEP_MEPID: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfmCcPeerMep {
+    Instance(u32),
+    Mepid(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcPeerMep {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_) | Self::Mepid(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(v) | Self::Mepid(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_PEER_MEP_INSTANCE,
+            Self::Mepid(_) => IFLA_BRIDGE_CFM_CC_PEER_MEP_MEPID,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcPeerMep
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_PEER_MEP_INSTANCE => {
+                Self::Instance(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_PEER_MEP_INSTANCE value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_MEP_MEPID => {
+                Self::Mepid(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_PEER_MEP_MEPID value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM_CC_PEER_MEP"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_CFM_CC_RDI_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_RDI_RDI: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfmCcRdi {
+    Instance(u32),
+    /// Remote Defect Indication
+    Rdi(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcRdi {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_) | Self::Rdi(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(v) | Self::Rdi(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_RDI_INSTANCE,
+            Self::Rdi(_) => IFLA_BRIDGE_CFM_CC_RDI_RDI,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcRdi
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_RDI_INSTANCE => Self::Instance(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_CFM_CC_RDI_INSTANCE value")?,
+            ),
+            IFLA_BRIDGE_CFM_CC_RDI_RDI => Self::Rdi(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_CFM_CC_RDI_RDI value")?,
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM_CC_RDI"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_CFM_CC_CCM_TX_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_DMAC: u16 = 2;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_SEQ_NO_UPDATE: u16 = 3;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_PERIOD: u16 = 4;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV: u16 = 5;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV_VALUE: u16 = 6;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV: u16 = 7;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV_VALUE: u16 = 8;
+
+/// Continuity Check Message(CCM) transmission
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfmCcCcmTx {
+    Instance(u32),
+    Dmac([u8; 6]),
+    SeqNoUpdate(u32),
+    /// Transmission period in seconds
+    Period(u32),
+    IfTlv(u32),
+    IfTlvValue(u8),
+    PortTlv(u32),
+    PortTlvValue(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcCcmTx {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_)
+            | Self::SeqNoUpdate(_)
+            | Self::Period(_)
+            | Self::IfTlv(_)
+            | Self::PortTlv(_) => 4,
+            Self::Dmac(_) => 6,
+            Self::IfTlvValue(_) | Self::PortTlvValue(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(v)
+            | Self::SeqNoUpdate(v)
+            | Self::Period(v)
+            | Self::IfTlv(v)
+            | Self::PortTlv(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Dmac(v) => buffer.copy_from_slice(v.as_slice()),
+            Self::IfTlvValue(v) | Self::PortTlvValue(v) => buffer[0] = *v,
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_INSTANCE,
+            Self::Dmac(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_DMAC,
+            Self::SeqNoUpdate(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_SEQ_NO_UPDATE,
+            Self::Period(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_PERIOD,
+            Self::IfTlv(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV,
+            Self::IfTlvValue(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV_VALUE,
+            Self::PortTlv(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV,
+            Self::PortTlvValue(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV_VALUE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcCcmTx
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_CCM_TX_INSTANCE => {
+                Self::Instance(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CCM_TX_INSTANCE value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_DMAC => Self::Dmac(
+                parse_mac(payload)
+                    .context("invalid IFLA_BRIDGE_CFM_CC_CCM_TX_DMAC value")?,
+            ),
+            IFLA_BRIDGE_CFM_CC_CCM_TX_SEQ_NO_UPDATE => {
+                Self::SeqNoUpdate(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CCM_TX_SEQ_NO_UPDATE value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_PERIOD => {
+                Self::Period(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CCM_TX_PERIOD value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV => {
+                Self::IfTlv(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV_VALUE => {
+                Self::IfTlvValue(parse_u8(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV_VALUE value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV => {
+                Self::PortTlv(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV value",
+                )?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV_VALUE => {
+                Self::PortTlvValue(parse_u8(payload).context(
+                    "invalid IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV_VALUE value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM_CC_CCM_TX"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_CFM_MEP_STATUS_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_MEP_STATUS_OPCODE_UNEXP_SEEN: u16 = 2;
+const IFLA_BRIDGE_CFM_MEP_STATUS_VERSION_UNEXP_SEEN: u16 = 3;
+const IFLA_BRIDGE_CFM_MEP_STATUS_RX_LEVEL_LOW_SEEN: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfmMepStatus {
+    Instance(u32),
+    OpcodeUnexpSeen(u32),
+    VersionUnexpSeen(u32),
+    RxLevelLowSeen(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmMepStatus {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_)
+            | Self::OpcodeUnexpSeen(_)
+            | Self::VersionUnexpSeen(_)
+            | Self::RxLevelLowSeen(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(v)
+            | Self::OpcodeUnexpSeen(v)
+            | Self::VersionUnexpSeen(v)
+            | Self::RxLevelLowSeen(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_MEP_STATUS_INSTANCE,
+            Self::OpcodeUnexpSeen(_) => {
+                IFLA_BRIDGE_CFM_MEP_STATUS_OPCODE_UNEXP_SEEN
+            }
+            Self::VersionUnexpSeen(_) => {
+                IFLA_BRIDGE_CFM_MEP_STATUS_VERSION_UNEXP_SEEN
+            }
+            Self::RxLevelLowSeen(_) => {
+                IFLA_BRIDGE_CFM_MEP_STATUS_RX_LEVEL_LOW_SEEN
+            }
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmMepStatus
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_STATUS_INSTANCE => Self::Instance(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_MEP_STATUS_INSTANCE value")?),
+            IFLA_BRIDGE_CFM_MEP_STATUS_OPCODE_UNEXP_SEEN => Self::OpcodeUnexpSeen(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_MEP_STATUS_OPCODE_UNEXP_SEEN value")?),
+            IFLA_BRIDGE_CFM_MEP_STATUS_VERSION_UNEXP_SEEN => Self::VersionUnexpSeen(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_MEP_STATUS_VERSION_UNEXP_SEEN value")?),
+            IFLA_BRIDGE_CFM_MEP_STATUS_RX_LEVEL_LOW_SEEN => Self::RxLevelLowSeen(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_MEP_STATUS_RX_LEVEL_LOW_SEEN value")?),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM_MEP_STATUS_INFO"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_PEER_MEPID: u16 = 2;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_CCM_DEFECT: u16 = 3;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_RDI: u16 = 4;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_PORT_TLV_VALUE: u16 = 5;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_IF_TLV_VALUE: u16 = 6;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEEN: u16 = 7;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_TLV_SEEN: u16 = 8;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEQ_UNEXP_SEEN: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeCfmCcPeerStatus {
+    Instance(u32),
+    PeerMepid(u32),
+    CcmDefect(u32),
+    Rdi(u32),
+    PortTlvValue(u8),
+    IfTlvValue(u8),
+    Seen(u32),
+    TlvSeen(u32),
+    SeqUnexpSeen(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcPeerStatus {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_)
+            | Self::PeerMepid(_)
+            | Self::CcmDefect(_)
+            | Self::Rdi(_)
+            | Self::Seen(_)
+            | Self::TlvSeen(_)
+            | Self::SeqUnexpSeen(_) => 4,
+            Self::PortTlvValue(_) | Self::IfTlvValue(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(v)
+            | Self::PeerMepid(v)
+            | Self::CcmDefect(v)
+            | Self::Rdi(v)
+            | Self::Seen(v)
+            | Self::TlvSeen(v)
+            | Self::SeqUnexpSeen(v) => NativeEndian::write_u32(buffer, *v),
+            Self::PortTlvValue(v) | Self::IfTlvValue(v) => buffer[0] = *v,
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_INSTANCE,
+            Self::PeerMepid(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_PEER_MEPID,
+            Self::CcmDefect(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_CCM_DEFECT,
+            Self::Rdi(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_RDI,
+            Self::PortTlvValue(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_STATUS_PORT_TLV_VALUE
+            }
+            Self::IfTlvValue(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_IF_TLV_VALUE,
+            Self::Seen(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEEN,
+            Self::TlvSeen(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_TLV_SEEN,
+            Self::SeqUnexpSeen(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEQ_UNEXP_SEEN
+            }
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcPeerStatus
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_INSTANCE => Self::Instance(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_CC_PEER_STATUS_INSTANCE value")?),
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_PEER_MEPID => Self::PeerMepid(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_CC_PEER_STATUS_PEER_MEPID value")?),
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_CCM_DEFECT => Self::CcmDefect(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_CC_PEER_STATUS_CCM_DEFECT value")?),
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_RDI => Self::Rdi(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_CC_PEER_STATUS_RDI value")?),
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_PORT_TLV_VALUE => Self::PortTlvValue(parse_u8(payload).context("invalid IFLA_BRIDGE_CFM_CC_PEER_STATUS_PORT_TLV_VALUE value")?),
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_IF_TLV_VALUE => Self::IfTlvValue(parse_u8(payload).context("invalid IFLA_BRIDGE_CFM_CC_PEER_STATUS_IF_TLV_VALUE value")?),
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEEN => Self::Seen(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEEN value")?),
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_TLV_SEEN => Self::TlvSeen(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_CC_PEER_STATUS_TLV_SEEN value")?),
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEQ_UNEXP_SEEN => Self::SeqUnexpSeen(parse_u32(payload).context("invalid IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEQ_UNEXP_SEEN value")?),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_CFM_CC_PEER_STATUS_INFO"
+            ))?),
+        })
+    }
+}
+
+const BR_CFM_PORT: u32 = 0;
+const BR_CFM_VLAN: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeCfmDomain {
+    Port,
+    Vlan,
+    Other(u32),
+}
+
+impl From<u32> for BridgeCfmDomain {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_CFM_PORT => Self::Port,
+            BR_CFM_VLAN => Self::Vlan,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeCfmDomain> for u32 {
+    fn from(v: BridgeCfmDomain) -> u32 {
+        match v {
+            BridgeCfmDomain::Port => BR_CFM_PORT,
+            BridgeCfmDomain::Vlan => BR_CFM_VLAN,
+            BridgeCfmDomain::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeCfmDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Port => write!(f, "port"),
+            Self::Vlan => write!(f, "vlan"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const BR_CFM_MEP_DIRECTION_DOWN: u32 = 0;
+const BR_CFM_MEP_DIRECTION_UP: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeCfmMepDirection {
+    Down,
+    Up,
+    Other(u32),
+}
+
+impl From<u32> for BridgeCfmMepDirection {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_CFM_MEP_DIRECTION_DOWN => Self::Down,
+            BR_CFM_MEP_DIRECTION_UP => Self::Up,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeCfmMepDirection> for u32 {
+    fn from(v: BridgeCfmMepDirection) -> u32 {
+        match v {
+            BridgeCfmMepDirection::Down => BR_CFM_MEP_DIRECTION_DOWN,
+            BridgeCfmMepDirection::Up => BR_CFM_MEP_DIRECTION_UP,
+            BridgeCfmMepDirection::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeCfmMepDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Down => write!(f, "down"),
+            Self::Up => write!(f, "up"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const BR_CFM_CCM_INTERVAL_NONE: u32 = 0;
+const BR_CFM_CCM_INTERVAL_3_3_MS: u32 = 1;
+const BR_CFM_CCM_INTERVAL_10_MS: u32 = 2;
+const BR_CFM_CCM_INTERVAL_100_MS: u32 = 3;
+const BR_CFM_CCM_INTERVAL_1_SEC: u32 = 4;
+const BR_CFM_CCM_INTERVAL_10_SEC: u32 = 5;
+const BR_CFM_CCM_INTERVAL_1_MIN: u32 = 6;
+const BR_CFM_CCM_INTERVAL_10_MIN: u32 = 7;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeCfmCcmInterval {
+    None,
+    Ms3_3,
+    Ms10,
+    Ms100,
+    Sec1,
+    Sec10,
+    Min1,
+    Min10,
+    Other(u32),
+}
+
+impl From<u32> for BridgeCfmCcmInterval {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_CFM_CCM_INTERVAL_NONE => Self::None,
+            BR_CFM_CCM_INTERVAL_3_3_MS => Self::Ms3_3,
+            BR_CFM_CCM_INTERVAL_10_MS => Self::Ms10,
+            BR_CFM_CCM_INTERVAL_100_MS => Self::Ms100,
+            BR_CFM_CCM_INTERVAL_1_SEC => Self::Sec1,
+            BR_CFM_CCM_INTERVAL_10_SEC => Self::Sec10,
+            BR_CFM_CCM_INTERVAL_1_MIN => Self::Min1,
+            BR_CFM_CCM_INTERVAL_10_MIN => Self::Min10,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeCfmCcmInterval> for u32 {
+    fn from(v: BridgeCfmCcmInterval) -> u32 {
+        match v {
+            BridgeCfmCcmInterval::None => BR_CFM_CCM_INTERVAL_NONE,
+            BridgeCfmCcmInterval::Ms3_3 => BR_CFM_CCM_INTERVAL_3_3_MS,
+            BridgeCfmCcmInterval::Ms10 => BR_CFM_CCM_INTERVAL_10_MS,
+            BridgeCfmCcmInterval::Ms100 => BR_CFM_CCM_INTERVAL_100_MS,
+            BridgeCfmCcmInterval::Sec1 => BR_CFM_CCM_INTERVAL_1_SEC,
+            BridgeCfmCcmInterval::Sec10 => BR_CFM_CCM_INTERVAL_10_SEC,
+            BridgeCfmCcmInterval::Min1 => BR_CFM_CCM_INTERVAL_1_MIN,
+            BridgeCfmCcmInterval::Min10 => BR_CFM_CCM_INTERVAL_10_MIN,
+            BridgeCfmCcmInterval::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeCfmCcmInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Ms3_3 => write!(f, "3.3ms"),
+            Self::Ms10 => write!(f, "10ms"),
+            Self::Ms100 => write!(f, "100ms"),
+            Self::Sec1 => write!(f, "1s"),
+            Self::Sec10 => write!(f, "10s"),
+            Self::Min1 => write!(f, "1min"),
+            Self::Min10 => write!(f, "10min"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
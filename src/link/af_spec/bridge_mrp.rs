@@ -0,0 +1,1206 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::bridge::parse_nlas;
+
+const IFLA_BRIDGE_MRP_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_MRP_PORT_STATE: u16 = 2;
+const IFLA_BRIDGE_MRP_PORT_ROLE: u16 = 3;
+const IFLA_BRIDGE_MRP_RING_STATE: u16 = 4;
+const IFLA_BRIDGE_MRP_RING_ROLE: u16 = 5;
+const IFLA_BRIDGE_MRP_START_TEST: u16 = 6;
+const IFLA_BRIDGE_MRP_INFO: u16 = 7;
+const IFLA_BRIDGE_MRP_IN_ROLE: u16 = 8;
+const IFLA_BRIDGE_MRP_IN_STATE: u16 = 9;
+const IFLA_BRIDGE_MRP_START_IN_TEST: u16 = 10;
+
+/// Media Redundancy Protocol(IEC 62439-2) of bridge
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrp {
+    /// Create or delete MRP instance
+    Instance(Vec<BridgeMrpInstance>),
+    PortState(Vec<BridgeMrpPortState>),
+    PortRole(Vec<BridgeMrpPortRole>),
+    RingState(Vec<BridgeMrpRingState>),
+    RingRole(Vec<BridgeMrpRingRole>),
+    StartTest(Vec<BridgeMrpStartTest>),
+    /// Status of MRP instance reported by kernel
+    Info(Vec<BridgeMrpInfo>),
+    InRole(Vec<BridgeMrpInRole>),
+    InState(Vec<BridgeMrpInState>),
+    StartInTest(Vec<BridgeMrpStartInTest>),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrp {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(nlas) => nlas.as_slice().buffer_len(),
+            Self::PortState(nlas) => nlas.as_slice().buffer_len(),
+            Self::PortRole(nlas) => nlas.as_slice().buffer_len(),
+            Self::RingState(nlas) => nlas.as_slice().buffer_len(),
+            Self::RingRole(nlas) => nlas.as_slice().buffer_len(),
+            Self::StartTest(nlas) => nlas.as_slice().buffer_len(),
+            Self::Info(nlas) => nlas.as_slice().buffer_len(),
+            Self::InRole(nlas) => nlas.as_slice().buffer_len(),
+            Self::InState(nlas) => nlas.as_slice().buffer_len(),
+            Self::StartInTest(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(nlas) => nlas.as_slice().emit(buffer),
+            Self::PortState(nlas) => nlas.as_slice().emit(buffer),
+            Self::PortRole(nlas) => nlas.as_slice().emit(buffer),
+            Self::RingState(nlas) => nlas.as_slice().emit(buffer),
+            Self::RingRole(nlas) => nlas.as_slice().emit(buffer),
+            Self::StartTest(nlas) => nlas.as_slice().emit(buffer),
+            Self::Info(nlas) => nlas.as_slice().emit(buffer),
+            Self::InRole(nlas) => nlas.as_slice().emit(buffer),
+            Self::InState(nlas) => nlas.as_slice().emit(buffer),
+            Self::StartInTest(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_MRP_INSTANCE | NLA_F_NESTED,
+            Self::PortState(_) => IFLA_BRIDGE_MRP_PORT_STATE | NLA_F_NESTED,
+            Self::PortRole(_) => IFLA_BRIDGE_MRP_PORT_ROLE | NLA_F_NESTED,
+            Self::RingState(_) => IFLA_BRIDGE_MRP_RING_STATE | NLA_F_NESTED,
+            Self::RingRole(_) => IFLA_BRIDGE_MRP_RING_ROLE | NLA_F_NESTED,
+            Self::StartTest(_) => IFLA_BRIDGE_MRP_START_TEST | NLA_F_NESTED,
+            Self::Info(_) => IFLA_BRIDGE_MRP_INFO | NLA_F_NESTED,
+            Self::InRole(_) => IFLA_BRIDGE_MRP_IN_ROLE | NLA_F_NESTED,
+            Self::InState(_) => IFLA_BRIDGE_MRP_IN_STATE | NLA_F_NESTED,
+            Self::StartInTest(_) => {
+                IFLA_BRIDGE_MRP_START_IN_TEST | NLA_F_NESTED
+            }
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BridgeMrp {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_INSTANCE => {
+                Self::Instance(parse_nlas(payload, "IFLA_BRIDGE_MRP_INSTANCE")?)
+            }
+            IFLA_BRIDGE_MRP_PORT_STATE => Self::PortState(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_MRP_PORT_STATE",
+            )?),
+            IFLA_BRIDGE_MRP_PORT_ROLE => Self::PortRole(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_MRP_PORT_ROLE",
+            )?),
+            IFLA_BRIDGE_MRP_RING_STATE => Self::RingState(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_MRP_RING_STATE",
+            )?),
+            IFLA_BRIDGE_MRP_RING_ROLE => Self::RingRole(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_MRP_RING_ROLE",
+            )?),
+            IFLA_BRIDGE_MRP_START_TEST => Self::StartTest(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_MRP_START_TEST",
+            )?),
+            IFLA_BRIDGE_MRP_INFO => {
+                Self::Info(parse_nlas(payload, "IFLA_BRIDGE_MRP_INFO")?)
+            }
+            IFLA_BRIDGE_MRP_IN_ROLE => {
+                Self::InRole(parse_nlas(payload, "IFLA_BRIDGE_MRP_IN_ROLE")?)
+            }
+            IFLA_BRIDGE_MRP_IN_STATE => {
+                Self::InState(parse_nlas(payload, "IFLA_BRIDGE_MRP_IN_STATE")?)
+            }
+            IFLA_BRIDGE_MRP_START_IN_TEST => Self::StartInTest(parse_nlas(
+                payload,
+                "IFLA_BRIDGE_MRP_START_IN_TEST",
+            )?),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_INSTANCE_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_INSTANCE_P_IFINDEX: u16 = 2;
+const IFLA_BRIDGE_MRP_INSTANCE_S_IFINDEX: u16 = 3;
+const IFLA_BRIDGE_MRP_INSTANCE_PRIO: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpInstance {
+    RingId(u32),
+    /// Interface index of primary port
+    PIfindex(u32),
+    /// Interface index of secondary port
+    SIfindex(u32),
+    Prio(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpInstance {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_) | Self::PIfindex(_) | Self::SIfindex(_) => 4,
+            Self::Prio(_) => 2,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(v) | Self::PIfindex(v) | Self::SIfindex(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Prio(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_INSTANCE_RING_ID,
+            Self::PIfindex(_) => IFLA_BRIDGE_MRP_INSTANCE_P_IFINDEX,
+            Self::SIfindex(_) => IFLA_BRIDGE_MRP_INSTANCE_S_IFINDEX,
+            Self::Prio(_) => IFLA_BRIDGE_MRP_INSTANCE_PRIO,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpInstance
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_INSTANCE_RING_ID => {
+                Self::RingId(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_INSTANCE_RING_ID value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_INSTANCE_P_IFINDEX => {
+                Self::PIfindex(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_INSTANCE_P_IFINDEX value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_INSTANCE_S_IFINDEX => {
+                Self::SIfindex(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_INSTANCE_S_IFINDEX value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_INSTANCE_PRIO => Self::Prio(
+                parse_u16(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INSTANCE_PRIO value")?,
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_INSTANCE"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_PORT_STATE_STATE: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpPortState {
+    State(BridgeMrpPortStateType),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpPortState {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::State(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::State(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::State(_) => IFLA_BRIDGE_MRP_PORT_STATE_STATE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpPortState
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_PORT_STATE_STATE => Self::State(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_PORT_STATE_STATE value")?
+                    .into(),
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_PORT_STATE"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_PORT_ROLE_ROLE: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpPortRole {
+    Role(BridgeMrpPortRoleType),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpPortRole {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Role(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Role(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Role(_) => IFLA_BRIDGE_MRP_PORT_ROLE_ROLE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpPortRole
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_PORT_ROLE_ROLE => Self::Role(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_PORT_ROLE_ROLE value")?
+                    .into(),
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_PORT_ROLE"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_RING_STATE_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_RING_STATE_STATE: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpRingState {
+    RingId(u32),
+    State(BridgeMrpRingStateType),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpRingState {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_) | Self::State(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(v) => NativeEndian::write_u32(buffer, *v),
+            Self::State(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_RING_STATE_RING_ID,
+            Self::State(_) => IFLA_BRIDGE_MRP_RING_STATE_STATE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpRingState
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_RING_STATE_RING_ID => {
+                Self::RingId(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_RING_STATE_RING_ID value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_RING_STATE_STATE => Self::State(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_RING_STATE_STATE value")?
+                    .into(),
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_RING_STATE"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_RING_ROLE_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_RING_ROLE_ROLE: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpRingRole {
+    RingId(u32),
+    Role(BridgeMrpRingRoleType),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpRingRole {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_) | Self::Role(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Role(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_RING_ROLE_RING_ID,
+            Self::Role(_) => IFLA_BRIDGE_MRP_RING_ROLE_ROLE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpRingRole
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_RING_ROLE_RING_ID => {
+                Self::RingId(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_RING_ROLE_RING_ID value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_RING_ROLE_ROLE => Self::Role(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_RING_ROLE_ROLE value")?
+                    .into(),
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_RING_ROLE"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_START_TEST_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_START_TEST_INTERVAL: u16 = 2;
+const IFLA_BRIDGE_MRP_START_TEST_MAX_MISS: u16 = 3;
+const IFLA_BRIDGE_MRP_START_TEST_PERIOD: u16 = 4;
+const IFLA_BRIDGE_MRP_START_TEST_MONITOR: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpStartTest {
+    RingId(u32),
+    /// Interval in microseconds
+    Interval(u32),
+    MaxMiss(u32),
+    /// Period in microseconds
+    Period(u32),
+    Monitor(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpStartTest {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_)
+            | Self::Interval(_)
+            | Self::MaxMiss(_)
+            | Self::Period(_)
+            | Self::Monitor(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(v)
+            | Self::Interval(v)
+            | Self::MaxMiss(v)
+            | Self::Period(v)
+            | Self::Monitor(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_START_TEST_RING_ID,
+            Self::Interval(_) => IFLA_BRIDGE_MRP_START_TEST_INTERVAL,
+            Self::MaxMiss(_) => IFLA_BRIDGE_MRP_START_TEST_MAX_MISS,
+            Self::Period(_) => IFLA_BRIDGE_MRP_START_TEST_PERIOD,
+            Self::Monitor(_) => IFLA_BRIDGE_MRP_START_TEST_MONITOR,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpStartTest
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_START_TEST_RING_ID => {
+                Self::RingId(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_START_TEST_RING_ID value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_START_TEST_INTERVAL => {
+                Self::Interval(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_START_TEST_INTERVAL value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_START_TEST_MAX_MISS => {
+                Self::MaxMiss(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_START_TEST_MAX_MISS value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_START_TEST_PERIOD => {
+                Self::Period(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_START_TEST_PERIOD value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_START_TEST_MONITOR => {
+                Self::Monitor(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_START_TEST_MONITOR value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_START_TEST"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_INFO_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_INFO_P_IFINDEX: u16 = 2;
+const IFLA_BRIDGE_MRP_INFO_S_IFINDEX: u16 = 3;
+const IFLA_BRIDGE_MRP_INFO_PRIO: u16 = 4;
+const IFLA_BRIDGE_MRP_INFO_RING_STATE: u16 = 5;
+const IFLA_BRIDGE_MRP_INFO_RING_ROLE: u16 = 6;
+const IFLA_BRIDGE_MRP_INFO_TEST_INTERVAL: u16 = 7;
+const IFLA_BRIDGE_MRP_INFO_TEST_MAX_MISS: u16 = 8;
+const IFLA_BRIDGE_MRP_INFO_TEST_MONITOR: u16 = 9;
+const IFLA_BRIDGE_MRP_INFO_I_IFINDEX: u16 = 10;
+const IFLA_BRIDGE_MRP_INFO_IN_STATE: u16 = 11;
+const IFLA_BRIDGE_MRP_INFO_IN_ROLE: u16 = 12;
+const IFLA_BRIDGE_MRP_INFO_IN_TEST_INTERVAL: u16 = 13;
+const IFLA_BRIDGE_MRP_INFO_IN_TEST_MAX_MISS: u16 = 14;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpInfo {
+    RingId(u32),
+    PIfindex(u32),
+    SIfindex(u32),
+    Prio(u16),
+    RingState(BridgeMrpRingStateType),
+    RingRole(BridgeMrpRingRoleType),
+    TestInterval(u32),
+    TestMaxMiss(u32),
+    TestMonitor(u32),
+    IIfindex(u32),
+    InState(BridgeMrpInStateType),
+    InRole(BridgeMrpInRoleType),
+    InTestInterval(u32),
+    InTestMaxMiss(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_)
+            | Self::PIfindex(_)
+            | Self::SIfindex(_)
+            | Self::RingState(_)
+            | Self::RingRole(_)
+            | Self::TestInterval(_)
+            | Self::TestMaxMiss(_)
+            | Self::TestMonitor(_)
+            | Self::IIfindex(_)
+            | Self::InState(_)
+            | Self::InRole(_)
+            | Self::InTestInterval(_)
+            | Self::InTestMaxMiss(_) => 4,
+            Self::Prio(_) => 2,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(v)
+            | Self::PIfindex(v)
+            | Self::SIfindex(v)
+            | Self::TestInterval(v)
+            | Self::TestMaxMiss(v)
+            | Self::TestMonitor(v)
+            | Self::IIfindex(v)
+            | Self::InTestInterval(v)
+            | Self::InTestMaxMiss(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Prio(v) => NativeEndian::write_u16(buffer, *v),
+            Self::RingState(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::RingRole(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::InState(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::InRole(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_INFO_RING_ID,
+            Self::PIfindex(_) => IFLA_BRIDGE_MRP_INFO_P_IFINDEX,
+            Self::SIfindex(_) => IFLA_BRIDGE_MRP_INFO_S_IFINDEX,
+            Self::Prio(_) => IFLA_BRIDGE_MRP_INFO_PRIO,
+            Self::RingState(_) => IFLA_BRIDGE_MRP_INFO_RING_STATE,
+            Self::RingRole(_) => IFLA_BRIDGE_MRP_INFO_RING_ROLE,
+            Self::TestInterval(_) => IFLA_BRIDGE_MRP_INFO_TEST_INTERVAL,
+            Self::TestMaxMiss(_) => IFLA_BRIDGE_MRP_INFO_TEST_MAX_MISS,
+            Self::TestMonitor(_) => IFLA_BRIDGE_MRP_INFO_TEST_MONITOR,
+            Self::IIfindex(_) => IFLA_BRIDGE_MRP_INFO_I_IFINDEX,
+            Self::InState(_) => IFLA_BRIDGE_MRP_INFO_IN_STATE,
+            Self::InRole(_) => IFLA_BRIDGE_MRP_INFO_IN_ROLE,
+            Self::InTestInterval(_) => IFLA_BRIDGE_MRP_INFO_IN_TEST_INTERVAL,
+            Self::InTestMaxMiss(_) => IFLA_BRIDGE_MRP_INFO_IN_TEST_MAX_MISS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_INFO_RING_ID => Self::RingId(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INFO_RING_ID value")?,
+            ),
+            IFLA_BRIDGE_MRP_INFO_P_IFINDEX => Self::PIfindex(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INFO_P_IFINDEX value")?,
+            ),
+            IFLA_BRIDGE_MRP_INFO_S_IFINDEX => Self::SIfindex(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INFO_S_IFINDEX value")?,
+            ),
+            IFLA_BRIDGE_MRP_INFO_PRIO => Self::Prio(
+                parse_u16(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INFO_PRIO value")?,
+            ),
+            IFLA_BRIDGE_MRP_INFO_RING_STATE => Self::RingState(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INFO_RING_STATE value")?
+                    .into(),
+            ),
+            IFLA_BRIDGE_MRP_INFO_RING_ROLE => Self::RingRole(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INFO_RING_ROLE value")?
+                    .into(),
+            ),
+            IFLA_BRIDGE_MRP_INFO_TEST_INTERVAL => {
+                Self::TestInterval(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_INFO_TEST_INTERVAL value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_INFO_TEST_MAX_MISS => {
+                Self::TestMaxMiss(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_INFO_TEST_MAX_MISS value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_INFO_TEST_MONITOR => {
+                Self::TestMonitor(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_INFO_TEST_MONITOR value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_INFO_I_IFINDEX => Self::IIfindex(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INFO_I_IFINDEX value")?,
+            ),
+            IFLA_BRIDGE_MRP_INFO_IN_STATE => Self::InState(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INFO_IN_STATE value")?
+                    .into(),
+            ),
+            IFLA_BRIDGE_MRP_INFO_IN_ROLE => Self::InRole(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_INFO_IN_ROLE value")?
+                    .into(),
+            ),
+            IFLA_BRIDGE_MRP_INFO_IN_TEST_INTERVAL => {
+                Self::InTestInterval(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_INFO_IN_TEST_INTERVAL value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_INFO_IN_TEST_MAX_MISS => {
+                Self::InTestMaxMiss(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_INFO_IN_TEST_MAX_MISS value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_INFO"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_IN_STATE_IN_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_IN_STATE_STATE: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpInState {
+    InId(u32),
+    State(BridgeMrpInStateType),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpInState {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::InId(_) | Self::State(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::InId(v) => NativeEndian::write_u32(buffer, *v),
+            Self::State(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::InId(_) => IFLA_BRIDGE_MRP_IN_STATE_IN_ID,
+            Self::State(_) => IFLA_BRIDGE_MRP_IN_STATE_STATE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpInState
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_IN_STATE_IN_ID => Self::InId(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_IN_STATE_IN_ID value")?,
+            ),
+            IFLA_BRIDGE_MRP_IN_STATE_STATE => Self::State(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_IN_STATE_STATE value")?
+                    .into(),
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_IN_STATE"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_IN_ROLE_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_IN_ROLE_IN_ID: u16 = 2;
+const IFLA_BRIDGE_MRP_IN_ROLE_ROLE: u16 = 3;
+const IFLA_BRIDGE_MRP_IN_ROLE_I_IFINDEX: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpInRole {
+    RingId(u32),
+    InId(u16),
+    Role(BridgeMrpInRoleType),
+    /// Interface index of interconnect port
+    IIfindex(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpInRole {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_) | Self::Role(_) | Self::IIfindex(_) => 4,
+            Self::InId(_) => 2,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(v) | Self::IIfindex(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::InId(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Role(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_IN_ROLE_RING_ID,
+            Self::InId(_) => IFLA_BRIDGE_MRP_IN_ROLE_IN_ID,
+            Self::Role(_) => IFLA_BRIDGE_MRP_IN_ROLE_ROLE,
+            Self::IIfindex(_) => IFLA_BRIDGE_MRP_IN_ROLE_I_IFINDEX,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpInRole
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_IN_ROLE_RING_ID => Self::RingId(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_IN_ROLE_RING_ID value")?,
+            ),
+            IFLA_BRIDGE_MRP_IN_ROLE_IN_ID => Self::InId(
+                parse_u16(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_IN_ROLE_IN_ID value")?,
+            ),
+            IFLA_BRIDGE_MRP_IN_ROLE_ROLE => Self::Role(
+                parse_u32(payload)
+                    .context("invalid IFLA_BRIDGE_MRP_IN_ROLE_ROLE value")?
+                    .into(),
+            ),
+            IFLA_BRIDGE_MRP_IN_ROLE_I_IFINDEX => {
+                Self::IIfindex(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_IN_ROLE_I_IFINDEX value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_IN_ROLE"
+            ))?),
+        })
+    }
+}
+
+const IFLA_BRIDGE_MRP_START_IN_TEST_IN_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_START_IN_TEST_INTERVAL: u16 = 2;
+const IFLA_BRIDGE_MRP_START_IN_TEST_MAX_MISS: u16 = 3;
+const IFLA_BRIDGE_MRP_START_IN_TEST_PERIOD: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeMrpStartInTest {
+    InId(u32),
+    /// Interval in microseconds
+    Interval(u32),
+    MaxMiss(u32),
+    /// Period in microseconds
+    Period(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpStartInTest {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::InId(_)
+            | Self::Interval(_)
+            | Self::MaxMiss(_)
+            | Self::Period(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::InId(v)
+            | Self::Interval(v)
+            | Self::MaxMiss(v)
+            | Self::Period(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::InId(_) => IFLA_BRIDGE_MRP_START_IN_TEST_IN_ID,
+            Self::Interval(_) => IFLA_BRIDGE_MRP_START_IN_TEST_INTERVAL,
+            Self::MaxMiss(_) => IFLA_BRIDGE_MRP_START_IN_TEST_MAX_MISS,
+            Self::Period(_) => IFLA_BRIDGE_MRP_START_IN_TEST_PERIOD,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpStartInTest
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_START_IN_TEST_IN_ID => {
+                Self::InId(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_START_IN_TEST_IN_ID value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_START_IN_TEST_INTERVAL => {
+                Self::Interval(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_START_IN_TEST_INTERVAL value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_START_IN_TEST_MAX_MISS => {
+                Self::MaxMiss(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_START_IN_TEST_MAX_MISS value",
+                )?)
+            }
+            IFLA_BRIDGE_MRP_START_IN_TEST_PERIOD => {
+                Self::Period(parse_u32(payload).context(
+                    "invalid IFLA_BRIDGE_MRP_START_IN_TEST_PERIOD value",
+                )?)
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_BRIDGE_MRP_START_IN_TEST"
+            ))?),
+        })
+    }
+}
+
+const BR_MRP_PORT_STATE_DISABLED: u32 = 0;
+const BR_MRP_PORT_STATE_BLOCKED: u32 = 1;
+const BR_MRP_PORT_STATE_FORWARDING: u32 = 2;
+const BR_MRP_PORT_STATE_NOT_CONNECTED: u32 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeMrpPortStateType {
+    Disabled,
+    Blocked,
+    Forwarding,
+    NotConnected,
+    Other(u32),
+}
+
+impl From<u32> for BridgeMrpPortStateType {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_PORT_STATE_DISABLED => Self::Disabled,
+            BR_MRP_PORT_STATE_BLOCKED => Self::Blocked,
+            BR_MRP_PORT_STATE_FORWARDING => Self::Forwarding,
+            BR_MRP_PORT_STATE_NOT_CONNECTED => Self::NotConnected,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeMrpPortStateType> for u32 {
+    fn from(v: BridgeMrpPortStateType) -> u32 {
+        match v {
+            BridgeMrpPortStateType::Disabled => BR_MRP_PORT_STATE_DISABLED,
+            BridgeMrpPortStateType::Blocked => BR_MRP_PORT_STATE_BLOCKED,
+            BridgeMrpPortStateType::Forwarding => BR_MRP_PORT_STATE_FORWARDING,
+            BridgeMrpPortStateType::NotConnected => {
+                BR_MRP_PORT_STATE_NOT_CONNECTED
+            }
+            BridgeMrpPortStateType::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeMrpPortStateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Disabled => write!(f, "disabled"),
+            Self::Blocked => write!(f, "blocked"),
+            Self::Forwarding => write!(f, "forwarding"),
+            Self::NotConnected => write!(f, "not_connected"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const BR_MRP_PORT_ROLE_PRIMARY: u32 = 0;
+const BR_MRP_PORT_ROLE_SECONDARY: u32 = 1;
+const BR_MRP_PORT_ROLE_INTER: u32 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeMrpPortRoleType {
+    Primary,
+    Secondary,
+    Interconnect,
+    Other(u32),
+}
+
+impl From<u32> for BridgeMrpPortRoleType {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_PORT_ROLE_PRIMARY => Self::Primary,
+            BR_MRP_PORT_ROLE_SECONDARY => Self::Secondary,
+            BR_MRP_PORT_ROLE_INTER => Self::Interconnect,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeMrpPortRoleType> for u32 {
+    fn from(v: BridgeMrpPortRoleType) -> u32 {
+        match v {
+            BridgeMrpPortRoleType::Primary => BR_MRP_PORT_ROLE_PRIMARY,
+            BridgeMrpPortRoleType::Secondary => BR_MRP_PORT_ROLE_SECONDARY,
+            BridgeMrpPortRoleType::Interconnect => BR_MRP_PORT_ROLE_INTER,
+            BridgeMrpPortRoleType::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeMrpPortRoleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Primary => write!(f, "primary"),
+            Self::Secondary => write!(f, "secondary"),
+            Self::Interconnect => write!(f, "interconnect"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const BR_MRP_RING_STATE_OPEN: u32 = 0;
+const BR_MRP_RING_STATE_CLOSED: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeMrpRingStateType {
+    Open,
+    Closed,
+    Other(u32),
+}
+
+impl From<u32> for BridgeMrpRingStateType {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_RING_STATE_OPEN => Self::Open,
+            BR_MRP_RING_STATE_CLOSED => Self::Closed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeMrpRingStateType> for u32 {
+    fn from(v: BridgeMrpRingStateType) -> u32 {
+        match v {
+            BridgeMrpRingStateType::Open => BR_MRP_RING_STATE_OPEN,
+            BridgeMrpRingStateType::Closed => BR_MRP_RING_STATE_CLOSED,
+            BridgeMrpRingStateType::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeMrpRingStateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => write!(f, "open"),
+            Self::Closed => write!(f, "closed"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const BR_MRP_RING_ROLE_DISABLED: u32 = 0;
+const BR_MRP_RING_ROLE_MRC: u32 = 1;
+const BR_MRP_RING_ROLE_MRM: u32 = 2;
+const BR_MRP_RING_ROLE_MRA: u32 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeMrpRingRoleType {
+    Disabled,
+    /// Media Redundancy Client
+    Mrc,
+    /// Media Redundancy Manager
+    Mrm,
+    /// Media Redundancy Automanager
+    Mra,
+    Other(u32),
+}
+
+impl From<u32> for BridgeMrpRingRoleType {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_RING_ROLE_DISABLED => Self::Disabled,
+            BR_MRP_RING_ROLE_MRC => Self::Mrc,
+            BR_MRP_RING_ROLE_MRM => Self::Mrm,
+            BR_MRP_RING_ROLE_MRA => Self::Mra,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeMrpRingRoleType> for u32 {
+    fn from(v: BridgeMrpRingRoleType) -> u32 {
+        match v {
+            BridgeMrpRingRoleType::Disabled => BR_MRP_RING_ROLE_DISABLED,
+            BridgeMrpRingRoleType::Mrc => BR_MRP_RING_ROLE_MRC,
+            BridgeMrpRingRoleType::Mrm => BR_MRP_RING_ROLE_MRM,
+            BridgeMrpRingRoleType::Mra => BR_MRP_RING_ROLE_MRA,
+            BridgeMrpRingRoleType::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeMrpRingRoleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Disabled => write!(f, "disabled"),
+            Self::Mrc => write!(f, "mrc"),
+            Self::Mrm => write!(f, "mrm"),
+            Self::Mra => write!(f, "mra"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const BR_MRP_IN_STATE_OPEN: u32 = 0;
+const BR_MRP_IN_STATE_CLOSED: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeMrpInStateType {
+    Open,
+    Closed,
+    Other(u32),
+}
+
+impl From<u32> for BridgeMrpInStateType {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_IN_STATE_OPEN => Self::Open,
+            BR_MRP_IN_STATE_CLOSED => Self::Closed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeMrpInStateType> for u32 {
+    fn from(v: BridgeMrpInStateType) -> u32 {
+        match v {
+            BridgeMrpInStateType::Open => BR_MRP_IN_STATE_OPEN,
+            BridgeMrpInStateType::Closed => BR_MRP_IN_STATE_CLOSED,
+            BridgeMrpInStateType::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeMrpInStateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => write!(f, "open"),
+            Self::Closed => write!(f, "closed"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const BR_MRP_IN_ROLE_DISABLED: u32 = 0;
+const BR_MRP_IN_ROLE_MIC: u32 = 1;
+const BR_MRP_IN_ROLE_MIM: u32 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum BridgeMrpInRoleType {
+    Disabled,
+    /// Media Redundancy Interconnection Client
+    Mic,
+    /// Media Redundancy Interconnection Manager
+    Mim,
+    Other(u32),
+}
+
+impl From<u32> for BridgeMrpInRoleType {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_IN_ROLE_DISABLED => Self::Disabled,
+            BR_MRP_IN_ROLE_MIC => Self::Mic,
+            BR_MRP_IN_ROLE_MIM => Self::Mim,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<BridgeMrpInRoleType> for u32 {
+    fn from(v: BridgeMrpInRoleType) -> u32 {
+        match v {
+            BridgeMrpInRoleType::Disabled => BR_MRP_IN_ROLE_DISABLED,
+            BridgeMrpInRoleType::Mic => BR_MRP_IN_ROLE_MIC,
+            BridgeMrpInRoleType::Mim => BR_MRP_IN_ROLE_MIM,
+            BridgeMrpInRoleType::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for BridgeMrpInRoleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Disabled => write!(f, "disabled"),
+            Self::Mic => write!(f, "mic"),
+            Self::Mim => write!(f, "mim"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
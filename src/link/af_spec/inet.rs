@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{self, DefaultNla, NlaBuffer, NlasIterator},
     traits::{Emitable, Parseable},
@@ -19,6 +20,11 @@ const DEV_CONF_LEN: usize = IPV4_DEVCONF_MAX * 4;
 #[non_exhaustive]
 pub enum AfSpecInet {
     DevConf(InetDevConf),
+    /// Sparse update of IPv4 devconf used for changing link. Only the listed
+    /// keys will be modified by kernel in a single request. Kernel never
+    /// reply with this format, the `IFLA_INET_CONF` in a link dump is always
+    /// parsed as `AfSpecInet::DevConf`.
+    DevConfUpdate(Vec<(InetDevConfKey, u32)>),
     Other(DefaultNla),
 }
 
@@ -43,6 +49,10 @@ impl nla::Nla for AfSpecInet {
         use self::AfSpecInet::*;
         match *self {
             DevConf(ref c) => c.buffer_len(),
+            DevConfUpdate(ref v) => v
+                .iter()
+                .map(|(k, v)| InetDevConfNla(*k, *v).buffer_len())
+                .sum(),
             Other(ref nla) => nla.value_len(),
         }
     }
@@ -51,6 +61,11 @@ impl nla::Nla for AfSpecInet {
         use self::AfSpecInet::*;
         match *self {
             DevConf(ref c) => c.emit(buffer),
+            DevConfUpdate(ref v) => {
+                let nlas: Vec<InetDevConfNla> =
+                    v.iter().map(|(k, v)| InetDevConfNla(*k, *v)).collect();
+                nlas.as_slice().emit(buffer)
+            }
             Other(ref nla) => nla.emit_value(buffer),
         }
     }
@@ -58,7 +73,7 @@ impl nla::Nla for AfSpecInet {
     fn kind(&self) -> u16 {
         use self::AfSpecInet::*;
         match *self {
-            DevConf(_) => IFLA_INET_CONF,
+            DevConf(_) | DevConfUpdate(_) => IFLA_INET_CONF,
             Other(ref nla) => nla.kind(),
         }
     }
@@ -252,3 +267,202 @@ impl Emitable for InetDevConf {
         buffer.set_arp_evict_nocarrier(self.arp_evict_nocarrier);
     }
 }
+
+// Each attribute of the sparse `IFLA_INET_CONF` is using `IPV4_DEVCONF_*`
+// as NLA type and holding a u32 value.
+struct InetDevConfNla(InetDevConfKey, u32);
+
+impl nla::Nla for InetDevConfNla {
+    fn value_len(&self) -> usize {
+        4
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(buffer, self.1)
+    }
+
+    fn kind(&self) -> u16 {
+        self.0.into()
+    }
+}
+
+const IPV4_DEVCONF_FORWARDING: u16 = 1;
+const IPV4_DEVCONF_MC_FORWARDING: u16 = 2;
+const IPV4_DEVCONF_PROXY_ARP: u16 = 3;
+const IPV4_DEVCONF_ACCEPT_REDIRECTS: u16 = 4;
+const IPV4_DEVCONF_SECURE_REDIRECTS: u16 = 5;
+const IPV4_DEVCONF_SEND_REDIRECTS: u16 = 6;
+const IPV4_DEVCONF_SHARED_MEDIA: u16 = 7;
+const IPV4_DEVCONF_RP_FILTER: u16 = 8;
+const IPV4_DEVCONF_ACCEPT_SOURCE_ROUTE: u16 = 9;
+const IPV4_DEVCONF_BOOTP_RELAY: u16 = 10;
+const IPV4_DEVCONF_LOG_MARTIANS: u16 = 11;
+const IPV4_DEVCONF_TAG: u16 = 12;
+const IPV4_DEVCONF_ARPFILTER: u16 = 13;
+const IPV4_DEVCONF_MEDIUM_ID: u16 = 14;
+const IPV4_DEVCONF_NOXFRM: u16 = 15;
+const IPV4_DEVCONF_NOPOLICY: u16 = 16;
+const IPV4_DEVCONF_FORCE_IGMP_VERSION: u16 = 17;
+const IPV4_DEVCONF_ARP_ANNOUNCE: u16 = 18;
+const IPV4_DEVCONF_ARP_IGNORE: u16 = 19;
+const IPV4_DEVCONF_PROMOTE_SECONDARIES: u16 = 20;
+const IPV4_DEVCONF_ARP_ACCEPT: u16 = 21;
+const IPV4_DEVCONF_ARP_NOTIFY: u16 = 22;
+const IPV4_DEVCONF_ACCEPT_LOCAL: u16 = 23;
+const IPV4_DEVCONF_SRC_VMARK: u16 = 24;
+const IPV4_DEVCONF_PROXY_ARP_PVLAN: u16 = 25;
+const IPV4_DEVCONF_ROUTE_LOCALNET: u16 = 26;
+const IPV4_DEVCONF_IGMPV2_UNSOLICITED_REPORT_INTERVAL: u16 = 27;
+const IPV4_DEVCONF_IGMPV3_UNSOLICITED_REPORT_INTERVAL: u16 = 28;
+const IPV4_DEVCONF_IGNORE_ROUTES_WITH_LINKDOWN: u16 = 29;
+const IPV4_DEVCONF_DROP_UNICAST_IN_L2_MULTICAST: u16 = 30;
+const IPV4_DEVCONF_DROP_GRATUITOUS_ARP: u16 = 31;
+const IPV4_DEVCONF_BC_FORWARDING: u16 = 32;
+const IPV4_DEVCONF_ARP_EVICT_NOCARRIER: u16 = 33;
+
+/// Index of IPv4 devconf(`IPV4_DEVCONF_*`), each one is matching a field of
+/// [InetDevConf].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum InetDevConfKey {
+    Forwarding,
+    McForwarding,
+    ProxyArp,
+    AcceptRedirects,
+    SecureRedirects,
+    SendRedirects,
+    SharedMedia,
+    RpFilter,
+    AcceptSourceRoute,
+    BootpRelay,
+    LogMartians,
+    Tag,
+    Arpfilter,
+    MediumId,
+    Noxfrm,
+    Nopolicy,
+    ForceIgmpVersion,
+    ArpAnnounce,
+    ArpIgnore,
+    PromoteSecondaries,
+    ArpAccept,
+    ArpNotify,
+    AcceptLocal,
+    SrcVmark,
+    ProxyArpPvlan,
+    RouteLocalnet,
+    Igmpv2UnsolicitedReportInterval,
+    Igmpv3UnsolicitedReportInterval,
+    IgnoreRoutesWithLinkdown,
+    DropUnicastInL2Multicast,
+    DropGratuitousArp,
+    BcForwarding,
+    ArpEvictNocarrier,
+    Other(u16),
+}
+
+impl From<u16> for InetDevConfKey {
+    fn from(d: u16) -> Self {
+        match d {
+            IPV4_DEVCONF_FORWARDING => Self::Forwarding,
+            IPV4_DEVCONF_MC_FORWARDING => Self::McForwarding,
+            IPV4_DEVCONF_PROXY_ARP => Self::ProxyArp,
+            IPV4_DEVCONF_ACCEPT_REDIRECTS => Self::AcceptRedirects,
+            IPV4_DEVCONF_SECURE_REDIRECTS => Self::SecureRedirects,
+            IPV4_DEVCONF_SEND_REDIRECTS => Self::SendRedirects,
+            IPV4_DEVCONF_SHARED_MEDIA => Self::SharedMedia,
+            IPV4_DEVCONF_RP_FILTER => Self::RpFilter,
+            IPV4_DEVCONF_ACCEPT_SOURCE_ROUTE => Self::AcceptSourceRoute,
+            IPV4_DEVCONF_BOOTP_RELAY => Self::BootpRelay,
+            IPV4_DEVCONF_LOG_MARTIANS => Self::LogMartians,
+            IPV4_DEVCONF_TAG => Self::Tag,
+            IPV4_DEVCONF_ARPFILTER => Self::Arpfilter,
+            IPV4_DEVCONF_MEDIUM_ID => Self::MediumId,
+            IPV4_DEVCONF_NOXFRM => Self::Noxfrm,
+            IPV4_DEVCONF_NOPOLICY => Self::Nopolicy,
+            IPV4_DEVCONF_FORCE_IGMP_VERSION => Self::ForceIgmpVersion,
+            IPV4_DEVCONF_ARP_ANNOUNCE => Self::ArpAnnounce,
+            IPV4_DEVCONF_ARP_IGNORE => Self::ArpIgnore,
+            IPV4_DEVCONF_PROMOTE_SECONDARIES => Self::PromoteSecondaries,
+            IPV4_DEVCONF_ARP_ACCEPT => Self::ArpAccept,
+            IPV4_DEVCONF_ARP_NOTIFY => Self::ArpNotify,
+            IPV4_DEVCONF_ACCEPT_LOCAL => Self::AcceptLocal,
+            IPV4_DEVCONF_SRC_VMARK => Self::SrcVmark,
+            IPV4_DEVCONF_PROXY_ARP_PVLAN => Self::ProxyArpPvlan,
+            IPV4_DEVCONF_ROUTE_LOCALNET => Self::RouteLocalnet,
+            IPV4_DEVCONF_IGMPV2_UNSOLICITED_REPORT_INTERVAL => {
+                Self::Igmpv2UnsolicitedReportInterval
+            }
+            IPV4_DEVCONF_IGMPV3_UNSOLICITED_REPORT_INTERVAL => {
+                Self::Igmpv3UnsolicitedReportInterval
+            }
+            IPV4_DEVCONF_IGNORE_ROUTES_WITH_LINKDOWN => {
+                Self::IgnoreRoutesWithLinkdown
+            }
+            IPV4_DEVCONF_DROP_UNICAST_IN_L2_MULTICAST => {
+                Self::DropUnicastInL2Multicast
+            }
+            IPV4_DEVCONF_DROP_GRATUITOUS_ARP => Self::DropGratuitousArp,
+            IPV4_DEVCONF_BC_FORWARDING => Self::BcForwarding,
+            IPV4_DEVCONF_ARP_EVICT_NOCARRIER => Self::ArpEvictNocarrier,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<InetDevConfKey> for u16 {
+    fn from(v: InetDevConfKey) -> u16 {
+        match v {
+            InetDevConfKey::Forwarding => IPV4_DEVCONF_FORWARDING,
+            InetDevConfKey::McForwarding => IPV4_DEVCONF_MC_FORWARDING,
+            InetDevConfKey::ProxyArp => IPV4_DEVCONF_PROXY_ARP,
+            InetDevConfKey::AcceptRedirects => IPV4_DEVCONF_ACCEPT_REDIRECTS,
+            InetDevConfKey::SecureRedirects => IPV4_DEVCONF_SECURE_REDIRECTS,
+            InetDevConfKey::SendRedirects => IPV4_DEVCONF_SEND_REDIRECTS,
+            InetDevConfKey::SharedMedia => IPV4_DEVCONF_SHARED_MEDIA,
+            InetDevConfKey::RpFilter => IPV4_DEVCONF_RP_FILTER,
+            InetDevConfKey::AcceptSourceRoute => {
+                IPV4_DEVCONF_ACCEPT_SOURCE_ROUTE
+            }
+            InetDevConfKey::BootpRelay => IPV4_DEVCONF_BOOTP_RELAY,
+            InetDevConfKey::LogMartians => IPV4_DEVCONF_LOG_MARTIANS,
+            InetDevConfKey::Tag => IPV4_DEVCONF_TAG,
+            InetDevConfKey::Arpfilter => IPV4_DEVCONF_ARPFILTER,
+            InetDevConfKey::MediumId => IPV4_DEVCONF_MEDIUM_ID,
+            InetDevConfKey::Noxfrm => IPV4_DEVCONF_NOXFRM,
+            InetDevConfKey::Nopolicy => IPV4_DEVCONF_NOPOLICY,
+            InetDevConfKey::ForceIgmpVersion => IPV4_DEVCONF_FORCE_IGMP_VERSION,
+            InetDevConfKey::ArpAnnounce => IPV4_DEVCONF_ARP_ANNOUNCE,
+            InetDevConfKey::ArpIgnore => IPV4_DEVCONF_ARP_IGNORE,
+            InetDevConfKey::PromoteSecondaries => {
+                IPV4_DEVCONF_PROMOTE_SECONDARIES
+            }
+            InetDevConfKey::ArpAccept => IPV4_DEVCONF_ARP_ACCEPT,
+            InetDevConfKey::ArpNotify => IPV4_DEVCONF_ARP_NOTIFY,
+            InetDevConfKey::AcceptLocal => IPV4_DEVCONF_ACCEPT_LOCAL,
+            InetDevConfKey::SrcVmark => IPV4_DEVCONF_SRC_VMARK,
+            InetDevConfKey::ProxyArpPvlan => IPV4_DEVCONF_PROXY_ARP_PVLAN,
+            InetDevConfKey::RouteLocalnet => IPV4_DEVCONF_ROUTE_LOCALNET,
+            InetDevConfKey::Igmpv2UnsolicitedReportInterval => {
+                IPV4_DEVCONF_IGMPV2_UNSOLICITED_REPORT_INTERVAL
+            }
+            InetDevConfKey::Igmpv3UnsolicitedReportInterval => {
+                IPV4_DEVCONF_IGMPV3_UNSOLICITED_REPORT_INTERVAL
+            }
+            InetDevConfKey::IgnoreRoutesWithLinkdown => {
+                IPV4_DEVCONF_IGNORE_ROUTES_WITH_LINKDOWN
+            }
+            InetDevConfKey::DropUnicastInL2Multicast => {
+                IPV4_DEVCONF_DROP_UNICAST_IN_L2_MULTICAST
+            }
+            InetDevConfKey::DropGratuitousArp => {
+                IPV4_DEVCONF_DROP_GRATUITOUS_ARP
+            }
+            InetDevConfKey::BcForwarding => IPV4_DEVCONF_BC_FORWARDING,
+            InetDevConfKey::ArpEvictNocarrier => {
+                IPV4_DEVCONF_ARP_EVICT_NOCARRIER
+            }
+            InetDevConfKey::Other(d) => d,
+        }
+    }
+}
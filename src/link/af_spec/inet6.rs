@@ -14,16 +14,18 @@ use netlink_packet_utils::{
 use super::super::{
     buffer_tool::expand_buffer_if_small, Icmp6Stats, Icmp6StatsBuffer,
     Inet6CacheInfo, Inet6CacheInfoBuffer, Inet6DevConf, Inet6DevConfBuffer,
-    Inet6IfaceFlags, Inet6Stats, Inet6StatsBuffer,
+    Inet6IfaceFlag, Inet6Stats, Inet6StatsBuffer,
+};
+use super::{
+    inet6_devconf::LINK_INET6_DEV_CONF_LEN, inet6_iface_flag::VecInet6IfaceFlag,
 };
-use super::inet6_devconf::LINK_INET6_DEV_CONF_LEN;
 use crate::ip::parse_ipv6_addr;
 
 const IFLA_INET6_FLAGS: u16 = 1;
 const IFLA_INET6_CONF: u16 = 2;
 const IFLA_INET6_STATS: u16 = 3;
 // No kernel code used IFLA_INET6_MCAST
-// const IFLA_INET6_MCAST: u16 = 4;
+const IFLA_INET6_MCAST: u16 = 4;
 const IFLA_INET6_CACHEINFO: u16 = 5;
 const IFLA_INET6_ICMP6STATS: u16 = 6;
 const IFLA_INET6_TOKEN: u16 = 7;
@@ -33,14 +35,22 @@ const IFLA_INET6_RA_MTU: u16 = 9;
 #[derive(Clone, Eq, PartialEq, Debug)]
 #[non_exhaustive]
 pub enum AfSpecInet6 {
-    //TODO(Gris Ge): Use Vec<enum> for `IFF_UP` and etc
-    Flags(Inet6IfaceFlags),
+    Flags(Vec<Inet6IfaceFlag>),
     CacheInfo(Inet6CacheInfo),
+    /// Read only. Unlike `AfSpecInet::DevConfUpdate`, kernel ignores
+    /// `IFLA_INET6_CONF` when changing link, please use sysctl instead.
     DevConf(Inet6DevConf),
     Stats(Inet6Stats),
+    /// Kernel never sends or accepts `IFLA_INET6_MCAST`, the raw payload is
+    /// kept for completeness.
+    Mcast(Vec<u8>),
     Icmp6Stats(Icmp6Stats),
+    /// Interface identifier used for SLAAC, could be changed by
+    /// `LinkMessage` with `AfSpecUnspec::Inet6` in `IFLA_AF_SPEC`.
     Token(Ipv6Addr),
-    AddrGenMode(u8),
+    /// Could be changed by `LinkMessage` with `AfSpecUnspec::Inet6` in
+    /// `IFLA_AF_SPEC`.
+    AddrGenMode(Inet6AddrGenMode),
     RaMtu(u32),
     Other(DefaultNla),
 }
@@ -69,6 +79,7 @@ impl Nla for AfSpecInet6 {
             DevConf(ref dev_conf) => dev_conf.buffer_len(),
             Stats(ref stats) => stats.buffer_len(),
             Icmp6Stats(ref icmp_stats) => icmp_stats.buffer_len(),
+            Mcast(ref v) => v.len(),
             Flags(_) | RaMtu(_) => 4,
             Token(_) => 16,
             AddrGenMode(_) => 1,
@@ -79,16 +90,18 @@ impl Nla for AfSpecInet6 {
     fn emit_value(&self, buffer: &mut [u8]) {
         use self::AfSpecInet6::*;
         match *self {
-            Flags(ref value) => {
-                NativeEndian::write_u32(buffer, u32::from(value))
-            }
+            Flags(ref value) => NativeEndian::write_u32(
+                buffer,
+                u32::from(&VecInet6IfaceFlag(value.to_vec())),
+            ),
             RaMtu(ref value) => NativeEndian::write_u32(buffer, *value),
             CacheInfo(ref v) => v.emit(buffer),
             DevConf(ref v) => v.emit(buffer),
             Stats(ref v) => v.emit(buffer),
             Icmp6Stats(ref v) => v.emit(buffer),
+            Mcast(ref v) => buffer.copy_from_slice(v.as_slice()),
             Token(v) => buffer.copy_from_slice(&v.octets()),
-            AddrGenMode(value) => buffer[0] = value,
+            AddrGenMode(value) => buffer[0] = value.into(),
             Other(ref nla) => nla.emit_value(buffer),
         }
     }
@@ -100,6 +113,7 @@ impl Nla for AfSpecInet6 {
             CacheInfo(_) => IFLA_INET6_CACHEINFO,
             DevConf(_) => IFLA_INET6_CONF,
             Stats(_) => IFLA_INET6_STATS,
+            Mcast(_) => IFLA_INET6_MCAST,
             Icmp6Stats(_) => IFLA_INET6_ICMP6STATS,
             Token(_) => IFLA_INET6_TOKEN,
             AddrGenMode(_) => IFLA_INET6_ADDR_GEN_MODE,
@@ -115,9 +129,13 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecInet6 {
 
         let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_INET6_FLAGS => Flags(Inet6IfaceFlags::from(
-                parse_u32(payload).context("invalid IFLA_INET6_FLAGS value")?,
-            )),
+            IFLA_INET6_FLAGS => Flags(
+                VecInet6IfaceFlag::from(
+                    parse_u32(payload)
+                        .context("invalid IFLA_INET6_FLAGS value")?,
+                )
+                .0,
+            ),
             IFLA_INET6_CACHEINFO => CacheInfo(
                 Inet6CacheInfo::parse(&Inet6CacheInfoBuffer::new(payload))
                     .context(format!(
@@ -144,6 +162,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecInet6 {
                     format!("invalid IFLA_INET6_STATS value {:?}", payload),
                 )?,
             ),
+            IFLA_INET6_MCAST => Mcast(payload.to_vec()),
             IFLA_INET6_ICMP6STATS => Icmp6Stats(
                 super::super::Icmp6Stats::parse(&Icmp6StatsBuffer::new(
                     payload,
@@ -159,7 +178,8 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecInet6 {
             ),
             IFLA_INET6_ADDR_GEN_MODE => AddrGenMode(
                 parse_u8(payload)
-                    .context("invalid IFLA_INET6_ADDR_GEN_MODE value")?,
+                    .context("invalid IFLA_INET6_ADDR_GEN_MODE value")?
+                    .into(),
             ),
             IFLA_INET6_RA_MTU => RaMtu(
                 parse_u32(payload)
@@ -171,3 +191,58 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecInet6 {
         })
     }
 }
+
+const IN6_ADDR_GEN_MODE_EUI64: u8 = 0;
+const IN6_ADDR_GEN_MODE_NONE: u8 = 1;
+const IN6_ADDR_GEN_MODE_STABLE_PRIVACY: u8 = 2;
+const IN6_ADDR_GEN_MODE_RANDOM: u8 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Inet6AddrGenMode {
+    /// Generate link local address from the EUI-64 of interface
+    Eui64,
+    /// Do not generate link local address
+    None,
+    /// RFC 7217 stable privacy address, requires `stable_secret` set
+    StablePrivacy,
+    /// Stable privacy address with random secret
+    Random,
+    Other(u8),
+}
+
+impl From<u8> for Inet6AddrGenMode {
+    fn from(d: u8) -> Self {
+        match d {
+            IN6_ADDR_GEN_MODE_EUI64 => Self::Eui64,
+            IN6_ADDR_GEN_MODE_NONE => Self::None,
+            IN6_ADDR_GEN_MODE_STABLE_PRIVACY => Self::StablePrivacy,
+            IN6_ADDR_GEN_MODE_RANDOM => Self::Random,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Inet6AddrGenMode> for u8 {
+    fn from(v: Inet6AddrGenMode) -> u8 {
+        match v {
+            Inet6AddrGenMode::Eui64 => IN6_ADDR_GEN_MODE_EUI64,
+            Inet6AddrGenMode::None => IN6_ADDR_GEN_MODE_NONE,
+            Inet6AddrGenMode::StablePrivacy => IN6_ADDR_GEN_MODE_STABLE_PRIVACY,
+            Inet6AddrGenMode::Random => IN6_ADDR_GEN_MODE_RANDOM,
+            Inet6AddrGenMode::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for Inet6AddrGenMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Eui64 => write!(f, "eui64"),
+            Self::None => write!(f, "none"),
+            Self::StablePrivacy => write!(f, "stable_secret"),
+            Self::Random => write!(f, "random"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
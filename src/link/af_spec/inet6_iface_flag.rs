@@ -7,7 +7,7 @@ const IF_RS_SENT: u32 = 0x10;
 const IF_READY: u32 = 0x80000000;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
-pub struct Inet6IfaceFlags(pub Vec<Inet6IfaceFlag>);
+pub(crate) struct VecInet6IfaceFlag(pub(crate) Vec<Inet6IfaceFlag>);
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[non_exhaustive]
@@ -24,11 +24,11 @@ pub enum Inet6IfaceFlag {
 impl From<u32> for Inet6IfaceFlag {
     fn from(d: u32) -> Self {
         match d {
-            d if (d & IF_RA_OTHERCONF) > 0 => Self::Otherconf,
-            d if (d & IF_RA_MANAGED) > 0 => Self::RaManaged,
-            d if (d & IF_RA_RCVD) > 0 => Self::RaRcvd,
-            d if (d & IF_RS_SENT) > 0 => Self::RsSent,
-            d if (d & IF_READY) > 0 => Self::Ready,
+            IF_RA_OTHERCONF => Self::Otherconf,
+            IF_RA_MANAGED => Self::RaManaged,
+            IF_RA_RCVD => Self::RaRcvd,
+            IF_RS_SENT => Self::RsSent,
+            IF_READY => Self::Ready,
             _ => Self::Other(d),
         }
     }
@@ -68,7 +68,7 @@ const ALL_INET_IFACE_FLAGS: [Inet6IfaceFlag; 5] = [
     Inet6IfaceFlag::Ready,
 ];
 
-impl From<u32> for Inet6IfaceFlags {
+impl From<u32> for VecInet6IfaceFlag {
     fn from(d: u32) -> Self {
         let mut got: u32 = 0;
         let mut ret = Vec::new();
@@ -85,8 +85,8 @@ impl From<u32> for Inet6IfaceFlags {
     }
 }
 
-impl From<&Inet6IfaceFlags> for u32 {
-    fn from(v: &Inet6IfaceFlags) -> u32 {
+impl From<&VecInet6IfaceFlag> for u32 {
+    fn from(v: &VecInet6IfaceFlag) -> u32 {
         let mut d: u32 = 0;
         for flag in &v.0 {
             d += u32::from(*flag);
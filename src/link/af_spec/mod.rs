@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: MIT
 
 mod bridge;
+mod bridge_cfm;
+mod bridge_mrp;
 mod inet;
 mod inet6;
 mod inet6_cache;
@@ -8,20 +10,39 @@ mod inet6_devconf;
 mod inet6_icmp;
 mod inet6_iface_flag;
 mod inet6_stats;
+mod mpls;
 mod unspec;
 
-pub use self::bridge::{AfSpecBridge, BridgeVlanInfo};
-pub use self::inet::{AfSpecInet, InetDevConf};
-pub use self::inet6::AfSpecInet6;
+pub use self::bridge::{
+    AfSpecBridge, BridgeFlag, BridgeMode, BridgeVlanInfo, BridgeVlanInfoFlag,
+    BridgeVlanTunnelInfo,
+};
+pub use self::bridge_cfm::{
+    BridgeCfm, BridgeCfmCcCcmTx, BridgeCfmCcConfig, BridgeCfmCcPeerMep,
+    BridgeCfmCcPeerStatus, BridgeCfmCcRdi, BridgeCfmCcmInterval,
+    BridgeCfmDomain, BridgeCfmMepConfig, BridgeCfmMepCreate,
+    BridgeCfmMepDelete, BridgeCfmMepDirection, BridgeCfmMepStatus,
+};
+pub use self::bridge_mrp::{
+    BridgeMrp, BridgeMrpInRole, BridgeMrpInRoleType, BridgeMrpInState,
+    BridgeMrpInStateType, BridgeMrpInfo, BridgeMrpInstance, BridgeMrpPortRole,
+    BridgeMrpPortRoleType, BridgeMrpPortState, BridgeMrpPortStateType,
+    BridgeMrpRingRole, BridgeMrpRingRoleType, BridgeMrpRingState,
+    BridgeMrpRingStateType, BridgeMrpStartInTest, BridgeMrpStartTest,
+};
+pub use self::inet::{AfSpecInet, InetDevConf, InetDevConfKey};
+pub use self::inet6::{AfSpecInet6, Inet6AddrGenMode};
 pub use self::inet6_cache::{Inet6CacheInfo, Inet6CacheInfoBuffer};
 pub use self::inet6_devconf::{Inet6DevConf, Inet6DevConfBuffer};
 pub use self::inet6_icmp::{Icmp6Stats, Icmp6StatsBuffer};
-pub use self::inet6_iface_flag::{Inet6IfaceFlag, Inet6IfaceFlags};
+pub use self::inet6_iface_flag::Inet6IfaceFlag;
 pub use self::inet6_stats::{Inet6Stats, Inet6StatsBuffer};
+pub use self::mpls::{AfSpecMpls, MplsLinkStats, MplsLinkStatsBuffer};
 pub use self::unspec::AfSpecUnspec;
 
 #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
 pub(crate) use self::bridge::VecAfSpecBridge;
 pub(crate) use self::inet::VecAfSpecInet;
 pub(crate) use self::inet6::VecAfSpecInet6;
+pub(crate) use self::mpls::VecAfSpecMpls;
 pub(crate) use self::unspec::VecAfSpecUnspec;
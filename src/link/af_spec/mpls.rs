@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const MPLS_STATS_LINK: u16 = 1;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum AfSpecMpls {
+    Stats(MplsLinkStats),
+    Other(DefaultNla),
+}
+
+pub(crate) struct VecAfSpecMpls(pub(crate) Vec<AfSpecMpls>);
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for VecAfSpecMpls
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        let err = "Invalid AF_MPLS NLA for IFLA_AF_SPEC(AF_UNSPEC)";
+        for nla in NlasIterator::new(buf.into_inner()) {
+            let nla = nla.context(err)?;
+            nlas.push(AfSpecMpls::parse(&nla).context(err)?);
+        }
+        Ok(Self(nlas))
+    }
+}
+
+impl Nla for AfSpecMpls {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Stats(v) => v.buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Stats(v) => v.emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Stats(_) => MPLS_STATS_LINK,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecMpls {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MPLS_STATS_LINK => {
+                let err = format!("invalid MPLS_STATS_LINK value {payload:?}");
+                Self::Stats(
+                    MplsLinkStats::parse(
+                        &MplsLinkStatsBuffer::new_checked(payload)
+                            .context(err.clone())?,
+                    )
+                    .context(err)?,
+                )
+            }
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown AF_MPLS NLA type {kind} for IFLA_AF_SPEC(AF_UNSPEC)"
+            ))?),
+        })
+    }
+}
+
+/// Per-interface MPLS statistics, kernel `struct mpls_link_stats`
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+#[non_exhaustive]
+pub struct MplsLinkStats {
+    /// Total packets received
+    pub rx_packets: u64,
+    /// Total packets transmitted
+    pub tx_packets: u64,
+    /// Total bytes received
+    pub rx_bytes: u64,
+    /// Total bytes transmitted
+    pub tx_bytes: u64,
+    /// Bad packets received
+    pub rx_errors: u64,
+    /// Packet transmit problems
+    pub tx_errors: u64,
+    /// Packet dropped on receive
+    pub rx_dropped: u64,
+    /// Packet dropped on transmit
+    pub tx_dropped: u64,
+    /// Packet received with no route
+    pub rx_noroute: u64,
+}
+
+const MPLS_LINK_STATS_LEN: usize = 72;
+
+buffer!(MplsLinkStatsBuffer(MPLS_LINK_STATS_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    rx_noroute: (u64, 64..72),
+});
+
+impl<T: AsRef<[u8]>> Parseable<MplsLinkStatsBuffer<T>> for MplsLinkStats {
+    fn parse(buf: &MplsLinkStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            rx_noroute: buf.rx_noroute(),
+        })
+    }
+}
+
+impl Emitable for MplsLinkStats {
+    fn buffer_len(&self) -> usize {
+        MPLS_LINK_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = MplsLinkStatsBuffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_rx_noroute(self.rx_noroute);
+    }
+}
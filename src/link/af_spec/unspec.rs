@@ -8,11 +8,15 @@ use netlink_packet_utils::{
 };
 
 use crate::link::{
-    af_spec::{VecAfSpecInet, VecAfSpecInet6},
-    AfSpecInet, AfSpecInet6,
+    af_spec::{VecAfSpecInet, VecAfSpecInet6, VecAfSpecMpls},
+    AfSpecInet, AfSpecInet6, AfSpecMpls,
 };
 use crate::AddressFamily;
 
+// `AddressFamily::Mpls` is only available on Linux, but the layout of
+// IFLA_AF_SPEC is the same everywhere.
+const AF_MPLS: u16 = 28;
+
 // For `AF_UNSPEC`, the `IFLA_AF_SPEC` is two layer array:
 //
 // [{nla_len=408, nla_type=IFLA_AF_SPEC},
@@ -40,6 +44,7 @@ use crate::AddressFamily;
 pub enum AfSpecUnspec {
     Inet(Vec<AfSpecInet>),
     Inet6(Vec<AfSpecInet6>),
+    Mpls(Vec<AfSpecMpls>),
     Other(DefaultNla),
 }
 
@@ -68,6 +73,11 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                             .0,
                     )
                 }
+                AF_MPLS => AfSpecUnspec::Mpls(
+                    VecAfSpecMpls::parse(&NlaBuffer::new(&nla.value()))
+                        .context(err)?
+                        .0,
+                ),
                 kind => AfSpecUnspec::Other(DefaultNla::parse(&nla).context(
                     format!(
                         "Unknown AF_XXX type {kind} for IFLA_AF_SPEC(AF_UNSPEC)"
@@ -84,6 +94,7 @@ impl Nla for AfSpecUnspec {
         match *self {
             Self::Inet(ref nlas) => nlas.as_slice().buffer_len(),
             Self::Inet6(ref nlas) => nlas.as_slice().buffer_len(),
+            Self::Mpls(ref nlas) => nlas.as_slice().buffer_len(),
             Self::Other(ref nla) => nla.value_len(),
         }
     }
@@ -92,6 +103,7 @@ impl Nla for AfSpecUnspec {
         match *self {
             Self::Inet(ref nlas) => nlas.as_slice().emit(buffer),
             Self::Inet6(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Mpls(ref nlas) => nlas.as_slice().emit(buffer),
             Self::Other(ref nla) => nla.emit_value(buffer),
         }
     }
@@ -100,6 +112,7 @@ impl Nla for AfSpecUnspec {
         match *self {
             Self::Inet(_) => u8::from(AddressFamily::Inet) as u16,
             Self::Inet6(_) => u8::from(AddressFamily::Inet6) as u16,
+            Self::Mpls(_) => AF_MPLS,
             Self::Other(ref nla) => nla.kind(),
         }
     }
@@ -24,11 +24,13 @@ use super::{
     sriov::{VecLinkVfInfo, VecLinkVfPort},
     stats::LINK_STATS_LEN,
     stats64::LINK_STATS64_LEN,
+    wireless::VecLinkWirelessEvent,
     xdp::VecLinkXdp,
-    AfSpecBridge, AfSpecUnspec, LinkEvent, LinkExtentMask, LinkInfo,
-    LinkPhysId, LinkProtoInfoBridge, LinkProtoInfoInet6,
-    LinkProtocolDownReason, LinkVfInfo, LinkVfPort, LinkWirelessEvent, LinkXdp,
-    Map, MapBuffer, Prop, State, Stats, Stats64, Stats64Buffer, StatsBuffer,
+    AfSpecBridge, AfSpecUnspec, LinkDevlinkPort, LinkDpllPin, LinkEvent,
+    LinkExtentMask, LinkInfo, LinkPhysId, LinkProtoInfoBridge,
+    LinkProtoInfoInet6, LinkProtocolDownReason, LinkVfInfo, LinkVfPort,
+    LinkWirelessEvent, LinkXdp, Map, MapBuffer, Prop, State, Stats, Stats64,
+    Stats64Buffer, StatsBuffer,
 };
 use crate::AddressFamily;
 
@@ -89,8 +91,6 @@ const IFLA_MAX_MTU: u16 = 51;
 const IFLA_PROP_LIST: u16 = 52;
 const IFLA_PERM_ADDRESS: u16 = 54;
 const IFLA_PROTO_DOWN_REASON: u16 = 55;
-
-/* TODO:(Gris Ge)
 const IFLA_PARENT_DEV_NAME: u16 = 56;
 const IFLA_PARENT_DEV_BUS_NAME: u16 = 57;
 const IFLA_GRO_MAX_SIZE: u16 = 58;
@@ -98,7 +98,10 @@ const IFLA_TSO_MAX_SIZE: u16 = 59;
 const IFLA_TSO_MAX_SEGS: u16 = 60;
 const IFLA_ALLMULTI: u16 = 61;
 const IFLA_DEVLINK_PORT: u16 = 62;
-*/
+const IFLA_GSO_IPV4_MAX_SIZE: u16 = 63;
+const IFLA_GRO_IPV4_MAX_SIZE: u16 = 64;
+const IFLA_DPLL_PIN: u16 = 65;
+const IFLA_MAX_PDU: u16 = 66;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -116,7 +119,7 @@ pub enum LinkAttribute {
     CarrierDownCount(u32),
     NewIfIndex(i32),
     LinkInfo(Vec<LinkInfo>),
-    Wireless(LinkWirelessEvent),
+    Wireless(Vec<LinkWirelessEvent>),
     ProtoInfoBridge(Vec<LinkProtoInfoBridge>),
     ProtoInfoInet6(Vec<LinkProtoInfoInet6>),
     ProtoInfoUnknown(DefaultNla),
@@ -153,6 +156,21 @@ pub enum LinkAttribute {
     MinMtu(u32),
     /// The maximum MTU for the device.
     MaxMtu(u32),
+    /// Name of the parent device, e.g. the PCI address `0000:03:00.0`
+    ParentDevName(String),
+    /// Bus name of the parent device, e.g. `pci`
+    ParentDevBusName(String),
+    GroMaxSize(u32),
+    TsoMaxSize(u32),
+    TsoMaxSegs(u32),
+    /// Allmulti count of the device.
+    Allmulti(u32),
+    DevlinkPort(Vec<LinkDevlinkPort>),
+    GsoIpv4MaxSize(u32),
+    GroIpv4MaxSize(u32),
+    DpllPin(Vec<LinkDpllPin>),
+    /// Maximum size of protocol data unit of the device.
+    MaxPdu(u32),
     NetnsId(i32),
     OperState(State),
     Stats(Stats),
@@ -175,7 +193,7 @@ impl Nla for LinkAttribute {
             Self::PhysPortId(v) => v.buffer_len(),
             Self::PhysSwitchId(v) => v.buffer_len(),
             Self::Event(v) => v.buffer_len(),
-            Self::Wireless(v) => v.buffer_len(),
+            Self::Wireless(v) => v.iter().map(|e| e.buffer_len()).sum(),
             Self::ProtoInfoBridge(v) => v.as_slice().buffer_len(),
             Self::ProtoInfoInet6(v) => v.as_slice().buffer_len(),
             Self::ProtoDownReason(v) => v.as_slice().buffer_len(),
@@ -188,7 +206,9 @@ impl Nla for LinkAttribute {
             Self::IfName(string)
             | Self::Qdisc(string)
             | Self::IfAlias(string)
-            | Self::PhysPortName(string) => string.as_bytes().len() + 1,
+            | Self::PhysPortName(string)
+            | Self::ParentDevName(string)
+            | Self::ParentDevBusName(string) => string.as_bytes().len() + 1,
 
             Self::Mode(_) | Self::Carrier(_) | Self::ProtoDown(_) => 1,
 
@@ -214,7 +234,14 @@ impl Nla for LinkAttribute {
             | Self::CarrierUpCount(_)
             | Self::CarrierDownCount(_)
             | Self::NewIfIndex(_)
-            | Self::MaxMtu(_) => 4,
+            | Self::MaxMtu(_)
+            | Self::GroMaxSize(_)
+            | Self::TsoMaxSize(_)
+            | Self::TsoMaxSegs(_)
+            | Self::Allmulti(_)
+            | Self::GsoIpv4MaxSize(_)
+            | Self::GroIpv4MaxSize(_)
+            | Self::MaxPdu(_) => 4,
 
             Self::OperState(_) => 1,
             Self::Stats(_) => LINK_STATS_LEN,
@@ -223,6 +250,8 @@ impl Nla for LinkAttribute {
             Self::LinkInfo(nlas) => nlas.as_slice().buffer_len(),
             Self::Xdp(nlas) => nlas.as_slice().buffer_len(),
             Self::PropList(nlas) => nlas.as_slice().buffer_len(),
+            Self::DevlinkPort(nlas) => nlas.as_slice().buffer_len(),
+            Self::DpllPin(nlas) => nlas.as_slice().buffer_len(),
             Self::AfSpecUnspec(nlas) => nlas.as_slice().buffer_len(),
             Self::AfSpecBridge(nlas) => nlas.as_slice().buffer_len(),
             Self::ProtoInfoUnknown(attr) => attr.value_len(),
@@ -238,7 +267,14 @@ impl Nla for LinkAttribute {
             Self::PhysPortId(v) => v.emit(buffer),
             Self::PhysSwitchId(v) => v.emit(buffer),
             Self::Event(v) => v.emit(buffer),
-            Self::Wireless(v) => v.emit(buffer),
+            Self::Wireless(v) => {
+                let mut offset = 0;
+                for event in v {
+                    let len = event.buffer_len();
+                    event.emit(&mut buffer[offset..offset + len]);
+                    offset += len;
+                }
+            }
             Self::ProtoInfoBridge(v) => v.as_slice().emit(buffer),
             Self::ProtoInfoInet6(v) => v.as_slice().emit(buffer),
             Self::ProtoDownReason(v) => v.as_slice().emit(buffer),
@@ -252,7 +288,9 @@ impl Nla for LinkAttribute {
             Self::IfName(string)
             | Self::Qdisc(string)
             | Self::IfAlias(string)
-            | Self::PhysPortName(string) => {
+            | Self::PhysPortName(string)
+            | Self::ParentDevName(string)
+            | Self::ParentDevBusName(string) => {
                 buffer[..string.len()].copy_from_slice(string.as_bytes());
                 buffer[string.len()] = 0;
             }
@@ -277,7 +315,14 @@ impl Nla for LinkAttribute {
             | Self::GsoMaxSegs(value)
             | Self::GsoMaxSize(value)
             | Self::MinMtu(value)
-            | Self::MaxMtu(value) => NativeEndian::write_u32(buffer, *value),
+            | Self::MaxMtu(value)
+            | Self::GroMaxSize(value)
+            | Self::TsoMaxSize(value)
+            | Self::TsoMaxSegs(value)
+            | Self::Allmulti(value)
+            | Self::GsoIpv4MaxSize(value)
+            | Self::GroIpv4MaxSize(value)
+            | Self::MaxPdu(value) => NativeEndian::write_u32(buffer, *value),
 
             Self::ExtMask(value) => NativeEndian::write_u32(
                 buffer,
@@ -296,6 +341,8 @@ impl Nla for LinkAttribute {
             Self::LinkInfo(nlas) => nlas.as_slice().emit(buffer),
             Self::Xdp(nlas) => nlas.as_slice().emit(buffer),
             Self::PropList(nlas) => nlas.as_slice().emit(buffer),
+            Self::DevlinkPort(nlas) => nlas.as_slice().emit(buffer),
+            Self::DpllPin(nlas) => nlas.as_slice().emit(buffer),
             Self::AfSpecUnspec(nlas) => nlas.as_slice().emit(buffer),
             Self::AfSpecBridge(nlas) => nlas.as_slice().emit(buffer),
             Self::ProtoInfoUnknown(attr) | Self::Other(attr) => {
@@ -351,6 +398,17 @@ impl Nla for LinkAttribute {
             Self::GsoMaxSize(_) => IFLA_GSO_MAX_SIZE,
             Self::MinMtu(_) => IFLA_MIN_MTU,
             Self::MaxMtu(_) => IFLA_MAX_MTU,
+            Self::ParentDevName(_) => IFLA_PARENT_DEV_NAME,
+            Self::ParentDevBusName(_) => IFLA_PARENT_DEV_BUS_NAME,
+            Self::GroMaxSize(_) => IFLA_GRO_MAX_SIZE,
+            Self::TsoMaxSize(_) => IFLA_TSO_MAX_SIZE,
+            Self::TsoMaxSegs(_) => IFLA_TSO_MAX_SEGS,
+            Self::Allmulti(_) => IFLA_ALLMULTI,
+            Self::DevlinkPort(_) => IFLA_DEVLINK_PORT | NLA_F_NESTED,
+            Self::GsoIpv4MaxSize(_) => IFLA_GSO_IPV4_MAX_SIZE,
+            Self::GroIpv4MaxSize(_) => IFLA_GRO_IPV4_MAX_SIZE,
+            Self::DpllPin(_) => IFLA_DPLL_PIN | NLA_F_NESTED,
+            Self::MaxPdu(_) => IFLA_MAX_PDU,
             Self::NetnsId(_) => IFLA_LINK_NETNSID,
             Self::OperState(_) => IFLA_OPERSTATE,
             Self::Map(_) => IFLA_MAP,
@@ -398,8 +456,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 )?)
             }
             IFLA_WIRELESS => Self::Wireless(
-                LinkWirelessEvent::parse(payload)
-                    .context(format!("invalid IFLA_WIRELESS {payload:?}"))?,
+                VecLinkWirelessEvent::parse(payload)
+                    .context(format!("invalid IFLA_WIRELESS {payload:?}"))?
+                    .0,
             ),
             IFLA_PROTINFO => match interface_family {
                 AddressFamily::Inet6 => Self::ProtoInfoInet6(
@@ -556,6 +615,58 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
             IFLA_MAX_MTU => Self::MaxMtu(
                 parse_u32(payload).context("invalid IFLA_MAX_MTU value")?,
             ),
+            IFLA_PARENT_DEV_NAME => Self::ParentDevName(
+                parse_string(payload)
+                    .context("invalid IFLA_PARENT_DEV_NAME value")?,
+            ),
+            IFLA_PARENT_DEV_BUS_NAME => Self::ParentDevBusName(
+                parse_string(payload)
+                    .context("invalid IFLA_PARENT_DEV_BUS_NAME value")?,
+            ),
+            IFLA_GRO_MAX_SIZE => Self::GroMaxSize(
+                parse_u32(payload)
+                    .context("invalid IFLA_GRO_MAX_SIZE value")?,
+            ),
+            IFLA_TSO_MAX_SIZE => Self::TsoMaxSize(
+                parse_u32(payload)
+                    .context("invalid IFLA_TSO_MAX_SIZE value")?,
+            ),
+            IFLA_TSO_MAX_SEGS => Self::TsoMaxSegs(
+                parse_u32(payload)
+                    .context("invalid IFLA_TSO_MAX_SEGS value")?,
+            ),
+            IFLA_ALLMULTI => Self::Allmulti(
+                parse_u32(payload).context("invalid IFLA_ALLMULTI value")?,
+            ),
+            IFLA_DEVLINK_PORT => {
+                let err = "invalid IFLA_DEVLINK_PORT value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    nlas.push(LinkDevlinkPort::parse(nla).context(err)?);
+                }
+                Self::DevlinkPort(nlas)
+            }
+            IFLA_GSO_IPV4_MAX_SIZE => Self::GsoIpv4MaxSize(
+                parse_u32(payload)
+                    .context("invalid IFLA_GSO_IPV4_MAX_SIZE value")?,
+            ),
+            IFLA_GRO_IPV4_MAX_SIZE => Self::GroIpv4MaxSize(
+                parse_u32(payload)
+                    .context("invalid IFLA_GRO_IPV4_MAX_SIZE value")?,
+            ),
+            IFLA_DPLL_PIN => {
+                let err = "invalid IFLA_DPLL_PIN value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    nlas.push(LinkDpllPin::parse(nla).context(err)?);
+                }
+                Self::DpllPin(nlas)
+            }
+            IFLA_MAX_PDU => Self::MaxPdu(
+                parse_u32(payload).context("invalid IFLA_MAX_PDU value")?,
+            ),
             IFLA_LINK_NETNSID => Self::NetnsId(
                 parse_i32(payload)
                     .context("invalid IFLA_LINK_NETNSID value")?,
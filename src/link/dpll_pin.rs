@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+const DPLL_A_PIN_ID: u16 = 1;
+
+/// DPLL pin handle of the netdev, nested in `IFLA_DPLL_PIN`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum LinkDpllPin {
+    Id(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkDpllPin {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => DPLL_A_PIN_ID,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for LinkDpllPin {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DPLL_A_PIN_ID => Self::Id(
+                parse_u32(payload).context("invalid DPLL_A_PIN_ID value")?,
+            ),
+            kind => Self::Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_DPLL_PIN"
+            ))?),
+        })
+    }
+}
@@ -15,8 +15,8 @@ const IFLA_IPVLAN_FLAGS: u16 = 2;
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoIpVlan {
-    Mode(u16),
-    Flags(u16),
+    Mode(IpVlanMode),
+    Flags(Vec<IpVlanFlag>),
     Other(DefaultNla),
 }
 
@@ -32,8 +32,11 @@ impl Nla for InfoIpVlan {
     fn emit_value(&self, buffer: &mut [u8]) {
         use self::InfoIpVlan::*;
         match self {
-            Mode(value) => NativeEndian::write_u16(buffer, *value),
-            Flags(value) => NativeEndian::write_u16(buffer, *value),
+            Mode(value) => NativeEndian::write_u16(buffer, (*value).into()),
+            Flags(flags) => NativeEndian::write_u16(
+                buffer,
+                u16::from(&VecIpVlanFlag(flags.to_vec())),
+            ),
             Other(nla) => nla.emit_value(buffer),
         }
     }
@@ -54,11 +57,16 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpVlan {
         let payload = buf.value();
         Ok(match buf.kind() {
             IFLA_IPVLAN_MODE => Mode(
-                parse_u16(payload).context("invalid IFLA_IPVLAN_MODE value")?,
+                parse_u16(payload)
+                    .context("invalid IFLA_IPVLAN_MODE value")?
+                    .into(),
             ),
             IFLA_IPVLAN_FLAGS => Flags(
-                parse_u16(payload)
-                    .context("invalid IFLA_IPVLAN_FLAGS value")?,
+                VecIpVlanFlag::from(
+                    parse_u16(payload)
+                        .context("invalid IFLA_IPVLAN_FLAGS value")?,
+                )
+                .0,
             ),
             kind => Other(DefaultNla::parse(buf).context(format!(
                 "unknown NLA type {kind} for IFLA_INFO_DATA(ipvlan)"
@@ -66,3 +74,186 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpVlan {
         })
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoIpVtap {
+    Mode(IpVlanMode),
+    Flags(Vec<IpVlanFlag>),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoIpVtap {
+    fn value_len(&self) -> usize {
+        use self::InfoIpVtap::*;
+        match self {
+            Mode(_) | Flags(_) => 2,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoIpVtap::*;
+        match self {
+            Mode(value) => NativeEndian::write_u16(buffer, (*value).into()),
+            Flags(flags) => NativeEndian::write_u16(
+                buffer,
+                u16::from(&VecIpVlanFlag(flags.to_vec())),
+            ),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoIpVtap::*;
+        match self {
+            Mode(_) => IFLA_IPVLAN_MODE,
+            Flags(_) => IFLA_IPVLAN_FLAGS,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpVtap {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoIpVtap::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_IPVLAN_MODE => Mode(
+                parse_u16(payload)
+                    .context("invalid IFLA_IPVLAN_MODE value")?
+                    .into(),
+            ),
+            IFLA_IPVLAN_FLAGS => Flags(
+                VecIpVlanFlag::from(
+                    parse_u16(payload)
+                        .context("invalid IFLA_IPVLAN_FLAGS value")?,
+                )
+                .0,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!(
+                "unknown NLA type {kind} for IFLA_INFO_DATA(ipvtap)"
+            ))?),
+        })
+    }
+}
+
+const IPVLAN_MODE_L2: u16 = 0;
+const IPVLAN_MODE_L3: u16 = 1;
+const IPVLAN_MODE_L3S: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum IpVlanMode {
+    L2,
+    L3,
+    L3S,
+    Other(u16),
+}
+
+impl From<u16> for IpVlanMode {
+    fn from(d: u16) -> Self {
+        match d {
+            IPVLAN_MODE_L2 => Self::L2,
+            IPVLAN_MODE_L3 => Self::L3,
+            IPVLAN_MODE_L3S => Self::L3S,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<IpVlanMode> for u16 {
+    fn from(d: IpVlanMode) -> Self {
+        match d {
+            IpVlanMode::L2 => IPVLAN_MODE_L2,
+            IpVlanMode::L3 => IPVLAN_MODE_L3,
+            IpVlanMode::L3S => IPVLAN_MODE_L3S,
+            IpVlanMode::Other(value) => value,
+        }
+    }
+}
+
+impl std::fmt::Display for IpVlanMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::L2 => write!(f, "l2"),
+            Self::L3 => write!(f, "l3"),
+            Self::L3S => write!(f, "l3s"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+// Kernel has no constant for bridge mode, it is the absence of
+// IPVLAN_F_PRIVATE and IPVLAN_F_VEPA.
+const IPVLAN_F_BRIDGE: u16 = 0;
+const IPVLAN_F_PRIVATE: u16 = 1 << 0;
+const IPVLAN_F_VEPA: u16 = 1 << 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum IpVlanFlag {
+    /// Default mode, only reported when neither `Private` nor `Vepa` is set.
+    Bridge,
+    Private,
+    Vepa,
+    Other(u16),
+}
+
+impl From<IpVlanFlag> for u16 {
+    fn from(v: IpVlanFlag) -> u16 {
+        match v {
+            IpVlanFlag::Bridge => IPVLAN_F_BRIDGE,
+            IpVlanFlag::Private => IPVLAN_F_PRIVATE,
+            IpVlanFlag::Vepa => IPVLAN_F_VEPA,
+            IpVlanFlag::Other(i) => i,
+        }
+    }
+}
+
+impl std::fmt::Display for IpVlanFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bridge => write!(f, "bridge"),
+            Self::Private => write!(f, "private"),
+            Self::Vepa => write!(f, "vepa"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const ALL_IPVLAN_FLAGS: [IpVlanFlag; 2] =
+    [IpVlanFlag::Private, IpVlanFlag::Vepa];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecIpVlanFlag(Vec<IpVlanFlag>);
+
+impl From<u16> for VecIpVlanFlag {
+    fn from(d: u16) -> Self {
+        if d == IPVLAN_F_BRIDGE {
+            return Self(vec![IpVlanFlag::Bridge]);
+        }
+        let mut got: u16 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_IPVLAN_FLAGS {
+            if (d & (u16::from(flag))) > 0 {
+                ret.push(flag);
+                got += u16::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(IpVlanFlag::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecIpVlanFlag> for u16 {
+    fn from(v: &VecIpVlanFlag) -> u16 {
+        let mut d: u16 = 0;
+        for flag in &v.0 {
+            d |= u16::from(*flag);
+        }
+        d
+    }
+}
@@ -39,7 +39,7 @@ pub use self::info_data::InfoData;
 pub use self::info_port::{InfoPortData, InfoPortKind};
 pub use self::infos::{InfoKind, LinkInfo};
 pub use self::ipoib::InfoIpoib;
-pub use self::ipvlan::InfoIpVlan;
+pub use self::ipvlan::{InfoIpVlan, InfoIpVtap, IpVlanFlag, IpVlanMode};
 pub use self::mac_vlan::{InfoMacVlan, InfoMacVtap};
 pub use self::macsec::{
     InfoMacSec, MacSecCipherId, MacSecOffload, MacSecValidate,
@@ -47,7 +47,7 @@ pub use self::macsec::{
 pub use self::sit::InfoSitTun;
 pub use self::tun::InfoTun;
 pub use self::veth::InfoVeth;
-pub use self::vlan::{InfoVlan, VlanQosMapping};
+pub use self::vlan::{InfoVlan, VlanFlag, VlanFlagMask, VlanQosMapping};
 pub use self::vrf::InfoVrf;
 pub use self::vti::InfoVti;
 pub use self::vxlan::InfoVxlan;
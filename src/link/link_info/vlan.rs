@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+use std::collections::HashMap;
+
 use anyhow::Context;
 use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use netlink_packet_utils::{
@@ -23,7 +25,9 @@ const IFLA_VLAN_QOS_MAPPING: u16 = 1;
 #[non_exhaustive]
 pub enum InfoVlan {
     Id(u16),
-    Flags((u32, u32)),
+    /// Tuple (flags, mask), only flags included in mask will be changed
+    /// by kernel.
+    Flags((Vec<VlanFlag>, VlanFlagMask)),
     EgressQos(Vec<VlanQosMapping>),
     IngressQos(Vec<VlanQosMapping>),
     Protocol(VlanProtocol),
@@ -51,9 +55,12 @@ impl Nla for InfoVlan {
             Self::Protocol(value) => {
                 BigEndian::write_u16(buffer, (*value).into())
             }
-            Self::Flags(flags) => {
-                NativeEndian::write_u32(&mut buffer[0..4], flags.0);
-                NativeEndian::write_u32(&mut buffer[4..8], flags.1)
+            Self::Flags((flags, mask)) => {
+                NativeEndian::write_u32(
+                    &mut buffer[0..4],
+                    u32::from(&VecVlanFlag(flags.to_vec())),
+                );
+                NativeEndian::write_u32(&mut buffer[4..8], mask.0)
             }
             Self::Other(v) => v.emit_value(buffer),
         }
@@ -79,6 +86,92 @@ pub enum VlanQosMapping {
     Other(DefaultNla),
 }
 
+// Maximum value of 3 bits Priority Code Point in VLAN tag
+const VLAN_PRIO_MAX: u32 = 7;
+
+impl VlanQosMapping {
+    /// Convert ingress map of VLAN priority(0-7) to Linux socket buffer
+    /// priority into mappings of `InfoVlan::IngressQos`. Equivalent to
+    /// `ingress-qos-map` of `ip link`.
+    pub fn from_ingress_map(
+        map: &HashMap<u8, u32>,
+    ) -> Result<Vec<Self>, DecodeError> {
+        let mut mappings = Vec::new();
+        for (from, to) in map {
+            validate_vlan_prio(u32::from(*from))?;
+            mappings.push(Self::Mapping(u32::from(*from), *to));
+        }
+        mappings.sort_unstable_by_key(Self::sort_key);
+        Ok(mappings)
+    }
+
+    /// Convert mappings of `InfoVlan::IngressQos` into map of VLAN
+    /// priority to Linux socket buffer priority. `VlanQosMapping::Other`
+    /// is ignored.
+    pub fn to_ingress_map(
+        mappings: &[Self],
+    ) -> Result<HashMap<u8, u32>, DecodeError> {
+        let mut map = HashMap::new();
+        for mapping in mappings {
+            if let Self::Mapping(from, to) = mapping {
+                validate_vlan_prio(*from)?;
+                map.insert(*from as u8, *to);
+            }
+        }
+        Ok(map)
+    }
+
+    /// Convert egress map of Linux socket buffer priority to VLAN
+    /// priority(0-7) into mappings of `InfoVlan::EgressQos`. Equivalent to
+    /// `egress-qos-map` of `ip link`.
+    pub fn from_egress_map(
+        map: &HashMap<u32, u8>,
+    ) -> Result<Vec<Self>, DecodeError> {
+        let mut mappings = Vec::new();
+        for (from, to) in map {
+            validate_vlan_prio(u32::from(*to))?;
+            mappings.push(Self::Mapping(*from, u32::from(*to)));
+        }
+        mappings.sort_unstable_by_key(Self::sort_key);
+        Ok(mappings)
+    }
+
+    /// Convert mappings of `InfoVlan::EgressQos` into map of Linux socket
+    /// buffer priority to VLAN priority. `VlanQosMapping::Other` is
+    /// ignored.
+    pub fn to_egress_map(
+        mappings: &[Self],
+    ) -> Result<HashMap<u32, u8>, DecodeError> {
+        let mut map = HashMap::new();
+        for mapping in mappings {
+            if let Self::Mapping(from, to) = mapping {
+                validate_vlan_prio(*to)?;
+                map.insert(*from, *to as u8);
+            }
+        }
+        Ok(map)
+    }
+
+    fn sort_key(&self) -> u32 {
+        match self {
+            Self::Mapping(from, _) => *from,
+            Self::Other(_) => u32::MAX,
+        }
+    }
+}
+
+fn validate_vlan_prio(prio: u32) -> Result<(), DecodeError> {
+    if prio > VLAN_PRIO_MAX {
+        Err(format!(
+            "Invalid VLAN priority {prio}, should be in range of \
+            0-{VLAN_PRIO_MAX}"
+        )
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
 impl Nla for VlanQosMapping {
     fn value_len(&self) -> usize {
         match self {
@@ -149,7 +242,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVlan {
                 }
                 let flags = parse_u32(&payload[0..4]).context(err)?;
                 let mask = parse_u32(&payload[4..]).context(err)?;
-                Flags((flags, mask))
+                Flags((VecVlanFlag::from(flags).0, VlanFlagMask(mask)))
             }
             IFLA_VLAN_EGRESS_QOS => EgressQos(
                 parse_mappings(payload)
@@ -171,3 +264,130 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVlan {
         })
     }
 }
+
+const VLAN_FLAG_REORDER_HDR: u32 = 1 << 0;
+const VLAN_FLAG_GVRP: u32 = 1 << 1;
+const VLAN_FLAG_LOOSE_BINDING: u32 = 1 << 2;
+const VLAN_FLAG_MVRP: u32 = 1 << 3;
+const VLAN_FLAG_BRIDGE_BINDING: u32 = 1 << 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum VlanFlag {
+    /// Ethernet headers are reordered
+    ReorderHdr,
+    /// GARP VLAN Registration Protocol
+    Gvrp,
+    /// VLAN device operating state is not bound to its lower device
+    LooseBinding,
+    /// Multiple VLAN Registration Protocol
+    Mvrp,
+    /// VLAN device link state tracks the state of bridge ports that are
+    /// members of the VLAN
+    BridgeBinding,
+    Other(u32),
+}
+
+impl From<VlanFlag> for u32 {
+    fn from(v: VlanFlag) -> u32 {
+        match v {
+            VlanFlag::ReorderHdr => VLAN_FLAG_REORDER_HDR,
+            VlanFlag::Gvrp => VLAN_FLAG_GVRP,
+            VlanFlag::LooseBinding => VLAN_FLAG_LOOSE_BINDING,
+            VlanFlag::Mvrp => VLAN_FLAG_MVRP,
+            VlanFlag::BridgeBinding => VLAN_FLAG_BRIDGE_BINDING,
+            VlanFlag::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for VlanFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReorderHdr => write!(f, "reorder_hdr"),
+            Self::Gvrp => write!(f, "gvrp"),
+            Self::LooseBinding => write!(f, "loose_binding"),
+            Self::Mvrp => write!(f, "mvrp"),
+            Self::BridgeBinding => write!(f, "bridge_binding"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const ALL_VLAN_FLAGS: [VlanFlag; 5] = [
+    VlanFlag::ReorderHdr,
+    VlanFlag::Gvrp,
+    VlanFlag::LooseBinding,
+    VlanFlag::Mvrp,
+    VlanFlag::BridgeBinding,
+];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecVlanFlag(Vec<VlanFlag>);
+
+impl From<u32> for VecVlanFlag {
+    fn from(d: u32) -> Self {
+        let mut got: u32 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_VLAN_FLAGS {
+            if (d & (u32::from(flag))) > 0 {
+                ret.push(flag);
+                got += u32::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(VlanFlag::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecVlanFlag> for u32 {
+    fn from(v: &VecVlanFlag) -> u32 {
+        let mut d: u32 = 0;
+        for flag in &v.0 {
+            d |= u32::from(*flag);
+        }
+        d
+    }
+}
+
+/// Mask of `InfoVlan::Flags` holding raw bits, kernel always reply with
+/// all bits set.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct VlanFlagMask(pub u32);
+
+impl VlanFlagMask {
+    /// Change all flags, the value used by kernel in reply.
+    pub const ALL: Self = Self(u32::MAX);
+
+    pub fn new(flags: &[VlanFlag]) -> Self {
+        Self(u32::from(&VecVlanFlag(flags.to_vec())))
+    }
+
+    pub fn contains(&self, flag: VlanFlag) -> bool {
+        let d = u32::from(flag);
+        d != 0 && self.0 & d == d
+    }
+
+    /// Known flags included in this mask, unknown bits are ignored.
+    pub fn flags(&self) -> Vec<VlanFlag> {
+        ALL_VLAN_FLAGS
+            .iter()
+            .filter(|f| self.contains(**f))
+            .copied()
+            .collect()
+    }
+}
+
+impl From<u32> for VlanFlagMask {
+    fn from(d: u32) -> Self {
+        Self(d)
+    }
+}
+
+impl From<VlanFlagMask> for u32 {
+    fn from(v: VlanFlagMask) -> u32 {
+        v.0
+    }
+}
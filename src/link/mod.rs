@@ -3,7 +3,9 @@
 mod af_spec;
 mod attribute;
 mod buffer_tool;
+mod devlink_port;
 mod down_reason;
+mod dpll_pin;
 mod event;
 pub(crate) mod ext_mask;
 mod header;
@@ -26,13 +28,26 @@ mod xdp;
 mod tests;
 
 pub use self::af_spec::{
-    AfSpecBridge, AfSpecInet, AfSpecInet6, AfSpecUnspec, BridgeVlanInfo,
-    Icmp6Stats, Icmp6StatsBuffer, Inet6CacheInfo, Inet6CacheInfoBuffer,
-    Inet6DevConf, Inet6DevConfBuffer, Inet6IfaceFlag, Inet6IfaceFlags,
-    Inet6Stats, Inet6StatsBuffer, InetDevConf,
+    AfSpecBridge, AfSpecInet, AfSpecInet6, AfSpecMpls, AfSpecUnspec, BridgeCfm,
+    BridgeCfmCcCcmTx, BridgeCfmCcConfig, BridgeCfmCcPeerMep,
+    BridgeCfmCcPeerStatus, BridgeCfmCcRdi, BridgeCfmCcmInterval,
+    BridgeCfmDomain, BridgeCfmMepConfig, BridgeCfmMepCreate,
+    BridgeCfmMepDelete, BridgeCfmMepDirection, BridgeCfmMepStatus, BridgeFlag,
+    BridgeMode, BridgeMrp, BridgeMrpInRole, BridgeMrpInRoleType,
+    BridgeMrpInState, BridgeMrpInStateType, BridgeMrpInfo, BridgeMrpInstance,
+    BridgeMrpPortRole, BridgeMrpPortRoleType, BridgeMrpPortState,
+    BridgeMrpPortStateType, BridgeMrpRingRole, BridgeMrpRingRoleType,
+    BridgeMrpRingState, BridgeMrpRingStateType, BridgeMrpStartInTest,
+    BridgeMrpStartTest, BridgeVlanInfo, BridgeVlanInfoFlag,
+    BridgeVlanTunnelInfo, Icmp6Stats, Icmp6StatsBuffer, Inet6AddrGenMode,
+    Inet6CacheInfo, Inet6CacheInfoBuffer, Inet6DevConf, Inet6DevConfBuffer,
+    Inet6IfaceFlag, Inet6Stats, Inet6StatsBuffer, InetDevConf, InetDevConfKey,
+    MplsLinkStats, MplsLinkStatsBuffer,
 };
 pub use self::attribute::LinkAttribute;
+pub use self::devlink_port::LinkDevlinkPort;
 pub use self::down_reason::LinkProtocolDownReason;
+pub use self::dpll_pin::LinkDpllPin;
 pub use self::event::LinkEvent;
 pub use self::ext_mask::LinkExtentMask;
 pub use self::header::{LinkHeader, LinkMessageBuffer};
@@ -40,10 +55,11 @@ pub use self::link_flag::LinkFlag;
 pub use self::link_info::{
     BondAdInfo, BondPortState, BridgeQuerierState, HsrProtocol, InfoBond,
     InfoBondPort, InfoBridge, InfoData, InfoGreTap, InfoGreTap6, InfoGreTun,
-    InfoGreTun6, InfoGtp, InfoHsr, InfoIpVlan, InfoIpoib, InfoKind, InfoMacSec,
-    InfoMacVlan, InfoMacVtap, InfoPortData, InfoPortKind, InfoSitTun, InfoTun,
-    InfoVeth, InfoVlan, InfoVrf, InfoVti, InfoVxlan, InfoXfrm, LinkInfo,
-    LinkXstats, MacSecCipherId, MacSecOffload, MacSecValidate, MiiStatus,
+    InfoGreTun6, InfoGtp, InfoHsr, InfoIpVlan, InfoIpVtap, InfoIpoib, InfoKind,
+    InfoMacSec, InfoMacVlan, InfoMacVtap, InfoPortData, InfoPortKind,
+    InfoSitTun, InfoTun, InfoVeth, InfoVlan, InfoVrf, InfoVti, InfoVxlan,
+    InfoXfrm, IpVlanFlag, IpVlanMode, LinkInfo, LinkXstats, MacSecCipherId,
+    MacSecOffload, MacSecValidate, MiiStatus, VlanFlag, VlanFlagMask,
     VlanQosMapping,
 };
 pub use self::link_layer_type::LinkLayerType;
@@ -54,16 +70,19 @@ pub use self::phys_id::LinkPhysId;
 pub use self::prop_list::Prop;
 pub use self::proto_info::{LinkProtoInfoBridge, LinkProtoInfoInet6};
 pub use self::sriov::{
-    LinkVfInfo, LinkVfPort, VfInfo, VfInfoBroadcast, VfInfoBroadcastBuffer,
-    VfInfoGuid, VfInfoGuidBuffer, VfInfoLinkState, VfInfoLinkStateBuffer,
-    VfInfoMac, VfInfoMacBuffer, VfInfoRate, VfInfoRateBuffer, VfInfoRssQueryEn,
-    VfInfoRssQueryEnBuffer, VfInfoSpoofCheck, VfInfoSpoofCheckBuffer,
-    VfInfoTrust, VfInfoTrustBuffer, VfInfoTxRate, VfInfoTxRateBuffer,
-    VfInfoVlan, VfInfoVlanBuffer, VfLinkState, VfPort, VfStats, VfVlan,
-    VfVlanInfo,
+    LinkVfInfo, LinkVfPort, VfConfig, VfInfo, VfInfoBroadcast,
+    VfInfoBroadcastBuffer, VfInfoGuid, VfInfoGuidBuffer, VfInfoLinkState,
+    VfInfoLinkStateBuffer, VfInfoMac, VfInfoMacBuffer, VfInfoRate,
+    VfInfoRateBuffer, VfInfoRssQueryEn, VfInfoRssQueryEnBuffer,
+    VfInfoSpoofCheck, VfInfoSpoofCheckBuffer, VfInfoTrust, VfInfoTrustBuffer,
+    VfInfoTxRate, VfInfoTxRateBuffer, VfInfoVlan, VfInfoVlanBuffer,
+    VfLinkState, VfPort, VfPortRequest, VfPortResponse, VfPortVsi,
+    VfPortVsiBuffer, VfStats, VfVlan, VfVlanInfo,
 };
 pub use self::stats::{Stats, StatsBuffer};
 pub use self::stats64::{Stats64, Stats64Buffer};
 pub use self::vlan_protocol::VlanProtocol;
-pub use self::wireless::LinkWirelessEvent;
-pub use self::xdp::{LinkXdp, XdpAttached};
+pub use self::wireless::{
+    LinkWirelessEvent, WirelessMichaelMicFailure, WirelessPmkidCandidate,
+};
+pub use self::xdp::{LinkXdp, XdpAttached, XdpFlag};
@@ -1,8 +1,19 @@
 // SPDX-License-Identifier: MIT
 
+use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{DecodeError, Emitable, Parseable};
 
-const VF_INFO_GUID_LEN: usize = 12;
+// The `struct ifla_vf_guid` is a `__u32 vf` followed by a `__u64 guid`, hence
+// the guid is placed at the alignment of u64: offset 8 on most platforms but
+// offset 4 on i386.
+const VF_INFO_GUID_OFFSET: usize = if std::mem::align_of::<u64>() > 4 {
+    std::mem::align_of::<u64>()
+} else {
+    4
+};
+const VF_INFO_GUID_LEN: usize = VF_INFO_GUID_OFFSET + 8;
+const VF_INFO_GUID_PACKED_LEN: usize = 12;
+const VF_INFO_GUID_ALIGNED_LEN: usize = 16;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 #[non_exhaustive]
@@ -19,14 +30,28 @@ impl VfInfoGuid {
 
 buffer!(VfInfoGuidBuffer(VF_INFO_GUID_LEN) {
     vf_id: (u32, 0..4),
-    guid: (u64, 4..12),
+    guid: (u64, VF_INFO_GUID_OFFSET..VF_INFO_GUID_LEN),
 });
 
+// Accept both layouts regardless of the platform we are running on, as the
+// message might be generated by kernel of different architecture.
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<VfInfoGuidBuffer<&'a T>>
     for VfInfoGuid
 {
     fn parse(buf: &VfInfoGuidBuffer<&T>) -> Result<Self, DecodeError> {
-        Ok(Self::new(buf.vf_id(), buf.guid()))
+        let payload = buf.inner();
+        let guid = match payload.len() {
+            VF_INFO_GUID_PACKED_LEN => NativeEndian::read_u64(&payload[4..12]),
+            VF_INFO_GUID_ALIGNED_LEN => NativeEndian::read_u64(&payload[8..16]),
+            len => {
+                return Err(format!(
+                    "Invalid length {len} of struct ifla_vf_guid, expecting \
+                    {VF_INFO_GUID_PACKED_LEN} or {VF_INFO_GUID_ALIGNED_LEN}"
+                )
+                .into())
+            }
+        };
+        Ok(Self::new(buf.vf_id(), guid))
     }
 }
 
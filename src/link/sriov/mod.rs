@@ -10,6 +10,7 @@ mod spoofchk;
 mod stats;
 mod trust;
 mod tx_rate;
+mod vf_config;
 mod vf_list;
 mod vf_port;
 mod vf_vlan;
@@ -30,7 +31,11 @@ pub use self::spoofchk::{VfInfoSpoofCheck, VfInfoSpoofCheckBuffer};
 pub use self::stats::VfStats;
 pub use self::trust::{VfInfoTrust, VfInfoTrustBuffer};
 pub use self::tx_rate::{VfInfoTxRate, VfInfoTxRateBuffer};
+pub use self::vf_config::VfConfig;
 pub use self::vf_list::{LinkVfInfo, VfInfo};
-pub use self::vf_port::{LinkVfPort, VfPort};
+pub use self::vf_port::{
+    LinkVfPort, VfPort, VfPortRequest, VfPortResponse, VfPortVsi,
+    VfPortVsiBuffer,
+};
 pub use self::vf_vlan::{VfVlan, VfVlanInfo};
 pub use self::vlan::{VfInfoVlan, VfInfoVlanBuffer};
@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::DecodeError;
+
+use crate::link::{
+    LinkAttribute, LinkVfInfo, VfInfo, VfInfoGuid, VfInfoLinkState, VfInfoMac,
+    VfInfoRate, VfInfoRssQueryEn, VfInfoSpoofCheck, VfInfoTrust, VfInfoVlan,
+    VfLinkState, VfVlan, VfVlanInfo, VlanProtocol,
+};
+
+const MAX_ADDR_LEN: usize = 32;
+const VLAN_ID_MAX: u32 = 4095;
+const VLAN_QOS_MAX: u32 = 7;
+
+/// Configuration of a SR-IOV VF used for generating
+/// `LinkAttribute::VfInfoList` of `RTM_SETLINK` on PF. Only the properties
+/// set will be changed by kernel.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct VfConfig {
+    pub vf_id: u32,
+    pub mac: Option<Vec<u8>>,
+    /// Tuple (vlan_id, qos, protocol)
+    pub vlan: Option<(u32, u32, VlanProtocol)>,
+    /// Tuple (min_tx_rate, max_tx_rate) in Mbps, 0 means no limit
+    pub rate: Option<(u32, u32)>,
+    pub link_state: Option<VfLinkState>,
+    pub trust: Option<bool>,
+    pub spoof_check: Option<bool>,
+    pub rss_query: Option<bool>,
+    pub ib_node_guid: Option<u64>,
+    pub ib_port_guid: Option<u64>,
+}
+
+impl VfConfig {
+    pub fn new(vf_id: u32) -> Self {
+        Self {
+            vf_id,
+            ..Default::default()
+        }
+    }
+
+    pub fn mac(mut self, mac: &[u8]) -> Self {
+        self.mac = Some(mac.to_vec());
+        self
+    }
+
+    /// Set VLAN ID and priority(qos). Use VLAN ID 0 to remove VLAN.
+    /// The `VlanProtocol::Ieee8021Ad` is for QinQ.
+    pub fn vlan(
+        mut self,
+        vlan_id: u32,
+        qos: u32,
+        protocol: VlanProtocol,
+    ) -> Self {
+        self.vlan = Some((vlan_id, qos, protocol));
+        self
+    }
+
+    pub fn rate(mut self, min_tx_rate: u32, max_tx_rate: u32) -> Self {
+        self.rate = Some((min_tx_rate, max_tx_rate));
+        self
+    }
+
+    pub fn link_state(mut self, state: VfLinkState) -> Self {
+        self.link_state = Some(state);
+        self
+    }
+
+    pub fn trust(mut self, enabled: bool) -> Self {
+        self.trust = Some(enabled);
+        self
+    }
+
+    pub fn spoof_check(mut self, enabled: bool) -> Self {
+        self.spoof_check = Some(enabled);
+        self
+    }
+
+    pub fn rss_query(mut self, enabled: bool) -> Self {
+        self.rss_query = Some(enabled);
+        self
+    }
+
+    pub fn ib_node_guid(mut self, guid: u64) -> Self {
+        self.ib_node_guid = Some(guid);
+        self
+    }
+
+    pub fn ib_port_guid(mut self, guid: u64) -> Self {
+        self.ib_port_guid = Some(guid);
+        self
+    }
+
+    fn validate(&self) -> Result<(), DecodeError> {
+        let vf_id = self.vf_id;
+        if let Some(mac) = self.mac.as_ref() {
+            if mac.is_empty() || mac.len() > MAX_ADDR_LEN {
+                return Err(format!(
+                    "Invalid MAC address {mac:?} for VF {vf_id}, \
+                    should be 1 to {MAX_ADDR_LEN} bytes"
+                )
+                .into());
+            }
+        }
+        if let Some((vlan_id, qos, _)) = self.vlan {
+            if vlan_id > VLAN_ID_MAX {
+                return Err(format!(
+                    "Invalid VLAN ID {vlan_id} for VF {vf_id}, \
+                    should be in range of 0-{VLAN_ID_MAX}"
+                )
+                .into());
+            }
+            if qos > VLAN_QOS_MAX {
+                return Err(format!(
+                    "Invalid VLAN qos {qos} for VF {vf_id}, \
+                    should be in range of 0-{VLAN_QOS_MAX}"
+                )
+                .into());
+            }
+        }
+        if let Some((min_tx_rate, max_tx_rate)) = self.rate {
+            if max_tx_rate != 0 && max_tx_rate < min_tx_rate {
+                return Err(format!(
+                    "Invalid rate for VF {vf_id}, max_tx_rate \
+                    {max_tx_rate} is smaller than min_tx_rate {min_tx_rate}"
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate and convert into `IFLA_VF_INFO`
+    pub fn build(&self) -> Result<LinkVfInfo, DecodeError> {
+        self.validate()?;
+        let vf_id = self.vf_id;
+        let mut nlas = Vec::new();
+        if let Some(mac) = self.mac.as_ref() {
+            nlas.push(VfInfo::Mac(VfInfoMac::new(vf_id, mac)));
+        }
+        if let Some((vlan_id, qos, protocol)) = self.vlan {
+            // Use legacy IFLA_VF_VLAN for 802.1Q to support old kernels,
+            // IFLA_VF_VLAN_LIST is required for setting protocol.
+            if protocol == VlanProtocol::Ieee8021Q {
+                nlas.push(VfInfo::Vlan(VfInfoVlan::new(vf_id, vlan_id, qos)));
+            } else {
+                nlas.push(VfInfo::VlanList(vec![VfVlan::Info(
+                    VfVlanInfo::new(vf_id, vlan_id, qos, protocol),
+                )]));
+            }
+        }
+        if let Some((min_tx_rate, max_tx_rate)) = self.rate {
+            nlas.push(VfInfo::Rate(VfInfoRate::new(
+                vf_id,
+                min_tx_rate,
+                max_tx_rate,
+            )));
+        }
+        if let Some(state) = self.link_state {
+            nlas.push(VfInfo::LinkState(VfInfoLinkState::new(vf_id, state)));
+        }
+        if let Some(enabled) = self.trust {
+            nlas.push(VfInfo::Trust(VfInfoTrust::new(vf_id, enabled)));
+        }
+        if let Some(enabled) = self.spoof_check {
+            nlas.push(VfInfo::SpoofCheck(VfInfoSpoofCheck::new(
+                vf_id, enabled,
+            )));
+        }
+        if let Some(enabled) = self.rss_query {
+            nlas.push(VfInfo::RssQueryEn(VfInfoRssQueryEn::new(
+                vf_id, enabled,
+            )));
+        }
+        if let Some(guid) = self.ib_node_guid {
+            nlas.push(VfInfo::IbNodeGuid(VfInfoGuid::new(vf_id, guid)));
+        }
+        if let Some(guid) = self.ib_port_guid {
+            nlas.push(VfInfo::IbPortGuid(VfInfoGuid::new(vf_id, guid)));
+        }
+        Ok(LinkVfInfo(nlas))
+    }
+
+    /// Validate and convert into `LinkAttribute::VfInfoList`.
+    pub fn vf_info_list(vfs: &[Self]) -> Result<LinkAttribute, DecodeError> {
+        let mut vf_infos = Vec::new();
+        for (i, vf) in vfs.iter().enumerate() {
+            if vfs[..i].iter().any(|v| v.vf_id == vf.vf_id) {
+                return Err(
+                    format!("Duplicate config for VF {}", vf.vf_id).into()
+                );
+            }
+            vf_infos.push(vf.build()?);
+        }
+        Ok(LinkAttribute::VfInfoList(vf_infos))
+    }
+}
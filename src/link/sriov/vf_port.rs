@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: MIT
 
 use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u16, parse_u32, parse_u8},
     DecodeError, Emitable, Parseable,
 };
 
@@ -66,45 +68,70 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for LinkVfPort {
     }
 }
 
-/*
 const IFLA_PORT_VF: u16 = 1;
 const IFLA_PORT_PROFILE: u16 = 2;
-// No kernel code is accepting or generating IFLA_PORT_VSI_TYPE.
-// const IFLA_PORT_VSI_TYPE: u16 = 3;
+const IFLA_PORT_VSI_TYPE: u16 = 3;
 const IFLA_PORT_INSTANCE_UUID: u16 = 4;
 const IFLA_PORT_HOST_UUID: u16 = 5;
 const IFLA_PORT_REQUEST: u16 = 6;
 const IFLA_PORT_RESPONSE: u16 = 7;
 
 const UUID_LEN: usize = 16;
-*/
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum VfPort {
-    //    Vf(u32),
-    //    Profile(String),
-    //    InstanceUuid([u8; UUID_LEN]),
-    //    HostUuid([u8; UUID_LEN]),
-    //    Request(u8),
+    Vf(u32),
+    /// Port profile name of 802.1Qbh
+    Profile(String),
+    /// VSI type of 802.1Qbg, kernel passes it to driver untouched.
+    VsiType(VfPortVsi),
+    InstanceUuid([u8; UUID_LEN]),
+    HostUuid([u8; UUID_LEN]),
+    Request(VfPortRequest),
+    Response(VfPortResponse),
     Other(DefaultNla),
 }
 
 impl Nla for VfPort {
     fn value_len(&self) -> usize {
         match self {
+            Self::Vf(_) => 4,
+            Self::Profile(v) => v.len() + 1,
+            Self::VsiType(v) => v.buffer_len(),
+            Self::InstanceUuid(_) | Self::HostUuid(_) => UUID_LEN,
+            Self::Request(_) => 1,
+            Self::Response(_) => 2,
             Self::Other(v) => v.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::Vf(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Profile(v) => {
+                buffer[..v.len()].copy_from_slice(v.as_bytes());
+                buffer[v.len()] = 0;
+            }
+            Self::VsiType(v) => v.emit(buffer),
+            Self::InstanceUuid(v) | Self::HostUuid(v) => {
+                buffer[..UUID_LEN].copy_from_slice(v.as_slice())
+            }
+            Self::Request(v) => buffer[0] = (*v).into(),
+            Self::Response(v) => NativeEndian::write_u16(buffer, (*v).into()),
             Self::Other(attr) => attr.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Vf(_) => IFLA_PORT_VF,
+            Self::Profile(_) => IFLA_PORT_PROFILE,
+            Self::VsiType(_) => IFLA_PORT_VSI_TYPE,
+            Self::InstanceUuid(_) => IFLA_PORT_INSTANCE_UUID,
+            Self::HostUuid(_) => IFLA_PORT_HOST_UUID,
+            Self::Request(_) => IFLA_PORT_REQUEST,
+            Self::Response(_) => IFLA_PORT_RESPONSE,
             Self::Other(v) => v.kind(),
         }
     }
@@ -113,11 +140,252 @@ impl Nla for VfPort {
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VfPort {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
         let payload = buf.value();
-        #[allow(clippy::match_single_binding)]
         Ok(match buf.kind() {
+            IFLA_PORT_VF => Self::Vf(
+                parse_u32(payload).context("invalid IFLA_PORT_VF value")?,
+            ),
+            IFLA_PORT_PROFILE => Self::Profile(
+                parse_string(payload)
+                    .context("invalid IFLA_PORT_PROFILE value")?,
+            ),
+            IFLA_PORT_VSI_TYPE => Self::VsiType(
+                VfPortVsi::parse(
+                    &VfPortVsiBuffer::new_checked(payload)
+                        .context("invalid IFLA_PORT_VSI_TYPE value")?,
+                )
+                .context("invalid IFLA_PORT_VSI_TYPE value")?,
+            ),
+            IFLA_PORT_INSTANCE_UUID => Self::InstanceUuid(
+                parse_uuid(payload)
+                    .context("invalid IFLA_PORT_INSTANCE_UUID value")?,
+            ),
+            IFLA_PORT_HOST_UUID => Self::HostUuid(
+                parse_uuid(payload)
+                    .context("invalid IFLA_PORT_HOST_UUID value")?,
+            ),
+            IFLA_PORT_REQUEST => Self::Request(
+                parse_u8(payload)
+                    .context("invalid IFLA_PORT_REQUEST value")?
+                    .into(),
+            ),
+            IFLA_PORT_RESPONSE => Self::Response(
+                parse_u16(payload)
+                    .context("invalid IFLA_PORT_RESPONSE value")?
+                    .into(),
+            ),
             kind => Self::Other(DefaultNla::parse(buf).context(format!(
                 "failed to parse {kind} as DefaultNla: {payload:?}"
             ))?),
         })
     }
 }
+
+fn parse_uuid(payload: &[u8]) -> Result<[u8; UUID_LEN], DecodeError> {
+    if payload.len() != UUID_LEN {
+        return Err(format!(
+            "invalid UUID, expecting {UUID_LEN} bytes, but got {payload:?}"
+        )
+        .into());
+    }
+    let mut uuid = [0u8; UUID_LEN];
+    uuid.copy_from_slice(payload);
+    Ok(uuid)
+}
+
+const VF_PORT_VSI_LEN: usize = 8; // with 3 bytes padding
+
+/// Virtual Station Interface(VSI) type of 802.1Qbg
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct VfPortVsi {
+    pub manager_id: u8,
+    /// 24 bits VSI type ID
+    pub type_id: [u8; 3],
+    pub type_version: u8,
+}
+
+impl VfPortVsi {
+    pub fn new(manager_id: u8, type_id: [u8; 3], type_version: u8) -> Self {
+        Self {
+            manager_id,
+            type_id,
+            type_version,
+        }
+    }
+}
+
+buffer!(VfPortVsiBuffer(VF_PORT_VSI_LEN) {
+    manager_id: (u8, 0),
+    type_id: (slice, 1..4),
+    type_version: (u8, 4),
+});
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<VfPortVsiBuffer<&T>> for VfPortVsi {
+    fn parse(buf: &VfPortVsiBuffer<&T>) -> Result<Self, DecodeError> {
+        let mut type_id = [0u8; 3];
+        type_id.copy_from_slice(buf.type_id());
+        Ok(Self::new(buf.manager_id(), type_id, buf.type_version()))
+    }
+}
+
+impl Emitable for VfPortVsi {
+    fn buffer_len(&self) -> usize {
+        VF_PORT_VSI_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = VfPortVsiBuffer::new(buffer);
+        buffer.set_manager_id(self.manager_id);
+        buffer
+            .type_id_mut()
+            .copy_from_slice(self.type_id.as_slice());
+        buffer.set_type_version(self.type_version);
+    }
+}
+
+const PORT_REQUEST_PREASSOCIATE: u8 = 0;
+const PORT_REQUEST_PREASSOCIATE_RR: u8 = 1;
+const PORT_REQUEST_ASSOCIATE: u8 = 2;
+const PORT_REQUEST_DISASSOCIATE: u8 = 3;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VfPortRequest {
+    Preassociate,
+    /// Pre-associate with resource reservation
+    PreassociateRr,
+    Associate,
+    Disassociate,
+    Other(u8),
+}
+
+impl From<u8> for VfPortRequest {
+    fn from(d: u8) -> Self {
+        match d {
+            PORT_REQUEST_PREASSOCIATE => Self::Preassociate,
+            PORT_REQUEST_PREASSOCIATE_RR => Self::PreassociateRr,
+            PORT_REQUEST_ASSOCIATE => Self::Associate,
+            PORT_REQUEST_DISASSOCIATE => Self::Disassociate,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<VfPortRequest> for u8 {
+    fn from(v: VfPortRequest) -> u8 {
+        match v {
+            VfPortRequest::Preassociate => PORT_REQUEST_PREASSOCIATE,
+            VfPortRequest::PreassociateRr => PORT_REQUEST_PREASSOCIATE_RR,
+            VfPortRequest::Associate => PORT_REQUEST_ASSOCIATE,
+            VfPortRequest::Disassociate => PORT_REQUEST_DISASSOCIATE,
+            VfPortRequest::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for VfPortRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Preassociate => write!(f, "preassociate"),
+            Self::PreassociateRr => write!(f, "preassociate_rr"),
+            Self::Associate => write!(f, "associate"),
+            Self::Disassociate => write!(f, "disassociate"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const PORT_VDP_RESPONSE_SUCCESS: u16 = 0;
+const PORT_VDP_RESPONSE_INVALID_FORMAT: u16 = 1;
+const PORT_VDP_RESPONSE_INSUFFICIENT_RESOURCES: u16 = 2;
+const PORT_VDP_RESPONSE_UNUSED_VTID: u16 = 3;
+const PORT_VDP_RESPONSE_VTID_VIOLATION: u16 = 4;
+const PORT_VDP_RESPONSE_VTID_VERSION_VIOALTION: u16 = 5;
+const PORT_VDP_RESPONSE_OUT_OF_SYNC: u16 = 6;
+const PORT_PROFILE_RESPONSE_SUCCESS: u16 = 0x100;
+const PORT_PROFILE_RESPONSE_INPROGRESS: u16 = 0x101;
+const PORT_PROFILE_RESPONSE_INVALID: u16 = 0x102;
+const PORT_PROFILE_RESPONSE_BADSTATE: u16 = 0x103;
+const PORT_PROFILE_RESPONSE_INSUFFICIENT_RESOURCES: u16 = 0x104;
+const PORT_PROFILE_RESPONSE_ERROR: u16 = 0x105;
+
+/// The `Vdp*` variants are responses of 802.1Qbg VSI Discovery Protocol,
+/// the `Profile*` variants are responses of 802.1Qbh port profile.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VfPortResponse {
+    VdpSuccess,
+    VdpInvalidFormat,
+    VdpInsufficientResources,
+    VdpUnusedVtid,
+    VdpVtidViolation,
+    VdpVtidVersionViolation,
+    VdpOutOfSync,
+    ProfileSuccess,
+    ProfileInProgress,
+    ProfileInvalid,
+    ProfileBadState,
+    ProfileInsufficientResources,
+    ProfileError,
+    Other(u16),
+}
+
+impl From<u16> for VfPortResponse {
+    fn from(d: u16) -> Self {
+        match d {
+            PORT_VDP_RESPONSE_SUCCESS => Self::VdpSuccess,
+            PORT_VDP_RESPONSE_INVALID_FORMAT => Self::VdpInvalidFormat,
+            PORT_VDP_RESPONSE_INSUFFICIENT_RESOURCES => {
+                Self::VdpInsufficientResources
+            }
+            PORT_VDP_RESPONSE_UNUSED_VTID => Self::VdpUnusedVtid,
+            PORT_VDP_RESPONSE_VTID_VIOLATION => Self::VdpVtidViolation,
+            PORT_VDP_RESPONSE_VTID_VERSION_VIOALTION => {
+                Self::VdpVtidVersionViolation
+            }
+            PORT_VDP_RESPONSE_OUT_OF_SYNC => Self::VdpOutOfSync,
+            PORT_PROFILE_RESPONSE_SUCCESS => Self::ProfileSuccess,
+            PORT_PROFILE_RESPONSE_INPROGRESS => Self::ProfileInProgress,
+            PORT_PROFILE_RESPONSE_INVALID => Self::ProfileInvalid,
+            PORT_PROFILE_RESPONSE_BADSTATE => Self::ProfileBadState,
+            PORT_PROFILE_RESPONSE_INSUFFICIENT_RESOURCES => {
+                Self::ProfileInsufficientResources
+            }
+            PORT_PROFILE_RESPONSE_ERROR => Self::ProfileError,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<VfPortResponse> for u16 {
+    fn from(v: VfPortResponse) -> u16 {
+        match v {
+            VfPortResponse::VdpSuccess => PORT_VDP_RESPONSE_SUCCESS,
+            VfPortResponse::VdpInvalidFormat => {
+                PORT_VDP_RESPONSE_INVALID_FORMAT
+            }
+            VfPortResponse::VdpInsufficientResources => {
+                PORT_VDP_RESPONSE_INSUFFICIENT_RESOURCES
+            }
+            VfPortResponse::VdpUnusedVtid => PORT_VDP_RESPONSE_UNUSED_VTID,
+            VfPortResponse::VdpVtidViolation => {
+                PORT_VDP_RESPONSE_VTID_VIOLATION
+            }
+            VfPortResponse::VdpVtidVersionViolation => {
+                PORT_VDP_RESPONSE_VTID_VERSION_VIOALTION
+            }
+            VfPortResponse::VdpOutOfSync => PORT_VDP_RESPONSE_OUT_OF_SYNC,
+            VfPortResponse::ProfileSuccess => PORT_PROFILE_RESPONSE_SUCCESS,
+            VfPortResponse::ProfileInProgress => {
+                PORT_PROFILE_RESPONSE_INPROGRESS
+            }
+            VfPortResponse::ProfileInvalid => PORT_PROFILE_RESPONSE_INVALID,
+            VfPortResponse::ProfileBadState => PORT_PROFILE_RESPONSE_BADSTATE,
+            VfPortResponse::ProfileInsufficientResources => {
+                PORT_PROFILE_RESPONSE_INSUFFICIENT_RESOURCES
+            }
+            VfPortResponse::ProfileError => PORT_PROFILE_RESPONSE_ERROR,
+            VfPortResponse::Other(d) => d,
+        }
+    }
+}
@@ -1,15 +1,16 @@
 // SPDX-License-Identifier: MIT
 
-use netlink_packet_utils::{
-    nla::{DefaultNla, NlaBuffer},
-    Emitable, Parseable,
-};
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
 
 use crate::link::{
     af_spec::VecAfSpecBridge, AfSpecBridge, AfSpecInet, AfSpecInet6,
-    AfSpecUnspec, BridgeVlanInfo, Inet6CacheInfo, Inet6DevConf, Inet6IfaceFlag,
-    Inet6IfaceFlags, InetDevConf, InfoBridge, InfoData, InfoKind,
-    LinkAttribute, LinkFlag, LinkHeader, LinkInfo, LinkLayerType, LinkMessage,
+    AfSpecUnspec, BridgeCfm, BridgeCfmCcConfig, BridgeCfmCcmInterval,
+    BridgeCfmDomain, BridgeCfmMepCreate, BridgeCfmMepDirection, BridgeFlag,
+    BridgeMode, BridgeMrp, BridgeMrpInfo, BridgeMrpInstance, BridgeMrpRingRole,
+    BridgeMrpRingRoleType, BridgeMrpRingStateType, BridgeVlanInfo,
+    BridgeVlanInfoFlag, BridgeVlanTunnelInfo, Inet6CacheInfo, Inet6DevConf,
+    Inet6IfaceFlag, InetDevConf, InfoBridge, InfoData, InfoKind, LinkAttribute,
+    LinkFlag, LinkHeader, LinkInfo, LinkLayerType, LinkMessage,
     LinkMessageBuffer, LinkXdp, Map, State, Stats, Stats64, XdpAttached,
 };
 use crate::AddressFamily;
@@ -164,15 +165,15 @@ fn test_parse_link_bridge_no_extention_mask() {
             LinkAttribute::MaxMtu(65535),
             LinkAttribute::Group(0),
             LinkAttribute::Promiscuity(0),
-            LinkAttribute::Other(DefaultNla::new(61, vec![0, 0, 0, 0])),
+            LinkAttribute::Allmulti(0),
             LinkAttribute::NumTxQueues(1),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(63, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(64, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(59, vec![248, 255, 7, 0])),
-            LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
+            LinkAttribute::GroMaxSize(65536),
+            LinkAttribute::GsoIpv4MaxSize(65536),
+            LinkAttribute::GroIpv4MaxSize(65536),
+            LinkAttribute::TsoMaxSize(524280),
+            LinkAttribute::TsoMaxSegs(65535),
             LinkAttribute::NumRxQueues(1),
             LinkAttribute::Carrier(1),
             LinkAttribute::Qdisc("noqueue".to_string()),
@@ -336,10 +337,10 @@ fn test_parse_link_bridge_no_extention_mask() {
                     arp_evict_nocarrier: 1,
                 })]),
                 AfSpecUnspec::Inet6(vec![
-                    AfSpecInet6::Flags(Inet6IfaceFlags(vec![
+                    AfSpecInet6::Flags(vec![
                         Inet6IfaceFlag::RsSent,
                         Inet6IfaceFlag::Ready,
-                    ])),
+                    ]),
                     AfSpecInet6::CacheInfo(Inet6CacheInfo {
                         max_reasm_len: 65535,
                         tstamp: 26395879,
@@ -409,7 +410,7 @@ fn test_parse_link_bridge_no_extention_mask() {
                     }),
                 ]),
             ]),
-            LinkAttribute::Other(DefaultNla::new(32830, vec![])),
+            LinkAttribute::DevlinkPort(vec![]),
         ],
     };
 
@@ -449,3 +450,212 @@ fn test_af_spec_bridge() {
         expected
     );
 }
+
+#[test]
+fn test_bridge_vlan_info_flag_list() {
+    let mut vlan = BridgeVlanInfo::new(
+        1,
+        &[BridgeVlanInfoFlag::Pvid, BridgeVlanInfoFlag::Untagged],
+    );
+    assert_eq!(vlan.flags, 6);
+    assert_eq!(
+        vlan.flag_list(),
+        vec![BridgeVlanInfoFlag::Pvid, BridgeVlanInfoFlag::Untagged]
+    );
+
+    vlan.set_flags(&[BridgeVlanInfoFlag::Master]);
+    assert_eq!(vlan.flags, 1);
+
+    vlan.flags = 1 << 8;
+    assert_eq!(vlan.flag_list(), vec![BridgeVlanInfoFlag::Other(1 << 8)]);
+}
+
+#[test]
+fn test_af_spec_bridge_vlan_tunnel_info() {
+    // Equivalent to
+    //  `bridge vlan add dev vxlan0 vid 100-199 tunnel_info id 10100-10199 self`
+    // with `IFLA_BRIDGE_MODE` set to VEPA
+    let raw: Vec<u8> = vec![
+        0x06, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x06, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x03, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x74, 0x27, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x64, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x08, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x03, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0xd7, 0x27, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0xc7, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x10, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = vec![
+        AfSpecBridge::Flags(vec![BridgeFlag::LowerDev]),
+        AfSpecBridge::Mode(BridgeMode::Vepa),
+        AfSpecBridge::VlanTunnelInfo(vec![
+            BridgeVlanTunnelInfo::Id(10100),
+            BridgeVlanTunnelInfo::Vid(100),
+            BridgeVlanTunnelInfo::Flags(vec![BridgeVlanInfoFlag::RangeBegin]),
+        ]),
+        AfSpecBridge::VlanTunnelInfo(vec![
+            BridgeVlanTunnelInfo::Id(10199),
+            BridgeVlanTunnelInfo::Vid(199),
+            BridgeVlanTunnelInfo::Flags(vec![BridgeVlanInfoFlag::RangeEnd]),
+        ]),
+    ];
+
+    assert_eq!(
+        VecAfSpecBridge::parse(&NlaBuffer::new(&raw)).unwrap().0,
+        expected
+    );
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_bridge_vlan_info_compress_and_expand() {
+    let mut vlans = vec![BridgeVlanInfo::new(
+        1,
+        &[BridgeVlanInfoFlag::Pvid, BridgeVlanInfoFlag::Untagged],
+    )];
+    for vid in (2..=4094).rev() {
+        if vid != 100 {
+            vlans.push(BridgeVlanInfo::new(vid, &[]));
+        }
+    }
+    vlans.push(BridgeVlanInfo::new(4094, &[]));
+
+    let compressed = BridgeVlanInfo::compress(&vlans).unwrap();
+    assert_eq!(
+        compressed,
+        vec![
+            BridgeVlanInfo::new(
+                1,
+                &[BridgeVlanInfoFlag::Pvid, BridgeVlanInfoFlag::Untagged],
+            ),
+            BridgeVlanInfo::new(2, &[BridgeVlanInfoFlag::RangeBegin]),
+            BridgeVlanInfo::new(99, &[BridgeVlanInfoFlag::RangeEnd]),
+            BridgeVlanInfo::new(101, &[BridgeVlanInfoFlag::RangeBegin]),
+            BridgeVlanInfo::new(4094, &[BridgeVlanInfoFlag::RangeEnd]),
+        ]
+    );
+
+    let expanded = BridgeVlanInfo::expand(&compressed).unwrap();
+    assert_eq!(expanded.len(), 4093);
+    assert_eq!(expanded[0], vlans[0]);
+    assert_eq!(expanded[1], BridgeVlanInfo::new(2, &[]));
+    assert_eq!(expanded[99], BridgeVlanInfo::new(101, &[]));
+    assert_eq!(expanded[4092], BridgeVlanInfo::new(4094, &[]));
+
+    assert!(BridgeVlanInfo::expand(&compressed[..2]).is_err());
+    assert!(BridgeVlanInfo::expand(&compressed[2..]).is_err());
+
+    // Same VLAN ID with different flags is ambiguous
+    vlans.push(BridgeVlanInfo::new(100, &[]));
+    vlans.push(BridgeVlanInfo::new(100, &[BridgeVlanInfoFlag::Untagged]));
+    assert!(BridgeVlanInfo::compress(&vlans).is_err());
+}
+
+#[test]
+fn test_af_spec_bridge_mrp_info() {
+    // Kernel does not set NLA_F_NESTED for IFLA_BRIDGE_MRP in link dump
+    let raw: Vec<u8> = vec![
+        0x50, 0x00, 0x04, 0x00, 0x4c, 0x00, 0x07, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x06, 0x00, 0x04, 0x00,
+        0x00, 0x80, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00,
+        0x10, 0x27, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = vec![AfSpecBridge::Mrp(vec![BridgeMrp::Info(vec![
+        BridgeMrpInfo::RingId(1),
+        BridgeMrpInfo::PIfindex(2),
+        BridgeMrpInfo::SIfindex(3),
+        BridgeMrpInfo::Prio(0x8000),
+        BridgeMrpInfo::RingState(BridgeMrpRingStateType::Closed),
+        BridgeMrpInfo::RingRole(BridgeMrpRingRoleType::Mrm),
+        BridgeMrpInfo::TestInterval(10000),
+        BridgeMrpInfo::TestMaxMiss(3),
+        BridgeMrpInfo::TestMonitor(0),
+    ])])];
+
+    assert_eq!(
+        VecAfSpecBridge::parse(&NlaBuffer::new(&raw)).unwrap().0,
+        expected
+    );
+}
+
+#[test]
+fn test_af_spec_bridge_mrp_set() {
+    let raw: Vec<u8> = vec![
+        0x3c, 0x00, 0x04, 0x80, 0x24, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x06, 0x00, 0x04, 0x00,
+        0x00, 0x80, 0x00, 0x00, 0x14, 0x00, 0x05, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = vec![AfSpecBridge::Mrp(vec![
+        BridgeMrp::Instance(vec![
+            BridgeMrpInstance::RingId(1),
+            BridgeMrpInstance::PIfindex(2),
+            BridgeMrpInstance::SIfindex(3),
+            BridgeMrpInstance::Prio(0x8000),
+        ]),
+        BridgeMrp::RingRole(vec![
+            BridgeMrpRingRole::RingId(1),
+            BridgeMrpRingRole::Role(BridgeMrpRingRoleType::Mrm),
+        ]),
+    ])];
+
+    assert_eq!(
+        VecAfSpecBridge::parse(&NlaBuffer::new(&raw)).unwrap().0,
+        expected
+    );
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_af_spec_bridge_cfm_set() {
+    let raw: Vec<u8> = vec![
+        0x78, 0x00, 0x05, 0x80, 0x24, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x50, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x04, 0x00, 0x00, 0x00, 0x34, 0x00, 0x04, 0x00,
+        0x01, 0x04, 0x61, 0x62, 0x63, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let mut maid = [0u8; 48];
+    maid[..6].copy_from_slice(&[0x01, 0x04, b'a', b'b', b'c', b'd']);
+
+    let expected = vec![AfSpecBridge::Cfm(vec![
+        BridgeCfm::MepCreate(vec![
+            BridgeCfmMepCreate::Instance(1),
+            BridgeCfmMepCreate::Domain(BridgeCfmDomain::Port),
+            BridgeCfmMepCreate::Direction(BridgeCfmMepDirection::Down),
+            BridgeCfmMepCreate::Ifindex(3),
+        ]),
+        BridgeCfm::CcConfig(vec![
+            BridgeCfmCcConfig::Instance(1),
+            BridgeCfmCcConfig::Enable(1),
+            BridgeCfmCcConfig::ExpInterval(BridgeCfmCcmInterval::Sec1),
+            BridgeCfmCcConfig::ExpMaid(maid),
+        ]),
+    ])];
+
+    assert_eq!(
+        VecAfSpecBridge::parse(&NlaBuffer::new(&raw)).unwrap().0,
+        expected
+    );
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+}
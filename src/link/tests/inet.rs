@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::Emitable;
+
+use crate::link::{
+    AfSpecInet, AfSpecUnspec, InetDevConfKey, LinkAttribute, LinkHeader,
+    LinkMessage,
+};
+
+// Sparse IPv4 devconf update setting `forwarding=1` and `rp_filter=2` on
+// interface index 2
+#[test]
+fn test_set_inet_devconf_update() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x1a, 0x00, 0x18, 0x00, 0x02, 0x00,
+        0x14, 0x00, 0x01, 0x00, 0x08, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            index: 2,
+            ..Default::default()
+        },
+        attributes: vec![LinkAttribute::AfSpecUnspec(vec![
+            AfSpecUnspec::Inet(vec![AfSpecInet::DevConfUpdate(vec![
+                (InetDevConfKey::Forwarding, 1),
+                (InetDevConfKey::RpFilter, 2),
+            ])]),
+        ])],
+    };
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
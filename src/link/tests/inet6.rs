@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    AfSpecInet6, AfSpecUnspec, Inet6AddrGenMode, LinkAttribute, LinkHeader,
+    LinkMessage, LinkMessageBuffer,
+};
+
+// Equivalent to
+//  `ip link set eth1 addrgenmode stable_secret`
+//  `ip token set ::1:2:3:4 dev eth1`
+#[test]
+fn test_set_inet6_token_and_addr_gen_mode() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x1a, 0x00, 0x20, 0x00, 0x0a, 0x00,
+        0x14, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x05, 0x00, 0x08, 0x00,
+        0x02, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            index: 2,
+            ..Default::default()
+        },
+        attributes: vec![LinkAttribute::AfSpecUnspec(vec![
+            AfSpecUnspec::Inet6(vec![
+                AfSpecInet6::Token(
+                    std::net::Ipv6Addr::from_str("::1:2:3:4").unwrap(),
+                ),
+                AfSpecInet6::AddrGenMode(Inet6AddrGenMode::StablePrivacy),
+            ]),
+        ])],
+    };
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+}
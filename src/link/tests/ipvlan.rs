@@ -1,10 +1,11 @@
 // SPDX-License-Identifier: MIT
 
-use netlink_packet_utils::{Emitable, Parseable};
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
 
 use crate::link::{
-    InfoData, InfoIpVlan, InfoKind, LinkAttribute, LinkFlag, LinkHeader,
-    LinkInfo, LinkLayerType, LinkMessage, LinkMessageBuffer,
+    InfoData, InfoIpVlan, InfoIpVtap, InfoKind, IpVlanFlag, IpVlanMode,
+    LinkAttribute, LinkFlag, LinkHeader, LinkInfo, LinkLayerType, LinkMessage,
+    LinkMessageBuffer,
 };
 use crate::AddressFamily;
 
@@ -29,8 +30,8 @@ fn test_ipvlan_link_info() {
         attributes: vec![LinkAttribute::LinkInfo(vec![
             LinkInfo::Kind(InfoKind::IpVlan),
             LinkInfo::Data(InfoData::IpVlan(vec![
-                InfoIpVlan::Mode(0),
-                InfoIpVlan::Flags(2),
+                InfoIpVlan::Mode(IpVlanMode::L2),
+                InfoIpVlan::Flags(vec![IpVlanFlag::Vepa]),
             ])),
         ])],
     };
@@ -46,3 +47,52 @@ fn test_ipvlan_link_info() {
 
     assert_eq!(buf, raw);
 }
+
+#[test]
+fn test_ipvtap_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x13, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x76, 0x74, 0x61, 0x70, 0x00, 0x00, 0x14, 0x00, 0x02, 0x00,
+        0x06, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 19,
+            link_layer_type: LinkLayerType::Ether,
+            flags: vec![LinkFlag::Broadcast, LinkFlag::Multicast],
+            change_mask: vec![],
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::IpVtap),
+            LinkInfo::Data(InfoData::IpVtap(vec![
+                InfoIpVtap::Mode(IpVlanMode::L3S),
+                InfoIpVtap::Flags(vec![IpVlanFlag::Private]),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_ipvlan_bridge_flag() {
+    let nlas = vec![InfoIpVlan::Flags(vec![IpVlanFlag::Bridge])];
+    let mut buf = vec![0; nlas.as_slice().buffer_len()];
+    nlas.as_slice().emit(&mut buf);
+
+    assert_eq!(buf, vec![0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    assert_eq!(InfoIpVlan::parse(&NlaBuffer::new(&buf)).unwrap(), nlas[0]);
+}
@@ -7,6 +7,10 @@ mod bridge;
 #[cfg(test)]
 mod hsr;
 #[cfg(test)]
+mod inet;
+#[cfg(test)]
+mod inet6;
+#[cfg(test)]
 mod ipvlan;
 #[cfg(test)]
 mod loopback;
@@ -17,6 +21,10 @@ mod macvlan;
 #[cfg(test)]
 mod message;
 #[cfg(test)]
+mod mpls;
+#[cfg(test)]
+mod parent_dev;
+#[cfg(test)]
 mod prop_list;
 #[cfg(test)]
 mod sriov;
@@ -28,6 +36,9 @@ mod vlan;
 mod vrf;
 #[cfg(test)]
 mod vxlan;
+// The layout of `struct iw_event` depends on the pointer width
+#[cfg(all(test, target_pointer_width = "64"))]
+mod wireless;
 #[cfg(test)]
 mod xdp;
 #[cfg(test)]
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+
+use crate::link::{
+    af_spec::VecAfSpecUnspec, AfSpecMpls, AfSpecUnspec, MplsLinkStats,
+};
+
+#[test]
+fn test_af_spec_mpls_link_stats() {
+    let raw: Vec<u8> = vec![
+        0x50, 0x00, // length 80
+        0x1c, 0x00, // AF_MPLS 28
+        0x4c, 0x00, // length 76
+        0x01, 0x00, // MPLS_STATS_LINK 1
+        0x78, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_packets 120
+        0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_packets 100
+        0x80, 0x25, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_bytes 9600
+        0x40, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_bytes 8000
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_errors 1
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_errors 2
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_dropped 3
+        0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_dropped 4
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_noroute 5
+    ];
+
+    let expected =
+        vec![AfSpecUnspec::Mpls(vec![AfSpecMpls::Stats(MplsLinkStats {
+            rx_packets: 120,
+            tx_packets: 100,
+            rx_bytes: 9600,
+            tx_bytes: 8000,
+            rx_errors: 1,
+            tx_errors: 2,
+            rx_dropped: 3,
+            tx_dropped: 4,
+            rx_noroute: 5,
+        })])];
+
+    assert_eq!(
+        VecAfSpecUnspec::parse(&NlaBuffer::new(&raw)).unwrap().0,
+        expected
+    );
+    let mut buffer = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buffer);
+    assert_eq!(buffer.as_slice(), raw);
+}
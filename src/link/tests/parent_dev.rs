@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    LinkAttribute, LinkDevlinkPort, LinkFlag, LinkHeader, LinkLayerType,
+    LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+#[test]
+fn test_parent_dev_and_devlink_port() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, 0x43, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x38, 0x00, 0x30, 0x30, 0x30, 0x30,
+        0x3a, 0x30, 0x33, 0x3a, 0x30, 0x30, 0x2e, 0x30, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x39, 0x00, 0x70, 0x63, 0x69, 0x00, 0x28, 0x00, 0x3e, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x70, 0x63, 0x69, 0x00, 0x11, 0x00, 0x02, 0x00,
+        0x30, 0x30, 0x30, 0x30, 0x3a, 0x30, 0x33, 0x3a, 0x30, 0x30, 0x2e, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x42, 0x00, 0x00, 0x08, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 3,
+            link_layer_type: LinkLayerType::Ether,
+            flags: vec![
+                LinkFlag::Broadcast,
+                LinkFlag::Multicast,
+                LinkFlag::Running,
+                LinkFlag::Up,
+            ],
+            change_mask: vec![],
+        },
+        attributes: vec![
+            LinkAttribute::ParentDevName("0000:03:00.0".to_string()),
+            LinkAttribute::ParentDevBusName("pci".to_string()),
+            LinkAttribute::DevlinkPort(vec![
+                LinkDevlinkPort::BusName("pci".to_string()),
+                LinkDevlinkPort::DevName("0000:03:00.0".to_string()),
+                LinkDevlinkPort::PortIndex(1),
+            ]),
+            LinkAttribute::MaxPdu(2048),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -1,12 +1,15 @@
 // SPDX-License-Identifier: MIT
 
-use netlink_packet_utils::{nla::NlaBuffer, Emitable, ParseableParametrized};
+use netlink_packet_utils::{
+    nla::NlaBuffer, Emitable, Parseable, ParseableParametrized,
+};
 
 use crate::link::{
-    LinkAttribute, LinkVfInfo, VfInfo, VfInfoBroadcast, VfInfoLinkState,
-    VfInfoMac, VfInfoRate, VfInfoRssQueryEn, VfInfoSpoofCheck, VfInfoTrust,
-    VfInfoTxRate, VfInfoVlan, VfLinkState, VfStats, VfVlan, VfVlanInfo,
-    VlanProtocol,
+    LinkAttribute, LinkVfInfo, LinkVfPort, VfConfig, VfInfo, VfInfoBroadcast,
+    VfInfoGuid, VfInfoGuidBuffer, VfInfoLinkState, VfInfoMac, VfInfoRate,
+    VfInfoRssQueryEn, VfInfoSpoofCheck, VfInfoTrust, VfInfoTxRate, VfInfoVlan,
+    VfLinkState, VfPort, VfPortRequest, VfPortResponse, VfPortVsi, VfStats,
+    VfVlan, VfVlanInfo, VlanProtocol,
 };
 use crate::AddressFamily;
 
@@ -183,3 +186,184 @@ fn test_parsing_link_sriov() {
 
     assert_eq!(buf, raw);
 }
+
+// The `struct ifla_vf_guid` has no padding after `vf` on i386 kernel while
+// 4 bytes padding on x86_64.
+#[test]
+fn test_parsing_vf_info_guid_packed_and_aligned() {
+    let packed: Vec<u8> = vec![
+        0x01, 0x00, 0x00, 0x00, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00,
+    ];
+    let aligned: Vec<u8> = vec![
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x77, 0x66, 0x55, 0x44,
+        0x33, 0x22, 0x11, 0x00,
+    ];
+
+    let expected = VfInfoGuid::new(1, 0x0011223344556677);
+
+    assert_eq!(
+        expected,
+        VfInfoGuid::parse(&VfInfoGuidBuffer::new(&packed)).unwrap()
+    );
+    assert_eq!(
+        expected,
+        VfInfoGuid::parse(&VfInfoGuidBuffer::new(&aligned)).unwrap()
+    );
+    assert!(VfInfoGuid::parse(&VfInfoGuidBuffer::new(&packed[..8])).is_err());
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn test_emit_vf_info_guid() {
+    let raw: Vec<u8> = vec![
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x77, 0x66, 0x55, 0x44,
+        0x33, 0x22, 0x11, 0x00,
+    ];
+
+    let guid = VfInfoGuid::new(1, 0x0011223344556677);
+
+    let mut buf = vec![0; guid.buffer_len()];
+    guid.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// The VF 1 holds IFLA_VF_IB_NODE_GUID which is 16 bytes on 64 bits platform
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn test_emit_link_sriov_vf_config() {
+    let raw: Vec<u8> = vec![
+        0xbc, 0x00, 0x16, 0x00, 0x78, 0x00, 0x01, 0x00, 0x28, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x67, 0x89, 0x1a, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x0c, 0x00, 0x14, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x65, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x88, 0xa8, 0x00, 0x00,
+        0x10, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x64, 0x00, 0x00, 0x00,
+        0xe8, 0x03, 0x00, 0x00, 0x0c, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x01, 0x00, 0x28, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x23, 0x45, 0x67, 0x89, 0x1b, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x00,
+    ];
+
+    let attr = VfConfig::vf_info_list(&[
+        VfConfig::new(0)
+            .mac(&[0x00, 0x23, 0x45, 0x67, 0x89, 0x1a])
+            .vlan(101, 3, VlanProtocol::Ieee8021Ad)
+            .rate(100, 1000)
+            .link_state(VfLinkState::Auto)
+            .trust(true)
+            .spoof_check(false),
+        VfConfig::new(1)
+            .ib_node_guid(0x0011223344556677)
+            .mac(&[0x00, 0x23, 0x45, 0x67, 0x89, 0x1b]),
+    ])
+    .unwrap();
+
+    let expected = LinkAttribute::VfInfoList(vec![
+        LinkVfInfo(vec![
+            VfInfo::Mac(VfInfoMac::new(
+                0,
+                &[0x00, 0x23, 0x45, 0x67, 0x89, 0x1a],
+            )),
+            VfInfo::VlanList(vec![VfVlan::Info(VfVlanInfo::new(
+                0,
+                101,
+                3,
+                VlanProtocol::Ieee8021Ad,
+            ))]),
+            VfInfo::Rate(VfInfoRate::new(0, 100, 1000)),
+            VfInfo::LinkState(VfInfoLinkState::new(0, VfLinkState::Auto)),
+            VfInfo::Trust(VfInfoTrust::new(0, true)),
+            VfInfo::SpoofCheck(VfInfoSpoofCheck::new(0, false)),
+        ]),
+        LinkVfInfo(vec![
+            VfInfo::Mac(VfInfoMac::new(
+                1,
+                &[0x00, 0x23, 0x45, 0x67, 0x89, 0x1b],
+            )),
+            VfInfo::IbNodeGuid(VfInfoGuid::new(1, 0x0011223344556677)),
+        ]),
+    ]);
+    assert_eq!(attr, expected);
+
+    let mut buf = vec![0; attr.buffer_len()];
+    attr.emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        LinkAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            AddressFamily::Unspec
+        )
+        .unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn test_sriov_vf_config_validation() {
+    assert!(VfConfig::vf_info_list(&[VfConfig::new(0).vlan(
+        4096,
+        0,
+        VlanProtocol::Ieee8021Q
+    )])
+    .is_err());
+    assert!(VfConfig::vf_info_list(&[VfConfig::new(0).vlan(
+        100,
+        8,
+        VlanProtocol::Ieee8021Q
+    )])
+    .is_err());
+    assert!(VfConfig::vf_info_list(&[VfConfig::new(0).rate(100, 10)]).is_err());
+    assert!(VfConfig::vf_info_list(&[VfConfig::new(0).rate(100, 0)]).is_ok());
+    assert!(VfConfig::vf_info_list(&[VfConfig::new(0).mac(&[])]).is_err());
+    assert!(VfConfig::vf_info_list(&[
+        VfConfig::new(0).trust(true),
+        VfConfig::new(0).trust(false)
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_link_sriov_vf_port() {
+    let raw: Vec<u8> = vec![
+        0x4c, 0x00, 0x18, 0x00, 0x48, 0x00, 0x01, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x02, 0x00, 0x70, 0x72, 0x6f, 0x66,
+        0x31, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x00, 0x05, 0x12, 0x34, 0x56,
+        0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x04, 0x00, 0x00, 0x01, 0x02, 0x03,
+        0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        0x05, 0x00, 0x06, 0x00, 0x02, 0x00, 0x00, 0x00, 0x06, 0x00, 0x07, 0x00,
+        0x01, 0x01, 0x00, 0x00,
+    ];
+
+    let expected = LinkAttribute::VfPorts(vec![LinkVfPort(vec![
+        VfPort::Vf(2),
+        VfPort::Profile("prof1".to_string()),
+        VfPort::VsiType(VfPortVsi::new(5, [0x12, 0x34, 0x56], 1)),
+        VfPort::InstanceUuid([
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a,
+            0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        ]),
+        VfPort::Request(VfPortRequest::Associate),
+        VfPort::Response(VfPortResponse::ProfileInProgress),
+    ])]);
+
+    assert_eq!(
+        LinkAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            AddressFamily::Unspec
+        )
+        .unwrap(),
+        expected
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, raw);
+}
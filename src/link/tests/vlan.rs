@@ -1,11 +1,13 @@
 // SPDX-License-Identifier: MIT
 
+use std::collections::HashMap;
+
 use netlink_packet_utils::{Emitable, Parseable};
 
 use crate::link::{
     InfoData, InfoKind, InfoVlan, LinkAttribute, LinkFlag, LinkHeader,
-    LinkInfo, LinkLayerType, LinkMessage, LinkMessageBuffer, VlanProtocol,
-    VlanQosMapping,
+    LinkInfo, LinkLayerType, LinkMessage, LinkMessageBuffer, VlanFlag,
+    VlanFlagMask, VlanProtocol, VlanQosMapping,
 };
 use crate::AddressFamily;
 
@@ -67,7 +69,10 @@ fn test_parsing_link_vlan() {
             LinkInfo::Data(InfoData::Vlan(vec![
                 InfoVlan::Protocol(VlanProtocol::Ieee8021Q),
                 InfoVlan::Id(101),
-                InfoVlan::Flags((1, 4294967295)),
+                InfoVlan::Flags((
+                    vec![VlanFlag::ReorderHdr],
+                    VlanFlagMask::ALL,
+                )),
                 InfoVlan::IngressQos(vec![VlanQosMapping::Mapping(6, 7)]),
                 InfoVlan::EgressQos(vec![VlanQosMapping::Mapping(4, 5)]),
             ])),
@@ -85,3 +90,54 @@ fn test_parsing_link_vlan() {
 
     assert_eq!(buf, raw);
 }
+
+#[test]
+fn test_vlan_qos_map() {
+    let ingress = HashMap::from([(7u8, 6u32), (1, 2)]);
+    let mappings = VlanQosMapping::from_ingress_map(&ingress).unwrap();
+    assert_eq!(
+        mappings,
+        vec![VlanQosMapping::Mapping(1, 2), VlanQosMapping::Mapping(7, 6)]
+    );
+    assert_eq!(VlanQosMapping::to_ingress_map(&mappings).unwrap(), ingress);
+
+    let egress = HashMap::from([(4u32, 5u8), (100, 0)]);
+    let mappings = VlanQosMapping::from_egress_map(&egress).unwrap();
+    assert_eq!(
+        mappings,
+        vec![
+            VlanQosMapping::Mapping(4, 5),
+            VlanQosMapping::Mapping(100, 0)
+        ]
+    );
+    assert_eq!(VlanQosMapping::to_egress_map(&mappings).unwrap(), egress);
+
+    assert!(VlanQosMapping::from_ingress_map(&HashMap::from([(8, 1)])).is_err());
+    assert!(VlanQosMapping::from_egress_map(&HashMap::from([(1, 8)])).is_err());
+    assert!(
+        VlanQosMapping::to_egress_map(&[VlanQosMapping::Mapping(1, 8)])
+            .is_err()
+    );
+}
+
+#[test]
+fn test_vlan_flag_mask() {
+    let mask = VlanFlagMask::new(&[VlanFlag::Gvrp, VlanFlag::Mvrp]);
+    assert_eq!(mask, VlanFlagMask(0xa));
+    assert!(mask.contains(VlanFlag::Mvrp));
+    assert!(!mask.contains(VlanFlag::ReorderHdr));
+    assert_eq!(mask.flags(), vec![VlanFlag::Gvrp, VlanFlag::Mvrp]);
+
+    assert_eq!(VlanFlagMask::ALL.flags().len(), 5);
+
+    let nla = InfoVlan::Flags((vec![VlanFlag::Gvrp], mask));
+    let mut buf = vec![0; nla.buffer_len()];
+    nla.emit(&mut buf);
+    assert_eq!(
+        buf,
+        [
+            0x0c, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00,
+            0x00
+        ]
+    );
+}
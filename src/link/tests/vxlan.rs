@@ -1,13 +1,12 @@
 // SPDX-License-Identifier: MIT
 
-use netlink_packet_utils::{nla::DefaultNla, Emitable, Parseable};
+use netlink_packet_utils::{Emitable, Parseable};
 
 use crate::link::{
     AfSpecInet, AfSpecInet6, AfSpecUnspec, Inet6CacheInfo, Inet6DevConf,
-    Inet6IfaceFlag, Inet6IfaceFlags, InetDevConf, InfoData, InfoKind,
-    InfoVxlan, LinkAttribute, LinkFlag, LinkHeader, LinkInfo, LinkLayerType,
-    LinkMessage, LinkMessageBuffer, LinkXdp, Map, State, Stats, Stats64,
-    XdpAttached,
+    Inet6IfaceFlag, InetDevConf, InfoData, InfoKind, InfoVxlan, LinkAttribute,
+    LinkFlag, LinkHeader, LinkInfo, LinkLayerType, LinkMessage,
+    LinkMessageBuffer, LinkXdp, Map, State, Stats, Stats64, XdpAttached,
 };
 use crate::AddressFamily;
 
@@ -144,15 +143,15 @@ fn test_parsing_link_vxlan() {
             LinkAttribute::MaxMtu(65535),
             LinkAttribute::Group(0),
             LinkAttribute::Promiscuity(0),
-            LinkAttribute::Other(DefaultNla::new(61, vec![0, 0, 0, 0])),
+            LinkAttribute::Allmulti(0),
             LinkAttribute::NumTxQueues(1),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(63, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(64, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(59, vec![248, 255, 7, 0])),
-            LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
+            LinkAttribute::GroMaxSize(65536),
+            LinkAttribute::GsoIpv4MaxSize(65536),
+            LinkAttribute::GroIpv4MaxSize(65536),
+            LinkAttribute::TsoMaxSize(524280),
+            LinkAttribute::TsoMaxSegs(65535),
             LinkAttribute::NumRxQueues(1),
             LinkAttribute::Carrier(1),
             LinkAttribute::Qdisc("noqueue".to_string()),
@@ -291,10 +290,10 @@ fn test_parsing_link_vxlan() {
                     arp_evict_nocarrier: 1,
                 })]),
                 AfSpecUnspec::Inet6(vec![
-                    AfSpecInet6::Flags(Inet6IfaceFlags(vec![
+                    AfSpecInet6::Flags(vec![
                         Inet6IfaceFlag::RsSent,
                         Inet6IfaceFlag::Ready,
-                    ])),
+                    ]),
                     AfSpecInet6::CacheInfo(Inet6CacheInfo {
                         max_reasm_len: 65535,
                         tstamp: 7344,
@@ -364,7 +363,7 @@ fn test_parsing_link_vxlan() {
                     }),
                 ]),
             ]),
-            LinkAttribute::Other(DefaultNla::new(32830, vec![])),
+            LinkAttribute::DevlinkPort(vec![]),
         ],
     };
 
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::{
+    LinkAttribute, LinkFlag, LinkHeader, LinkLayerType, LinkMessage,
+    LinkMessageBuffer, LinkWirelessEvent, WirelessPmkidCandidate,
+};
+use crate::AddressFamily;
+
+fn wlan_header() -> LinkHeader {
+    LinkHeader {
+        interface_family: AddressFamily::Unspec,
+        index: 3,
+        link_layer_type: LinkLayerType::Ether,
+        flags: vec![
+            LinkFlag::Broadcast,
+            LinkFlag::LowerUp,
+            LinkFlag::Multicast,
+            LinkFlag::Running,
+            LinkFlag::Up,
+        ],
+        change_mask: vec![],
+    }
+}
+
+#[test]
+fn test_wireless_event_ap() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x0b, 0x00, 0x18, 0x00, 0x15, 0x8b,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: wlan_header(),
+        attributes: vec![LinkAttribute::Wireless(vec![LinkWirelessEvent::Ap(
+            [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+        )])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_wireless_event_custom_and_pmkid_candidate() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x66, 0x00, 0x0b, 0x00, 0x3a, 0x00, 0x02, 0x8c,
+        0x00, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x4d, 0x4c, 0x4d, 0x45, 0x2d, 0x4d, 0x49, 0x43, 0x48, 0x41, 0x45, 0x4c,
+        0x4d, 0x49, 0x43, 0x46, 0x41, 0x49, 0x4c, 0x55, 0x52, 0x45, 0x2e, 0x69,
+        0x6e, 0x64, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x28, 0x6b, 0x65,
+        0x79, 0x69, 0x64, 0x3d, 0x30, 0x29, 0x28, 0x00, 0x09, 0x8c, 0x00, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x11, 0x22, 0x33,
+        0x44, 0x66, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: wlan_header(),
+        attributes: vec![LinkAttribute::Wireless(vec![
+            LinkWirelessEvent::Custom(
+                b"MLME-MICHAELMICFAILURE.indication(keyid=0)".to_vec(),
+            ),
+            LinkWirelessEvent::PmkidCandidate(WirelessPmkidCandidate {
+                preauth: true,
+                index: 0,
+                bssid: [0x00, 0x11, 0x22, 0x33, 0x44, 0x66],
+            }),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -5,7 +5,7 @@ use netlink_packet_utils::{
     Emitable, Parseable,
 };
 
-use crate::link::{xdp::VecLinkXdp, LinkXdp, XdpAttached};
+use crate::link::{xdp::VecLinkXdp, LinkXdp, XdpAttached, XdpFlag};
 
 static ATTACHED: [u8; 48] = [
     0x05, 0x00, // length = 5
@@ -138,7 +138,7 @@ fn parse_xdp() {
     let parsed = VecLinkXdp::parse(&nla).unwrap().0;
     let expected = vec![
         LinkXdp::Fd(29856),
-        LinkXdp::Flags(0),
+        LinkXdp::Flags(vec![]),
         LinkXdp::ProgId(103),
         LinkXdp::DrvProgId(101),
         LinkXdp::SkbProgId(101),
@@ -156,7 +156,7 @@ fn parse_xdp() {
 fn emit_xdp() {
     let nlas = vec![
         LinkXdp::Fd(29856),
-        LinkXdp::Flags(0),
+        LinkXdp::Flags(vec![]),
         LinkXdp::ProgId(103),
         LinkXdp::DrvProgId(101),
         LinkXdp::SkbProgId(101),
@@ -173,3 +173,100 @@ fn emit_xdp() {
     nlas.as_slice().emit(&mut vec);
     assert_eq!(&vec[..], &XDP[..]);
 }
+
+#[test]
+fn emit_xdp_replace() {
+    let raw: Vec<u8> = vec![
+        0x08, 0x00, // length = 8
+        0x01, 0x00, // type = 1 = IFLA_XDP_FD
+        0x0a, 0x00, 0x00, 0x00, // 10
+        0x08, 0x00, // length = 8
+        0x03, 0x00, // type = 3 = IFLA_XDP_FLAGS
+        0x14, 0x00, 0x00, 0x00, // XDP_FLAGS_DRV_MODE | XDP_FLAGS_REPLACE
+        0x08, 0x00, // length = 8
+        0x08, 0x00, // type = 8 = IFLA_XDP_EXPECTED_FD
+        0x09, 0x00, 0x00, 0x00, // 9
+    ];
+    let nlas = LinkXdp::replace(10, 9, &[XdpFlag::DrvMode]).unwrap();
+    assert_eq!(
+        nlas,
+        vec![
+            LinkXdp::Fd(10),
+            LinkXdp::Flags(vec![XdpFlag::DrvMode, XdpFlag::Replace]),
+            LinkXdp::ExpectedFd(9),
+        ]
+    );
+
+    let mut buf = vec![0; nlas.as_slice().buffer_len()];
+    nlas.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    let nla = NlaBuffer::new_checked(&raw[..]).unwrap();
+    assert_eq!(VecLinkXdp::parse(&nla).unwrap().0, nlas);
+}
+
+#[test]
+fn xdp_attach_and_detach() {
+    assert_eq!(
+        LinkXdp::attach(10, &[XdpFlag::UpdateIfNoExist, XdpFlag::SkbMode])
+            .unwrap(),
+        vec![
+            LinkXdp::Fd(10),
+            LinkXdp::Flags(vec![XdpFlag::UpdateIfNoExist, XdpFlag::SkbMode]),
+        ]
+    );
+    assert_eq!(LinkXdp::detach(&[]).unwrap(), vec![LinkXdp::Fd(-1)]);
+
+    assert!(LinkXdp::attach(10, &[XdpFlag::SkbMode, XdpFlag::DrvMode]).is_err());
+    assert!(LinkXdp::attach(10, &[XdpFlag::Replace]).is_err());
+    assert!(LinkXdp::attach(10, &[XdpFlag::Other(1 << 5)]).is_err());
+    assert!(LinkXdp::replace(10, 9, &[XdpFlag::UpdateIfNoExist]).is_err());
+    assert!(LinkXdp::detach(&[XdpFlag::DrvMode, XdpFlag::HwMode]).is_err());
+
+    assert!(LinkXdp::detach(&[XdpFlag::Replace]).is_err());
+}
+
+#[test]
+fn xdp_attach_duplicate_flags() {
+    let nlas = LinkXdp::attach(
+        10,
+        &[XdpFlag::UpdateIfNoExist, XdpFlag::UpdateIfNoExist],
+    )
+    .unwrap();
+    assert_eq!(
+        nlas,
+        vec![
+            LinkXdp::Fd(10),
+            LinkXdp::Flags(vec![XdpFlag::UpdateIfNoExist])
+        ]
+    );
+
+    let raw: [u8; 16] = [
+        0x08, 0x00, 0x01, 0x00, // length 8, IFLA_XDP_FD
+        0x0a, 0x00, 0x00, 0x00, // 10
+        0x08, 0x00, 0x03, 0x00, // length 8, IFLA_XDP_FLAGS
+        0x01, 0x00, 0x00, 0x00, // XDP_FLAGS_UPDATE_IF_NOEXIST
+    ];
+    let mut buf = vec![0; nlas.as_slice().buffer_len()];
+    nlas.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+
+    // Duplicate flags should not be summed up when emitting
+    let nla = LinkXdp::Flags(vec![XdpFlag::SkbMode, XdpFlag::SkbMode]);
+    let mut buf = vec![0; nla.buffer_len()];
+    nla.emit(&mut buf);
+    assert_eq!(buf, [0x08, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn xdp_detach_expected() {
+    assert_eq!(
+        LinkXdp::detach_expected(9, &[XdpFlag::DrvMode]).unwrap(),
+        vec![
+            LinkXdp::Fd(-1),
+            LinkXdp::Flags(vec![XdpFlag::DrvMode, XdpFlag::Replace]),
+            LinkXdp::ExpectedFd(9),
+        ]
+    );
+    assert!(LinkXdp::detach_expected(9, &[XdpFlag::UpdateIfNoExist]).is_err());
+}
@@ -1,24 +1,344 @@
 // SPDX-License-Identifier: MIT
 
+use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{DecodeError, Emitable, Parseable};
 
-// Place holder for kernel code is `struct iw_event`
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
+// Kernel is using `struct iw_event` which holds a `union iwreq_data`. The
+// union contains a pointer, hence on 64 bits system the union is 8 bytes
+// aligned and the `len` and `cmd` fields are followed by 4 bytes of padding.
+#[cfg(target_pointer_width = "64")]
+const IW_EV_LCP_LEN: usize = 8;
+#[cfg(not(target_pointer_width = "64"))]
+const IW_EV_LCP_LEN: usize = 4;
+
+// `struct iw_point` without the user space pointer: `length` and `flags`
+// plus the tailing padding of the union on 64 bits system.
+#[cfg(target_pointer_width = "64")]
+const IW_EV_POINT_LEN: usize = IW_EV_LCP_LEN + 8;
+#[cfg(not(target_pointer_width = "64"))]
+const IW_EV_POINT_LEN: usize = IW_EV_LCP_LEN + 4;
+
+// `struct sockaddr` is 16 bytes
+const SOCKADDR_LEN: usize = 16;
+const IW_EV_ADDR_LEN: usize = IW_EV_LCP_LEN + SOCKADDR_LEN;
+
+const ARPHRD_ETHER: u16 = 1;
+const ETH_ALEN: usize = 6;
+
+const SIOCGIWAP: u16 = 0x8B15;
+const SIOCGIWSCAN: u16 = 0x8B19;
+const IWEVCUSTOM: u16 = 0x8C02;
+const IWEVMICHAELMICFAILURE: u16 = 0x8C06;
+const IWEVASSOCREQIE: u16 = 0x8C07;
+const IWEVASSOCRESPIE: u16 = 0x8C08;
+const IWEVPMKIDCAND: u16 = 0x8C09;
+
+/// Wireless extension event (kernel `struct iw_event`) carried by
+/// `IFLA_WIRELESS`.
+#[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
-pub struct LinkWirelessEvent(Vec<u8>);
+pub enum LinkWirelessEvent {
+    /// `SIOCGIWAP`: MAC address of the associated access point. All zero
+    /// address means disassociated.
+    Ap([u8; ETH_ALEN]),
+    /// `SIOCGIWSCAN`: Scan results are available.
+    ScanComplete,
+    /// `IWEVCUSTOM`: Driver specific text.
+    Custom(Vec<u8>),
+    /// `IWEVASSOCREQIE`: Information elements of the association request.
+    AssocReqIe(Vec<u8>),
+    /// `IWEVASSOCRESPIE`: Information elements of the association response.
+    AssocRespIe(Vec<u8>),
+    /// `IWEVMICHAELMICFAILURE`
+    MichaelMicFailure(WirelessMichaelMicFailure),
+    /// `IWEVPMKIDCAND`: PMKSA cache candidate for pre-authentication.
+    PmkidCandidate(WirelessPmkidCandidate),
+    /// Unknown command with the raw data following the `len` and `cmd`
+    /// header (including padding).
+    Other(u16, Vec<u8>),
+}
+
+impl LinkWirelessEvent {
+    fn cmd(&self) -> u16 {
+        match self {
+            Self::Ap(_) => SIOCGIWAP,
+            Self::ScanComplete => SIOCGIWSCAN,
+            Self::Custom(_) => IWEVCUSTOM,
+            Self::AssocReqIe(_) => IWEVASSOCREQIE,
+            Self::AssocRespIe(_) => IWEVASSOCRESPIE,
+            Self::MichaelMicFailure(_) => IWEVMICHAELMICFAILURE,
+            Self::PmkidCandidate(_) => IWEVPMKIDCAND,
+            Self::Other(cmd, _) => *cmd,
+        }
+    }
+
+    // The extra data of `IW_HEADER_TYPE_POINT` events
+    fn point_extra_len(&self) -> Option<usize> {
+        match self {
+            Self::ScanComplete => Some(0),
+            Self::Custom(v) | Self::AssocReqIe(v) | Self::AssocRespIe(v) => {
+                Some(v.len())
+            }
+            Self::MichaelMicFailure(v) => Some(v.buffer_len()),
+            Self::PmkidCandidate(v) => Some(v.buffer_len()),
+            Self::Ap(_) | Self::Other(..) => None,
+        }
+    }
+}
 
 impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for LinkWirelessEvent {
     fn parse(buf: &T) -> Result<Self, DecodeError> {
-        Ok(LinkWirelessEvent(buf.as_ref().to_vec()))
+        let buf = buf.as_ref();
+        if buf.len() < IW_EV_LCP_LEN {
+            return Err(format!(
+                "invalid IFLA_WIRELESS event {buf:?}: too short"
+            )
+            .into());
+        }
+        let cmd = NativeEndian::read_u16(&buf[2..4]);
+        let data = &buf[IW_EV_LCP_LEN..];
+
+        Ok(match cmd {
+            SIOCGIWAP => {
+                if buf.len() < IW_EV_ADDR_LEN {
+                    return Err(format!(
+                        "invalid SIOCGIWAP event {buf:?}: too short"
+                    )
+                    .into());
+                }
+                let mut mac = [0u8; ETH_ALEN];
+                mac.copy_from_slice(&data[2..2 + ETH_ALEN]);
+                Self::Ap(mac)
+            }
+            SIOCGIWSCAN
+            | IWEVCUSTOM
+            | IWEVASSOCREQIE
+            | IWEVASSOCRESPIE
+            | IWEVMICHAELMICFAILURE
+            | IWEVPMKIDCAND => {
+                if buf.len() < IW_EV_POINT_LEN {
+                    return Err(format!(
+                        "invalid IFLA_WIRELESS event {buf:?}: too short \
+                        for cmd {cmd:#x}"
+                    )
+                    .into());
+                }
+                let length = NativeEndian::read_u16(&data[0..2]) as usize;
+                let extra = &buf[IW_EV_POINT_LEN..];
+                if extra.len() < length {
+                    return Err(format!(
+                        "invalid IFLA_WIRELESS event {buf:?}: \
+                        data length {length} exceeds event length"
+                    )
+                    .into());
+                }
+                let extra = &extra[..length];
+                match cmd {
+                    SIOCGIWSCAN => Self::ScanComplete,
+                    IWEVCUSTOM => Self::Custom(extra.to_vec()),
+                    IWEVASSOCREQIE => Self::AssocReqIe(extra.to_vec()),
+                    IWEVASSOCRESPIE => Self::AssocRespIe(extra.to_vec()),
+                    IWEVMICHAELMICFAILURE => Self::MichaelMicFailure(
+                        WirelessMichaelMicFailure::parse(extra)?,
+                    ),
+                    _ => Self::PmkidCandidate(WirelessPmkidCandidate::parse(
+                        extra,
+                    )?),
+                }
+            }
+            _ => Self::Other(cmd, data.to_vec()),
+        })
     }
 }
 
 impl Emitable for LinkWirelessEvent {
     fn buffer_len(&self) -> usize {
-        self.0.len()
+        match self {
+            Self::Ap(_) => IW_EV_ADDR_LEN,
+            Self::Other(_, data) => IW_EV_LCP_LEN + data.len(),
+            _ => IW_EV_POINT_LEN + self.point_extra_len().unwrap_or_default(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        buffer[..len].fill(0);
+        NativeEndian::write_u16(&mut buffer[0..2], len as u16);
+        NativeEndian::write_u16(&mut buffer[2..4], self.cmd());
+        let data = &mut buffer[IW_EV_LCP_LEN..len];
+        match self {
+            Self::Ap(mac) => {
+                NativeEndian::write_u16(&mut data[0..2], ARPHRD_ETHER);
+                data[2..2 + ETH_ALEN].copy_from_slice(mac);
+            }
+            Self::Other(_, v) => data.copy_from_slice(v),
+            _ => {
+                let extra_len = self.point_extra_len().unwrap_or_default();
+                NativeEndian::write_u16(&mut data[0..2], extra_len as u16);
+                let extra = &mut buffer[IW_EV_POINT_LEN..len];
+                match self {
+                    Self::Custom(v)
+                    | Self::AssocReqIe(v)
+                    | Self::AssocRespIe(v) => extra.copy_from_slice(v),
+                    Self::MichaelMicFailure(v) => v.emit(extra),
+                    Self::PmkidCandidate(v) => v.emit(extra),
+                    _ => (),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub(crate) struct VecLinkWirelessEvent(pub(crate) Vec<LinkWirelessEvent>);
+
+// The IFLA_WIRELESS payload is a stream of `struct iw_event`, each one
+// starting with its own length.
+impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for VecLinkWirelessEvent {
+    fn parse(buf: &T) -> Result<Self, DecodeError> {
+        let mut buf = buf.as_ref();
+        let mut ret = Vec::new();
+        while buf.len() >= IW_EV_LCP_LEN {
+            let len = NativeEndian::read_u16(&buf[0..2]) as usize;
+            if len < IW_EV_LCP_LEN || len > buf.len() {
+                return Err(format!(
+                    "invalid IFLA_WIRELESS event length {len}, \
+                    remaining data {buf:?}"
+                )
+                .into());
+            }
+            ret.push(LinkWirelessEvent::parse(&buf[..len])?);
+            buf = &buf[len..];
+        }
+        Ok(Self(ret))
+    }
+}
+
+const IW_MICFAILURE_KEY_ID: u32 = 0x00000003;
+const IW_MICFAILURE_GROUP: u32 = 0x00000004;
+const IW_MICFAILURE_PAIRWISE: u32 = 0x00000008;
+const IW_MICFAILURE_STAKEY: u32 = 0x00000010;
+const IW_MICFAILURE_COUNT: u32 = 0x00000060;
+const IW_ENCODE_SEQ_MAX_SIZE: usize = 8;
+
+// flags(u32) + src_addr(struct sockaddr) + tsc
+const IW_MICHAELMICFAILURE_LEN: usize =
+    4 + SOCKADDR_LEN + IW_ENCODE_SEQ_MAX_SIZE;
+
+/// Kernel `struct iw_michaelmicfailure`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct WirelessMichaelMicFailure {
+    pub key_id: u8,
+    pub group: bool,
+    pub pairwise: bool,
+    pub stakey: bool,
+    /// 1 for first failure, 2 for second failure in 60 seconds, 3 or more
+    pub count: u8,
+    pub src_addr: [u8; ETH_ALEN],
+    /// TSC (TKIP sequence counter) of the frame
+    pub tsc: [u8; IW_ENCODE_SEQ_MAX_SIZE],
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for WirelessMichaelMicFailure {
+    fn parse(buf: &T) -> Result<Self, DecodeError> {
+        let buf = buf.as_ref();
+        if buf.len() < IW_MICHAELMICFAILURE_LEN {
+            return Err(format!(
+                "invalid IWEVMICHAELMICFAILURE data {buf:?}: too short"
+            )
+            .into());
+        }
+        let flags = NativeEndian::read_u32(&buf[0..4]);
+        let mut ret = Self {
+            key_id: (flags & IW_MICFAILURE_KEY_ID) as u8,
+            group: flags & IW_MICFAILURE_GROUP > 0,
+            pairwise: flags & IW_MICFAILURE_PAIRWISE > 0,
+            stakey: flags & IW_MICFAILURE_STAKEY > 0,
+            count: ((flags & IW_MICFAILURE_COUNT) >> 5) as u8,
+            ..Default::default()
+        };
+        ret.src_addr.copy_from_slice(&buf[6..6 + ETH_ALEN]);
+        ret.tsc
+            .copy_from_slice(&buf[4 + SOCKADDR_LEN..IW_MICHAELMICFAILURE_LEN]);
+        Ok(ret)
+    }
+}
+
+impl Emitable for WirelessMichaelMicFailure {
+    fn buffer_len(&self) -> usize {
+        IW_MICHAELMICFAILURE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut flags = u32::from(self.key_id) & IW_MICFAILURE_KEY_ID;
+        if self.group {
+            flags |= IW_MICFAILURE_GROUP;
+        }
+        if self.pairwise {
+            flags |= IW_MICFAILURE_PAIRWISE;
+        }
+        if self.stakey {
+            flags |= IW_MICFAILURE_STAKEY;
+        }
+        flags |= (u32::from(self.count) << 5) & IW_MICFAILURE_COUNT;
+        buffer[..IW_MICHAELMICFAILURE_LEN].fill(0);
+        NativeEndian::write_u32(&mut buffer[0..4], flags);
+        NativeEndian::write_u16(&mut buffer[4..6], ARPHRD_ETHER);
+        buffer[6..6 + ETH_ALEN].copy_from_slice(&self.src_addr);
+        buffer[4 + SOCKADDR_LEN..IW_MICHAELMICFAILURE_LEN]
+            .copy_from_slice(&self.tsc);
+    }
+}
+
+const IW_PMKID_CAND_PREAUTH: u32 = 0x00000001;
+
+// flags(u32) + index(u32) + bssid(struct sockaddr)
+const IW_PMKID_CAND_LEN: usize = 8 + SOCKADDR_LEN;
+
+/// Kernel `struct iw_pmkid_cand`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct WirelessPmkidCandidate {
+    /// RSN pre-authentication is enabled for this candidate
+    pub preauth: bool,
+    /// The smaller the index, the higher the priority
+    pub index: u32,
+    pub bssid: [u8; ETH_ALEN],
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for WirelessPmkidCandidate {
+    fn parse(buf: &T) -> Result<Self, DecodeError> {
+        let buf = buf.as_ref();
+        if buf.len() < IW_PMKID_CAND_LEN {
+            return Err(format!(
+                "invalid IWEVPMKIDCAND data {buf:?}: too short"
+            )
+            .into());
+        }
+        let mut ret = Self {
+            preauth: NativeEndian::read_u32(&buf[0..4]) & IW_PMKID_CAND_PREAUTH
+                > 0,
+            index: NativeEndian::read_u32(&buf[4..8]),
+            ..Default::default()
+        };
+        ret.bssid.copy_from_slice(&buf[10..10 + ETH_ALEN]);
+        Ok(ret)
+    }
+}
+
+impl Emitable for WirelessPmkidCandidate {
+    fn buffer_len(&self) -> usize {
+        IW_PMKID_CAND_LEN
     }
 
     fn emit(&self, buffer: &mut [u8]) {
-        buffer.copy_from_slice(self.0.as_slice())
+        buffer[..IW_PMKID_CAND_LEN].fill(0);
+        if self.preauth {
+            NativeEndian::write_u32(&mut buffer[0..4], IW_PMKID_CAND_PREAUTH);
+        }
+        NativeEndian::write_u32(&mut buffer[4..8], self.index);
+        NativeEndian::write_u16(&mut buffer[8..10], ARPHRD_ETHER);
+        buffer[10..10 + ETH_ALEN].copy_from_slice(&self.bssid);
     }
 }
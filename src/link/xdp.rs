@@ -30,7 +30,7 @@ const XDP_ATTACHED_MULTI: u8 = 4;
 pub enum LinkXdp {
     Fd(RawFd),
     Attached(XdpAttached),
-    Flags(u32),
+    Flags(Vec<XdpFlag>),
     ProgId(u32),
     DrvProgId(u32),
     SkbProgId(u32),
@@ -58,7 +58,10 @@ impl Nla for LinkXdp {
         match self {
             Self::Fd(ref value) => NativeEndian::write_i32(buffer, *value),
             Self::Attached(ref value) => buffer[0] = value.as_u8(),
-            Self::Flags(ref value) => NativeEndian::write_u32(buffer, *value),
+            Self::Flags(ref flags) => NativeEndian::write_u32(
+                buffer,
+                u32::from(&VecXdpFlag(flags.to_vec())),
+            ),
             Self::ProgId(ref value) => NativeEndian::write_u32(buffer, *value),
             Self::DrvProgId(ref value) => {
                 NativeEndian::write_u32(buffer, *value)
@@ -103,7 +106,11 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for LinkXdp {
                     .context("invalid IFLA_XDP_ATTACHED value")?,
             ),
             IFLA_XDP_FLAGS => Self::Flags(
-                parse_u32(payload).context("invalid IFLA_XDP_FLAGS value")?,
+                VecXdpFlag::from(
+                    parse_u32(payload)
+                        .context("invalid IFLA_XDP_FLAGS value")?,
+                )
+                .0,
             ),
             IFLA_XDP_PROG_ID => Self::ProgId(
                 parse_u32(payload).context("invalid IFLA_XDP_PROG_ID value")?,
@@ -128,6 +135,110 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for LinkXdp {
     }
 }
 
+impl LinkXdp {
+    /// Generate `IFLA_XDP` NLAs for attaching XDP program `fd` to link.
+    /// Use [LinkXdp::replace] for atomically replacing existing program.
+    pub fn attach(
+        fd: RawFd,
+        flags: &[XdpFlag],
+    ) -> Result<Vec<LinkXdp>, DecodeError> {
+        if flags.contains(&XdpFlag::Replace) {
+            return Err("XdpFlag::Replace should be set via \
+                LinkXdp::replace()"
+                .into());
+        }
+        validate_xdp_flags(flags)?;
+        Ok(Self::new_xdp_nlas(fd, flags, None))
+    }
+
+    /// Generate `IFLA_XDP` NLAs for replacing XDP program `expected_fd`
+    /// with `fd`. Kernel will fail the request if currently attached
+    /// program is not `expected_fd`. The `XdpFlag::Replace` is included
+    /// automatically.
+    pub fn replace(
+        fd: RawFd,
+        expected_fd: RawFd,
+        flags: &[XdpFlag],
+    ) -> Result<Vec<LinkXdp>, DecodeError> {
+        if flags.contains(&XdpFlag::UpdateIfNoExist) {
+            return Err("XdpFlag::UpdateIfNoExist cannot be used for \
+                replacing XDP program"
+                .into());
+        }
+        let mut flags = flags.to_vec();
+        if !flags.contains(&XdpFlag::Replace) {
+            flags.push(XdpFlag::Replace);
+        }
+        validate_xdp_flags(&flags)?;
+        Ok(Self::new_xdp_nlas(fd, &flags, Some(expected_fd)))
+    }
+
+    /// Generate `IFLA_XDP` NLAs for detaching XDP program from link.
+    /// The mode flags should match the ones used for attaching.
+    /// Use [LinkXdp::detach_expected] for detaching only when the attached
+    /// program is the expected one.
+    pub fn detach(flags: &[XdpFlag]) -> Result<Vec<LinkXdp>, DecodeError> {
+        if flags.contains(&XdpFlag::Replace) {
+            return Err("XdpFlag::Replace should be set via \
+                LinkXdp::detach_expected()"
+                .into());
+        }
+        validate_xdp_flags(flags)?;
+        Ok(Self::new_xdp_nlas(-1, flags, None))
+    }
+
+    /// Generate `IFLA_XDP` NLAs for detaching XDP program `expected_fd`
+    /// from link. Kernel will fail the request if currently attached
+    /// program is not `expected_fd`. The `XdpFlag::Replace` is included
+    /// automatically.
+    pub fn detach_expected(
+        expected_fd: RawFd,
+        flags: &[XdpFlag],
+    ) -> Result<Vec<LinkXdp>, DecodeError> {
+        Self::replace(-1, expected_fd, flags)
+    }
+
+    fn new_xdp_nlas(
+        fd: RawFd,
+        flags: &[XdpFlag],
+        expected_fd: Option<RawFd>,
+    ) -> Vec<LinkXdp> {
+        let mut nlas = vec![LinkXdp::Fd(fd)];
+        let mut uniq_flags: Vec<XdpFlag> = Vec::new();
+        for flag in flags {
+            if !uniq_flags.contains(flag) {
+                uniq_flags.push(*flag);
+            }
+        }
+        if !uniq_flags.is_empty() {
+            nlas.push(LinkXdp::Flags(uniq_flags));
+        }
+        if let Some(expected_fd) = expected_fd {
+            nlas.push(LinkXdp::ExpectedFd(expected_fd as u32));
+        }
+        nlas
+    }
+}
+
+fn validate_xdp_flags(flags: &[XdpFlag]) -> Result<(), DecodeError> {
+    if let Some(flag) = flags.iter().find(|f| matches!(f, XdpFlag::Other(_))) {
+        return Err(format!("Unsupported XDP flag {flag}").into());
+    }
+    let modes: Vec<&XdpFlag> = flags
+        .iter()
+        .filter(|f| {
+            matches!(f, XdpFlag::SkbMode | XdpFlag::DrvMode | XdpFlag::HwMode)
+        })
+        .collect();
+    if modes.len() > 1 {
+        return Err(format!(
+            "Only one XDP mode flag can be set, but got {modes:?}"
+        )
+        .into());
+    }
+    Ok(())
+}
+
 pub(crate) struct VecLinkXdp(pub(crate) Vec<LinkXdp>);
 
 // These NLAs are nested, meaning they are NLAs that contain NLAs. These NLAs
@@ -192,3 +303,89 @@ impl XdpAttached {
         }
     }
 }
+
+const XDP_FLAGS_UPDATE_IF_NOEXIST: u32 = 1 << 0;
+const XDP_FLAGS_SKB_MODE: u32 = 1 << 1;
+const XDP_FLAGS_DRV_MODE: u32 = 1 << 2;
+const XDP_FLAGS_HW_MODE: u32 = 1 << 3;
+const XDP_FLAGS_REPLACE: u32 = 1 << 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum XdpFlag {
+    /// Fail if there is XDP program attached already
+    UpdateIfNoExist,
+    /// Generic XDP in network stack
+    SkbMode,
+    /// Native XDP in driver
+    DrvMode,
+    /// Offloaded XDP in hardware
+    HwMode,
+    /// Replace the program specified by `LinkXdp::ExpectedFd`
+    Replace,
+    Other(u32),
+}
+
+impl From<XdpFlag> for u32 {
+    fn from(v: XdpFlag) -> u32 {
+        match v {
+            XdpFlag::UpdateIfNoExist => XDP_FLAGS_UPDATE_IF_NOEXIST,
+            XdpFlag::SkbMode => XDP_FLAGS_SKB_MODE,
+            XdpFlag::DrvMode => XDP_FLAGS_DRV_MODE,
+            XdpFlag::HwMode => XDP_FLAGS_HW_MODE,
+            XdpFlag::Replace => XDP_FLAGS_REPLACE,
+            XdpFlag::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for XdpFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UpdateIfNoExist => write!(f, "update_if_noexist"),
+            Self::SkbMode => write!(f, "skb_mode"),
+            Self::DrvMode => write!(f, "drv_mode"),
+            Self::HwMode => write!(f, "hw_mode"),
+            Self::Replace => write!(f, "replace"),
+            Self::Other(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+const ALL_XDP_FLAGS: [XdpFlag; 5] = [
+    XdpFlag::UpdateIfNoExist,
+    XdpFlag::SkbMode,
+    XdpFlag::DrvMode,
+    XdpFlag::HwMode,
+    XdpFlag::Replace,
+];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecXdpFlag(Vec<XdpFlag>);
+
+impl From<u32> for VecXdpFlag {
+    fn from(d: u32) -> Self {
+        let mut got: u32 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_XDP_FLAGS {
+            if (d & (u32::from(flag))) > 0 {
+                ret.push(flag);
+                got += u32::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(XdpFlag::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecXdpFlag> for u32 {
+    fn from(v: &VecXdpFlag) -> u32 {
+        let mut d: u32 = 0;
+        for flag in &v.0 {
+            d |= u32::from(*flag);
+        }
+        d
+    }
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use netlink_packet_utils::{DecodeError, Emitable};
 
@@ -32,6 +32,10 @@ impl RouteAddress {
             AddressFamily::Inet6 => Self::Inet6(parse_ipv6_addr(payload)?),
             #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
             AddressFamily::Mpls => Self::Mpls(MplsLabel::parse(payload)?),
+            #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
+            AddressFamily::Ipmr => Self::Inet(parse_ipv4_addr(payload)?),
+            #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
+            AddressFamily::Ip6mr => Self::Inet6(parse_ipv6_addr(payload)?),
             _ => Self::Other(payload.to_vec()),
         })
     }
@@ -69,6 +73,15 @@ impl From<Ipv6Addr> for RouteAddress {
     }
 }
 
+impl From<IpAddr> for RouteAddress {
+    fn from(v: IpAddr) -> Self {
+        match v {
+            IpAddr::V4(v) => Self::Inet(v),
+            IpAddr::V6(v) => Self::Inet6(v),
+        }
+    }
+}
+
 impl From<MplsLabel> for RouteAddress {
     fn from(v: MplsLabel) -> Self {
         Self::Mpls(v)
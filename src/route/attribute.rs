@@ -1,21 +1,24 @@
 // SPDX-License-Identifier: MIT
 
 use anyhow::Context;
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
-    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u64, parse_u8},
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
 use super::{
-    super::AddressFamily, lwtunnel::VecRouteLwTunnelEncap,
-    metrics::VecRouteMetric, mpls::VecMplsLabel, MplsLabel, RouteAddress,
-    RouteCacheInfo, RouteCacheInfoBuffer, RouteLwEnCapType, RouteLwTunnelEncap,
-    RouteMetric, RouteMfcStats, RouteMfcStatsBuffer, RouteMplsTtlPropagation,
-    RouteNextHop, RouteNextHopBuffer, RoutePreference, RouteRealm, RouteType,
-    RouteVia, RouteViaBuffer,
+    super::{AddressFamily, IpProtocol},
+    lwtunnel::VecRouteLwTunnelEncap,
+    metrics::VecRouteMetric,
+    mpls::VecMplsLabel,
+    MplsLabel, RouteAddress, RouteCacheInfo, RouteCacheInfoBuffer,
+    RouteLwEnCapType, RouteLwTunnelEncap, RouteMetric, RouteMfcStats,
+    RouteMfcStatsBuffer, RouteMplsTtlPropagation, RouteNextHop,
+    RouteNextHopBuffer, RoutePreference, RouteRealm, RouteType, RouteVia,
+    RouteViaBuffer,
 };
 
 const RTA_DST: u16 = 1;
@@ -43,10 +46,10 @@ const RTA_ENCAP: u16 = 22;
 const RTA_EXPIRES: u16 = 23;
 const RTA_UID: u16 = 25;
 const RTA_TTL_PROPAGATE: u16 = 26;
+const RTA_IP_PROTO: u16 = 27;
+const RTA_SPORT: u16 = 28;
+const RTA_DPORT: u16 = 29;
 // TODO
-// const RTA_IP_PROTO:u16 = 27;
-// const RTA_SPORT:u16 = 28;
-// const RTA_DPORT:u16 = 29;
 // const RTA_NH_ID:u16 = 30;
 
 /// Netlink attributes for `RTM_NEWROUTE`, `RTM_DELROUTE`,
@@ -84,6 +87,15 @@ pub enum RouteAttribute {
     Realm(RouteRealm),
     Table(u32),
     Mark(u32),
+    /// IP protocol of the flow to lookup route for, only valid in
+    /// `RTM_GETROUTE`
+    IpProtocol(IpProtocol),
+    /// Source port of the flow to lookup route for, only valid in
+    /// `RTM_GETROUTE`
+    SourcePort(u16),
+    /// Destination port of the flow to lookup route for, only valid in
+    /// `RTM_GETROUTE`
+    DestinationPort(u16),
     Other(DefaultNla),
 }
 
@@ -104,7 +116,8 @@ impl Nla for RouteAttribute {
             Self::MultiPath(next_hops) => {
                 next_hops.iter().map(|nh| nh.buffer_len()).sum()
             }
-            Self::Preference(_) => 1,
+            Self::Preference(_) | Self::IpProtocol(_) => 1,
+            Self::SourcePort(_) | Self::DestinationPort(_) => 2,
             Self::EncapType(v) => v.buffer_len(),
             Self::Realm(v) => v.buffer_len(),
             Self::Uid(_)
@@ -131,6 +144,10 @@ impl Nla for RouteAttribute {
             Self::Encap(nlas) => nlas.as_slice().emit(buffer),
             Self::TtlPropagate(v) => buffer[0] = u8::from(*v),
             Self::Preference(p) => buffer[0] = (*p).into(),
+            Self::IpProtocol(v) => buffer[0] = i32::from(*v) as u8,
+            Self::SourcePort(v) | Self::DestinationPort(v) => {
+                BigEndian::write_u16(buffer, *v)
+            }
             Self::CacheInfo(cache_info) => cache_info.emit(buffer),
             Self::MfcStats(stats) => stats.emit(buffer),
             Self::Metrics(metrics) => metrics.as_slice().emit(buffer),
@@ -183,6 +200,9 @@ impl Nla for RouteAttribute {
             Self::MulticastExpires(_) => RTA_EXPIRES,
             Self::Uid(_) => RTA_UID,
             Self::TtlPropagate(_) => RTA_TTL_PROPAGATE,
+            Self::IpProtocol(_) => RTA_IP_PROTO,
+            Self::SourcePort(_) => RTA_SPORT,
+            Self::DestinationPort(_) => RTA_DPORT,
             Self::Other(ref attr) => attr.kind(),
         }
     }
@@ -280,6 +300,15 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
             RTA_MARK => Self::Mark(
                 parse_u32(payload).context("invalid RTA_MARK value")?,
             ),
+            RTA_IP_PROTO => Self::IpProtocol(IpProtocol::from(
+                parse_u8(payload).context("invalid RTA_IP_PROTO value")? as i32,
+            )),
+            RTA_SPORT => Self::SourcePort(
+                parse_u16_be(payload).context("invalid RTA_SPORT value")?,
+            ),
+            RTA_DPORT => Self::DestinationPort(
+                parse_u16_be(payload).context("invalid RTA_DPORT value")?,
+            ),
 
             RTA_CACHEINFO => Self::CacheInfo(
                 RouteCacheInfo::parse(
@@ -0,0 +1,323 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    route::{
+        RouteAttribute, RouteHeader, RouteMessage, RouteMetric,
+        RouteNextHopConfig, RoutePreference, RouteProtocol, RouteRealm,
+        RouteScope, RouteType, RouteVia,
+    },
+    AddressFamily, IpPrefix,
+};
+
+/// Configuration of a IPv4 or IPv6 route used for generating `RouteMessage`
+/// of `RTM_NEWROUTE`, similar to `ip route add`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RouteConfig {
+    pub destination: IpAddr,
+    pub prefix_length: u8,
+    pub kind: RouteType,
+    pub gateway: Option<IpAddr>,
+    /// Gateway in different address family than the route, e.g. IPv6
+    /// gateway of IPv4 route
+    pub via: Option<RouteVia>,
+    pub multipath: Vec<RouteNextHopConfig>,
+    /// Interface index of outgoing interface
+    pub oif: Option<u32>,
+    /// Preferred source address
+    pub source: Option<IpAddr>,
+    /// The `metric` of `ip route` command
+    pub priority: Option<u32>,
+    /// Routing table ID, default to main table or local table for `Local`
+    /// and `Broadcast` routes
+    pub table: Option<u32>,
+    pub protocol: RouteProtocol,
+    /// Override the scope chosen by `RouteConfig::build()`
+    pub scope: Option<RouteScope>,
+    /// IPv4 only
+    pub realm: Option<RouteRealm>,
+    pub mark: Option<u32>,
+    /// IPv6 only
+    pub preference: Option<RoutePreference>,
+    /// Route lifetime in seconds, IPv6 only
+    pub expires: Option<u32>,
+    pub metrics: Vec<RouteMetric>,
+}
+
+impl RouteConfig {
+    /// Unicast route to specified subnet, use prefix length 0 for default
+    /// route.
+    pub fn new(destination: IpAddr, prefix_length: u8) -> Self {
+        Self {
+            destination,
+            prefix_length,
+            kind: RouteType::Unicast,
+            gateway: None,
+            via: None,
+            multipath: Vec::new(),
+            oif: None,
+            source: None,
+            priority: None,
+            table: None,
+            protocol: RouteProtocol::Boot,
+            scope: None,
+            realm: None,
+            mark: None,
+            preference: None,
+            expires: None,
+            metrics: Vec::new(),
+        }
+    }
+
+    pub fn kind(mut self, kind: RouteType) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn blackhole(self) -> Self {
+        self.kind(RouteType::BlackHole)
+    }
+
+    pub fn unreachable(self) -> Self {
+        self.kind(RouteType::Unreachable)
+    }
+
+    pub fn prohibit(self) -> Self {
+        self.kind(RouteType::Prohibit)
+    }
+
+    pub fn local(self) -> Self {
+        self.kind(RouteType::Local)
+    }
+
+    pub fn broadcast(self) -> Self {
+        self.kind(RouteType::Broadcast)
+    }
+
+    pub fn throw(self) -> Self {
+        self.kind(RouteType::Throw)
+    }
+
+    pub fn multicast(self) -> Self {
+        self.kind(RouteType::Multicast)
+    }
+
+    pub fn gateway(mut self, gateway: IpAddr) -> Self {
+        self.gateway = Some(gateway);
+        self
+    }
+
+    pub fn via(mut self, via: RouteVia) -> Self {
+        self.via = Some(via);
+        self
+    }
+
+    pub fn multipath(mut self, next_hops: Vec<RouteNextHopConfig>) -> Self {
+        self.multipath = next_hops;
+        self
+    }
+
+    pub fn oif(mut self, index: u32) -> Self {
+        self.oif = Some(index);
+        self
+    }
+
+    pub fn source(mut self, source: IpAddr) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    pub fn table(mut self, table: u32) -> Self {
+        self.table = Some(table);
+        self
+    }
+
+    pub fn protocol(mut self, protocol: RouteProtocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    pub fn scope(mut self, scope: RouteScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    pub fn realm(mut self, realm: RouteRealm) -> Self {
+        self.realm = Some(realm);
+        self
+    }
+
+    pub fn mark(mut self, mark: u32) -> Self {
+        self.mark = Some(mark);
+        self
+    }
+
+    pub fn preference(mut self, preference: RoutePreference) -> Self {
+        self.preference = Some(preference);
+        self
+    }
+
+    pub fn expires(mut self, seconds: u32) -> Self {
+        self.expires = Some(seconds);
+        self
+    }
+
+    pub fn metrics(mut self, metrics: Vec<RouteMetric>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    fn validate(&self) -> Result<(), DecodeError> {
+        let destination = self.destination;
+        IpPrefix::new(destination, self.prefix_length)?;
+        for (name, addr) in [("gateway", self.gateway), ("source", self.source)]
+        {
+            if let Some(addr) = addr {
+                if addr.is_ipv4() != destination.is_ipv4() {
+                    return Err(format!(
+                        "The {name} {addr} is not in the same address \
+                        family as destination {destination}"
+                    )
+                    .into());
+                }
+            }
+        }
+        if self.via.is_some() && destination.is_ipv6() {
+            return Err("IPv6 route does not support via".into());
+        }
+        let next_hop_count = usize::from(self.gateway.is_some())
+            + usize::from(self.via.is_some())
+            + usize::from(!self.multipath.is_empty());
+        if next_hop_count > 1 {
+            return Err("Only one of gateway, via and multipath is allowed \
+                for a route"
+                .into());
+        }
+        if matches!(
+            self.kind,
+            RouteType::BlackHole
+                | RouteType::Unreachable
+                | RouteType::Prohibit
+                | RouteType::Throw
+        ) && (next_hop_count > 0 || self.oif.is_some())
+        {
+            return Err(format!(
+                "Gateway, interface and multipath can not be specified for \
+                {:?} route",
+                self.kind
+            )
+            .into());
+        }
+        if destination.is_ipv6() && self.realm.is_some() {
+            return Err("IPv6 route does not support realm".into());
+        }
+        if destination.is_ipv4() {
+            if self.preference.is_some() {
+                return Err("IPv4 route does not support preference".into());
+            }
+            if self.expires.is_some() {
+                return Err("IPv4 route does not support expires".into());
+            }
+        }
+        Ok(())
+    }
+
+    // Same as iproute2: host scope for local route, link scope for
+    // broadcast/multicast route and unicast route without gateway.
+    fn default_scope(&self) -> RouteScope {
+        match self.kind {
+            RouteType::Local | RouteType::Nat => RouteScope::Host,
+            RouteType::Broadcast
+            | RouteType::Multicast
+            | RouteType::Anycast => RouteScope::Link,
+            RouteType::Unicast | RouteType::Unspec
+                if self.gateway.is_none()
+                    && self.via.is_none()
+                    && self.multipath.is_empty() =>
+            {
+                RouteScope::Link
+            }
+            _ => RouteScope::Universe,
+        }
+    }
+
+    /// Validate and convert into `RouteMessage`. Table ID bigger than 255
+    /// is stored in `RouteAttribute::Table` with `RouteHeader::table` set to
+    /// `RouteHeader::RT_TABLE_UNSPEC`.
+    pub fn build(&self) -> Result<RouteMessage, DecodeError> {
+        self.validate()?;
+        let address_family = AddressFamily::from(&self.destination);
+        let mut message = RouteMessage::default();
+        let header = &mut message.header;
+        header.address_family = address_family;
+        header.destination_prefix_length = self.prefix_length;
+        header.protocol = self.protocol;
+        header.scope = self.scope.unwrap_or_else(|| self.default_scope());
+        header.kind = self.kind;
+        let table = self.table.unwrap_or(match self.kind {
+            RouteType::Local
+            | RouteType::Broadcast
+            | RouteType::Nat
+            | RouteType::Anycast => RouteHeader::RT_TABLE_LOCAL.into(),
+            _ => RouteHeader::RT_TABLE_MAIN.into(),
+        });
+        if let Ok(table) = u8::try_from(table) {
+            header.table = table;
+        } else {
+            header.table = RouteHeader::RT_TABLE_UNSPEC;
+            message.attributes.push(RouteAttribute::Table(table));
+        }
+
+        let attributes = &mut message.attributes;
+        if self.prefix_length != 0 {
+            attributes
+                .push(RouteAttribute::Destination(self.destination.into()));
+        }
+        if let Some(gateway) = self.gateway {
+            attributes.push(RouteAttribute::Gateway(gateway.into()));
+        }
+        if let Some(via) = self.via.as_ref() {
+            attributes.push(RouteAttribute::Via(via.clone()));
+        }
+        if let Some(source) = self.source {
+            attributes.push(RouteAttribute::PrefSource(source.into()));
+        }
+        if let Some(oif) = self.oif {
+            attributes.push(RouteAttribute::Oif(oif));
+        }
+        if let Some(priority) = self.priority {
+            attributes.push(RouteAttribute::Priority(priority));
+        }
+        if let Some(realm) = self.realm {
+            attributes.push(RouteAttribute::Realm(realm));
+        }
+        if let Some(mark) = self.mark {
+            attributes.push(RouteAttribute::Mark(mark));
+        }
+        if let Some(preference) = self.preference {
+            attributes.push(RouteAttribute::Preference(preference));
+        }
+        if let Some(expires) = self.expires {
+            attributes.push(RouteAttribute::Expires(expires));
+        }
+        if !self.metrics.is_empty() {
+            attributes.push(RouteAttribute::Metrics(self.metrics.clone()));
+        }
+        if !self.multipath.is_empty() {
+            attributes.push(RouteNextHopConfig::multipath(
+                address_family,
+                &self.multipath,
+            )?);
+        }
+        Ok(message)
+    }
+}
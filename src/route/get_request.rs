@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    ip::max_prefix_length,
+    route::{RouteAttribute, RouteFlag, RouteHeader, RouteMessage},
+    AddressFamily, IpProtocol,
+};
+
+const IPPROTO_ICMPV6: i32 = 58;
+
+/// Query of `RTM_GETROUTE` for looking up the route of a flow, equivalent
+/// to `ip route get`. The routing policy rules with IP protocol, port, mark
+/// or UID selectors are taken into account by kernel.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RouteGetRequest {
+    pub destination: IpAddr,
+    pub source: Option<IpAddr>,
+    pub ip_protocol: Option<IpProtocol>,
+    pub source_port: Option<u16>,
+    pub destination_port: Option<u16>,
+    pub mark: Option<u32>,
+    pub uid: Option<u32>,
+    /// Interface index of incoming interface, lookup as received packet
+    pub iif: Option<u32>,
+    /// Interface index of outgoing interface
+    pub oif: Option<u32>,
+    /// Reply with the matched FIB entry instead of the resolved route
+    pub fib_match: bool,
+    /// Reply with the ID of the table where route was found
+    pub lookup_table: bool,
+}
+
+impl RouteGetRequest {
+    pub fn new(destination: IpAddr) -> Self {
+        Self {
+            destination,
+            source: None,
+            ip_protocol: None,
+            source_port: None,
+            destination_port: None,
+            mark: None,
+            uid: None,
+            iif: None,
+            oif: None,
+            fib_match: false,
+            lookup_table: false,
+        }
+    }
+
+    pub fn source(mut self, source: IpAddr) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Kernel only supports TCP, UDP and ICMP for IPv4 or ICMPv6 for IPv6
+    /// here, [RouteGetRequest::build()] will error out on others.
+    pub fn ip_protocol(mut self, ip_protocol: IpProtocol) -> Self {
+        self.ip_protocol = Some(ip_protocol);
+        self
+    }
+
+    pub fn source_port(mut self, port: u16) -> Self {
+        self.source_port = Some(port);
+        self
+    }
+
+    pub fn destination_port(mut self, port: u16) -> Self {
+        self.destination_port = Some(port);
+        self
+    }
+
+    pub fn mark(mut self, mark: u32) -> Self {
+        self.mark = Some(mark);
+        self
+    }
+
+    pub fn uid(mut self, uid: u32) -> Self {
+        self.uid = Some(uid);
+        self
+    }
+
+    pub fn iif(mut self, iif: u32) -> Self {
+        self.iif = Some(iif);
+        self
+    }
+
+    pub fn oif(mut self, oif: u32) -> Self {
+        self.oif = Some(oif);
+        self
+    }
+
+    pub fn fib_match(mut self, enabled: bool) -> Self {
+        self.fib_match = enabled;
+        self
+    }
+
+    pub fn lookup_table(mut self, enabled: bool) -> Self {
+        self.lookup_table = enabled;
+        self
+    }
+
+    /// Validate and convert into `RouteMessage`. The header only contains
+    /// address family, prefix lengths and flags as required by kernel
+    /// strict checking of `RTM_GETROUTE`.
+    pub fn build(&self) -> Result<RouteMessage, DecodeError> {
+        let address_family = AddressFamily::from(&self.destination);
+        let prefix_length = max_prefix_length(&self.destination);
+        let mut message = RouteMessage::default();
+        message.header.address_family = address_family;
+        message.header.table = RouteHeader::RT_TABLE_UNSPEC;
+        message.header.destination_prefix_length = prefix_length;
+        if self.fib_match {
+            message.header.flags.push(RouteFlag::FibMatch);
+        }
+        if self.lookup_table {
+            message.header.flags.push(RouteFlag::LookupTable);
+        }
+        if let Some(source) = self.source {
+            if source.is_ipv4() != self.destination.is_ipv4() {
+                return Err(format!(
+                    "Source address {source} is not in the same address \
+                    family as destination {}",
+                    self.destination
+                )
+                .into());
+            }
+            message.header.source_prefix_length = prefix_length;
+            message
+                .attributes
+                .push(RouteAttribute::Source(source.into()));
+        }
+        message
+            .attributes
+            .push(RouteAttribute::Destination(self.destination.into()));
+        if let Some(iif) = self.iif {
+            message.attributes.push(RouteAttribute::Iif(iif));
+        }
+        if let Some(oif) = self.oif {
+            message.attributes.push(RouteAttribute::Oif(oif));
+        }
+        if let Some(mark) = self.mark {
+            message.attributes.push(RouteAttribute::Mark(mark));
+        }
+        if let Some(uid) = self.uid {
+            message.attributes.push(RouteAttribute::Uid(uid));
+        }
+        if let Some(ip_protocol) = self.ip_protocol {
+            let supported = match ip_protocol {
+                IpProtocol::Tcp | IpProtocol::Udp => true,
+                IpProtocol::Icmp => self.destination.is_ipv4(),
+                IpProtocol::Other(IPPROTO_ICMPV6) => self.destination.is_ipv6(),
+                _ => false,
+            };
+            if !supported {
+                return Err(format!(
+                    "IP protocol {ip_protocol:?} is not supported by kernel \
+                    for route lookup of {}",
+                    self.destination
+                )
+                .into());
+            }
+            message
+                .attributes
+                .push(RouteAttribute::IpProtocol(ip_protocol));
+        }
+        if let Some(port) = self.source_port {
+            message.attributes.push(RouteAttribute::SourcePort(port));
+        }
+        if let Some(port) = self.destination_port {
+            message
+                .attributes
+                .push(RouteAttribute::DestinationPort(port));
+        }
+        Ok(message)
+    }
+}
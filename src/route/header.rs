@@ -57,7 +57,10 @@ pub struct RouteHeader {
 }
 
 impl RouteHeader {
+    /// Default table of multicast routes
+    pub const RT_TABLE_DEFAULT: u8 = 253;
     pub const RT_TABLE_MAIN: u8 = 254;
+    pub const RT_TABLE_LOCAL: u8 = 255;
     pub const RT_TABLE_UNSPEC: u8 = 0;
 }
 
@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u64, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+const ILA_ATTR_LOCATOR: u16 = 1;
+// const ILA_ATTR_PAD: u16 = 6;
+const ILA_ATTR_CSUM_MODE: u16 = 7;
+const ILA_ATTR_IDENT_TYPE: u16 = 8;
+const ILA_ATTR_HOOK_TYPE: u16 = 9;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_ILA`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteIla {
+    /// The 64 bits locator, e.g. `2001:db8:1:2` is `0x20010db800010002`
+    Locator(u64),
+    ChecksumMode(IlaChecksumMode),
+    IdentifierType(IlaIdentifierType),
+    HookType(IlaHookType),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteIla {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Locator(_) => 8,
+            Self::ChecksumMode(_)
+            | Self::IdentifierType(_)
+            | Self::HookType(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Locator(_) => ILA_ATTR_LOCATOR,
+            Self::ChecksumMode(_) => ILA_ATTR_CSUM_MODE,
+            Self::IdentifierType(_) => ILA_ATTR_IDENT_TYPE,
+            Self::HookType(_) => ILA_ATTR_HOOK_TYPE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Locator(v) => BigEndian::write_u64(buffer, *v),
+            Self::ChecksumMode(v) => buffer[0] = (*v).into(),
+            Self::IdentifierType(v) => buffer[0] = (*v).into(),
+            Self::HookType(v) => buffer[0] = (*v).into(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouteIla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ILA_ATTR_LOCATOR => Self::Locator(u64::from_be(
+                parse_u64(payload).context("invalid ILA_ATTR_LOCATOR value")?,
+            )),
+            ILA_ATTR_CSUM_MODE => Self::ChecksumMode(
+                parse_u8(payload)
+                    .context("invalid ILA_ATTR_CSUM_MODE value")?
+                    .into(),
+            ),
+            ILA_ATTR_IDENT_TYPE => Self::IdentifierType(
+                parse_u8(payload)
+                    .context("invalid ILA_ATTR_IDENT_TYPE value")?
+                    .into(),
+            ),
+            ILA_ATTR_HOOK_TYPE => Self::HookType(
+                parse_u8(payload)
+                    .context("invalid ILA_ATTR_HOOK_TYPE value")?
+                    .into(),
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const ILA_CSUM_ADJUST_TRANSPORT: u8 = 0;
+const ILA_CSUM_NEUTRAL_MAP: u8 = 1;
+const ILA_CSUM_NO_ACTION: u8 = 2;
+const ILA_CSUM_NEUTRAL_MAP_AUTO: u8 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum IlaChecksumMode {
+    AdjustTransport,
+    NeutralMap,
+    NoAction,
+    NeutralMapAuto,
+    Other(u8),
+}
+
+impl From<u8> for IlaChecksumMode {
+    fn from(d: u8) -> Self {
+        match d {
+            ILA_CSUM_ADJUST_TRANSPORT => Self::AdjustTransport,
+            ILA_CSUM_NEUTRAL_MAP => Self::NeutralMap,
+            ILA_CSUM_NO_ACTION => Self::NoAction,
+            ILA_CSUM_NEUTRAL_MAP_AUTO => Self::NeutralMapAuto,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<IlaChecksumMode> for u8 {
+    fn from(v: IlaChecksumMode) -> u8 {
+        match v {
+            IlaChecksumMode::AdjustTransport => ILA_CSUM_ADJUST_TRANSPORT,
+            IlaChecksumMode::NeutralMap => ILA_CSUM_NEUTRAL_MAP,
+            IlaChecksumMode::NoAction => ILA_CSUM_NO_ACTION,
+            IlaChecksumMode::NeutralMapAuto => ILA_CSUM_NEUTRAL_MAP_AUTO,
+            IlaChecksumMode::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for IlaChecksumMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AdjustTransport => write!(f, "adj-transport"),
+            Self::NeutralMap => write!(f, "neutral-map"),
+            Self::NoAction => write!(f, "no-action"),
+            Self::NeutralMapAuto => write!(f, "neutral-map-auto"),
+            Self::Other(d) => write!(f, "other({d})"),
+        }
+    }
+}
+
+const ILA_ATYPE_IID: u8 = 0;
+const ILA_ATYPE_LUID: u8 = 1;
+const ILA_ATYPE_VIRT_V4: u8 = 2;
+const ILA_ATYPE_VIRT_UNI_V6: u8 = 3;
+const ILA_ATYPE_VIRT_MULTI_V6: u8 = 4;
+const ILA_ATYPE_NONLOCAL_ADDR: u8 = 5;
+const ILA_ATYPE_USE_FORMAT: u8 = 32;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum IlaIdentifierType {
+    /// Interface identifier
+    Iid,
+    /// Locally unique identifier
+    Luid,
+    VirtualV4,
+    VirtualUnicastV6,
+    VirtualMulticastV6,
+    NonLocalAddress,
+    /// Get type from type field in identifier
+    UseFormat,
+    Other(u8),
+}
+
+impl From<u8> for IlaIdentifierType {
+    fn from(d: u8) -> Self {
+        match d {
+            ILA_ATYPE_IID => Self::Iid,
+            ILA_ATYPE_LUID => Self::Luid,
+            ILA_ATYPE_VIRT_V4 => Self::VirtualV4,
+            ILA_ATYPE_VIRT_UNI_V6 => Self::VirtualUnicastV6,
+            ILA_ATYPE_VIRT_MULTI_V6 => Self::VirtualMulticastV6,
+            ILA_ATYPE_NONLOCAL_ADDR => Self::NonLocalAddress,
+            ILA_ATYPE_USE_FORMAT => Self::UseFormat,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<IlaIdentifierType> for u8 {
+    fn from(v: IlaIdentifierType) -> u8 {
+        match v {
+            IlaIdentifierType::Iid => ILA_ATYPE_IID,
+            IlaIdentifierType::Luid => ILA_ATYPE_LUID,
+            IlaIdentifierType::VirtualV4 => ILA_ATYPE_VIRT_V4,
+            IlaIdentifierType::VirtualUnicastV6 => ILA_ATYPE_VIRT_UNI_V6,
+            IlaIdentifierType::VirtualMulticastV6 => ILA_ATYPE_VIRT_MULTI_V6,
+            IlaIdentifierType::NonLocalAddress => ILA_ATYPE_NONLOCAL_ADDR,
+            IlaIdentifierType::UseFormat => ILA_ATYPE_USE_FORMAT,
+            IlaIdentifierType::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for IlaIdentifierType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Iid => write!(f, "iid"),
+            Self::Luid => write!(f, "luid"),
+            Self::VirtualV4 => write!(f, "virt-v4"),
+            Self::VirtualUnicastV6 => write!(f, "virt-uni-v6"),
+            Self::VirtualMulticastV6 => write!(f, "virt-multi-v6"),
+            Self::NonLocalAddress => write!(f, "nonlocal-addr"),
+            Self::UseFormat => write!(f, "use-format"),
+            Self::Other(d) => write!(f, "other({d})"),
+        }
+    }
+}
+
+const ILA_HOOK_ROUTE_OUTPUT: u8 = 0;
+const ILA_HOOK_ROUTE_INPUT: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum IlaHookType {
+    RouteOutput,
+    RouteInput,
+    Other(u8),
+}
+
+impl From<u8> for IlaHookType {
+    fn from(d: u8) -> Self {
+        match d {
+            ILA_HOOK_ROUTE_OUTPUT => Self::RouteOutput,
+            ILA_HOOK_ROUTE_INPUT => Self::RouteInput,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<IlaHookType> for u8 {
+    fn from(v: IlaHookType) -> u8 {
+        match v {
+            IlaHookType::RouteOutput => ILA_HOOK_ROUTE_OUTPUT,
+            IlaHookType::RouteInput => ILA_HOOK_ROUTE_INPUT,
+            IlaHookType::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for IlaHookType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RouteOutput => write!(f, "output"),
+            Self::RouteInput => write!(f, "input"),
+            Self::Other(d) => write!(f, "other({d})"),
+        }
+    }
+}
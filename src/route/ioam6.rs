@@ -0,0 +1,373 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const IOAM6_IPTUNNEL_MODE: u16 = 1;
+const IOAM6_IPTUNNEL_DST: u16 = 2;
+const IOAM6_IPTUNNEL_TRACE: u16 = 3;
+const IOAM6_IPTUNNEL_FREQ_K: u16 = 4;
+const IOAM6_IPTUNNEL_FREQ_N: u16 = 5;
+const IOAM6_IPTUNNEL_SRC: u16 = 6;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_IOAM6`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteIoam6IpTunnel {
+    Mode(Ioam6Mode),
+    /// Tunnel destination for `Ioam6Mode::Encap` and `Ioam6Mode::Auto`
+    Destination(Ipv6Addr),
+    Trace(Ioam6Trace),
+    /// Insert IOAM to `FrequencyK` packets over every `FrequencyN` packets
+    FrequencyK(u32),
+    FrequencyN(u32),
+    /// Tunnel source for `Ioam6Mode::Encap` and `Ioam6Mode::Auto`
+    Source(Ipv6Addr),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteIoam6IpTunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Mode(_) => 1,
+            Self::Destination(_) | Self::Source(_) => IPV6_ADDR_LEN,
+            Self::Trace(v) => v.buffer_len(),
+            Self::FrequencyK(_) | Self::FrequencyN(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mode(_) => IOAM6_IPTUNNEL_MODE,
+            Self::Destination(_) => IOAM6_IPTUNNEL_DST,
+            Self::Trace(_) => IOAM6_IPTUNNEL_TRACE,
+            Self::FrequencyK(_) => IOAM6_IPTUNNEL_FREQ_K,
+            Self::FrequencyN(_) => IOAM6_IPTUNNEL_FREQ_N,
+            Self::Source(_) => IOAM6_IPTUNNEL_SRC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mode(v) => buffer[0] = (*v).into(),
+            Self::Destination(v) | Self::Source(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            Self::Trace(v) => v.emit(buffer),
+            Self::FrequencyK(v) | Self::FrequencyN(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteIoam6IpTunnel
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IOAM6_IPTUNNEL_MODE => Self::Mode(
+                parse_u8(payload)
+                    .context("invalid IOAM6_IPTUNNEL_MODE value")?
+                    .into(),
+            ),
+            IOAM6_IPTUNNEL_DST => Self::Destination(
+                parse_ipv6_addr(payload)
+                    .context("invalid IOAM6_IPTUNNEL_DST value")?,
+            ),
+            IOAM6_IPTUNNEL_TRACE => Self::Trace(
+                Ioam6Trace::parse(
+                    &Ioam6TraceBuffer::new_checked(payload)
+                        .context("invalid IOAM6_IPTUNNEL_TRACE value")?,
+                )
+                .context("invalid IOAM6_IPTUNNEL_TRACE value")?,
+            ),
+            IOAM6_IPTUNNEL_FREQ_K => Self::FrequencyK(
+                parse_u32(payload)
+                    .context("invalid IOAM6_IPTUNNEL_FREQ_K value")?,
+            ),
+            IOAM6_IPTUNNEL_FREQ_N => Self::FrequencyN(
+                parse_u32(payload)
+                    .context("invalid IOAM6_IPTUNNEL_FREQ_N value")?,
+            ),
+            IOAM6_IPTUNNEL_SRC => Self::Source(
+                parse_ipv6_addr(payload)
+                    .context("invalid IOAM6_IPTUNNEL_SRC value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const IOAM6_IPTUNNEL_MODE_INLINE: u8 = 1;
+const IOAM6_IPTUNNEL_MODE_ENCAP: u8 = 2;
+const IOAM6_IPTUNNEL_MODE_AUTO: u8 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Ioam6Mode {
+    /// Insert IOAM into the existing IPv6 header
+    Inline,
+    /// Encapsulate the packet in an outer IPv6 header with IOAM
+    Encap,
+    /// `Inline` for local packets, `Encap` for in-transit packets
+    Auto,
+    Other(u8),
+}
+
+impl From<u8> for Ioam6Mode {
+    fn from(d: u8) -> Self {
+        match d {
+            IOAM6_IPTUNNEL_MODE_INLINE => Self::Inline,
+            IOAM6_IPTUNNEL_MODE_ENCAP => Self::Encap,
+            IOAM6_IPTUNNEL_MODE_AUTO => Self::Auto,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Ioam6Mode> for u8 {
+    fn from(v: Ioam6Mode) -> u8 {
+        match v {
+            Ioam6Mode::Inline => IOAM6_IPTUNNEL_MODE_INLINE,
+            Ioam6Mode::Encap => IOAM6_IPTUNNEL_MODE_ENCAP,
+            Ioam6Mode::Auto => IOAM6_IPTUNNEL_MODE_AUTO,
+            Ioam6Mode::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for Ioam6Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Inline => write!(f, "inline"),
+            Self::Encap => write!(f, "encap"),
+            Self::Auto => write!(f, "auto"),
+            Self::Other(d) => write!(f, "other({d})"),
+        }
+    }
+}
+
+const IOAM6_TRACE_LEN: usize = 8;
+const IOAM6_NODE_LEN_SHIFT: u8 = 3;
+const IOAM6_OVERFLOW_MASK: u8 = 1 << 2;
+const IOAM6_REMAINING_LEN_MASK: u8 = 0x7f;
+
+buffer!(Ioam6TraceBuffer(IOAM6_TRACE_LEN) {
+    namespace_id: (slice, 0..2),
+    node_len_overflow: (u8, 2),
+    remaining_len: (u8, 3),
+    trace_type: (slice, 4..8),
+});
+
+/// IOAM Pre-allocated Trace Option Header defined in RFC 9197, the
+/// `struct ioam6_trace_hdr` of kernel. The trace data is not included.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Ioam6Trace {
+    pub namespace_id: u16,
+    /// Length of data added by each node in 4-octet units, computed by
+    /// kernel from `trace_type`
+    pub node_len: u8,
+    pub overflow: bool,
+    /// Pre-allocated space for trace data in 4-octet units
+    pub remaining_len: u8,
+    pub trace_type: Vec<Ioam6TraceType>,
+}
+
+impl Ioam6Trace {
+    pub fn new(
+        namespace_id: u16,
+        trace_type: &[Ioam6TraceType],
+        remaining_len: u8,
+    ) -> Self {
+        Self {
+            namespace_id,
+            trace_type: trace_type.to_vec(),
+            remaining_len,
+            ..Default::default()
+        }
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<Ioam6TraceBuffer<&T>> for Ioam6Trace {
+    fn parse(buf: &Ioam6TraceBuffer<&T>) -> Result<Self, DecodeError> {
+        let namespace_id = buf.namespace_id();
+        let trace_type = buf.trace_type();
+        Ok(Self {
+            namespace_id: u16::from_be_bytes([
+                namespace_id[0],
+                namespace_id[1],
+            ]),
+            node_len: buf.node_len_overflow() >> IOAM6_NODE_LEN_SHIFT,
+            overflow: buf.node_len_overflow() & IOAM6_OVERFLOW_MASK > 0,
+            remaining_len: buf.remaining_len() & IOAM6_REMAINING_LEN_MASK,
+            trace_type: VecIoam6TraceType::from(u32::from_be_bytes([
+                trace_type[0],
+                trace_type[1],
+                trace_type[2],
+                trace_type[3],
+            ]))
+            .0,
+        })
+    }
+}
+
+impl Emitable for Ioam6Trace {
+    fn buffer_len(&self) -> usize {
+        IOAM6_TRACE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = Ioam6TraceBuffer::new(buffer);
+        buffer
+            .namespace_id_mut()
+            .copy_from_slice(&self.namespace_id.to_be_bytes());
+        buffer.set_node_len_overflow(
+            (self.node_len << IOAM6_NODE_LEN_SHIFT)
+                | if self.overflow {
+                    IOAM6_OVERFLOW_MASK
+                } else {
+                    0
+                },
+        );
+        buffer.set_remaining_len(self.remaining_len & IOAM6_REMAINING_LEN_MASK);
+        buffer.trace_type_mut().copy_from_slice(
+            &u32::from(&VecIoam6TraceType(self.trace_type.clone()))
+                .to_be_bytes(),
+        );
+    }
+}
+
+// The bit 0 of IOAM trace type is the most significant bit
+const IOAM6_TRACE_TYPE_BIT0: u32 = 1 << 31;
+const IOAM6_TRACE_TYPE_BIT1: u32 = 1 << 30;
+const IOAM6_TRACE_TYPE_BIT2: u32 = 1 << 29;
+const IOAM6_TRACE_TYPE_BIT3: u32 = 1 << 28;
+const IOAM6_TRACE_TYPE_BIT4: u32 = 1 << 27;
+const IOAM6_TRACE_TYPE_BIT5: u32 = 1 << 26;
+const IOAM6_TRACE_TYPE_BIT6: u32 = 1 << 25;
+const IOAM6_TRACE_TYPE_BIT7: u32 = 1 << 24;
+const IOAM6_TRACE_TYPE_BIT8: u32 = 1 << 23;
+const IOAM6_TRACE_TYPE_BIT9: u32 = 1 << 22;
+const IOAM6_TRACE_TYPE_BIT10: u32 = 1 << 21;
+const IOAM6_TRACE_TYPE_BIT11: u32 = 1 << 20;
+const IOAM6_TRACE_TYPE_BIT22: u32 = 1 << 9;
+
+/// Data fields to be recorded by each node, defined in RFC 9197
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Ioam6TraceType {
+    /// Bit 0: Hop_Lim and node_id in short format
+    HopLimitNodeId,
+    /// Bit 1: ingress_if_id and egress_if_id in short format
+    InterfaceIds,
+    /// Bit 2: timestamp seconds
+    TimestampSeconds,
+    /// Bit 3: timestamp fraction
+    TimestampFraction,
+    /// Bit 4: transit delay
+    TransitDelay,
+    /// Bit 5: IOAM-Namespace specific data in short format
+    NamespaceData,
+    /// Bit 6: queue depth
+    QueueDepth,
+    /// Bit 7: checksum complement
+    ChecksumComplement,
+    /// Bit 8: Hop_Lim and node_id in wide format
+    HopLimitNodeIdWide,
+    /// Bit 9: ingress_if_id and egress_if_id in wide format
+    InterfaceIdsWide,
+    /// Bit 10: IOAM-Namespace specific data in wide format
+    NamespaceDataWide,
+    /// Bit 11: buffer occupancy
+    BufferOccupancy,
+    /// Bit 22: variable-length Opaque State Snapshot
+    OpaqueStateSnapshot,
+    Other(u32),
+}
+
+impl From<Ioam6TraceType> for u32 {
+    fn from(v: Ioam6TraceType) -> u32 {
+        match v {
+            Ioam6TraceType::HopLimitNodeId => IOAM6_TRACE_TYPE_BIT0,
+            Ioam6TraceType::InterfaceIds => IOAM6_TRACE_TYPE_BIT1,
+            Ioam6TraceType::TimestampSeconds => IOAM6_TRACE_TYPE_BIT2,
+            Ioam6TraceType::TimestampFraction => IOAM6_TRACE_TYPE_BIT3,
+            Ioam6TraceType::TransitDelay => IOAM6_TRACE_TYPE_BIT4,
+            Ioam6TraceType::NamespaceData => IOAM6_TRACE_TYPE_BIT5,
+            Ioam6TraceType::QueueDepth => IOAM6_TRACE_TYPE_BIT6,
+            Ioam6TraceType::ChecksumComplement => IOAM6_TRACE_TYPE_BIT7,
+            Ioam6TraceType::HopLimitNodeIdWide => IOAM6_TRACE_TYPE_BIT8,
+            Ioam6TraceType::InterfaceIdsWide => IOAM6_TRACE_TYPE_BIT9,
+            Ioam6TraceType::NamespaceDataWide => IOAM6_TRACE_TYPE_BIT10,
+            Ioam6TraceType::BufferOccupancy => IOAM6_TRACE_TYPE_BIT11,
+            Ioam6TraceType::OpaqueStateSnapshot => IOAM6_TRACE_TYPE_BIT22,
+            Ioam6TraceType::Other(d) => d,
+        }
+    }
+}
+
+const ALL_IOAM6_TRACE_TYPES: [Ioam6TraceType; 13] = [
+    Ioam6TraceType::HopLimitNodeId,
+    Ioam6TraceType::InterfaceIds,
+    Ioam6TraceType::TimestampSeconds,
+    Ioam6TraceType::TimestampFraction,
+    Ioam6TraceType::TransitDelay,
+    Ioam6TraceType::NamespaceData,
+    Ioam6TraceType::QueueDepth,
+    Ioam6TraceType::ChecksumComplement,
+    Ioam6TraceType::HopLimitNodeIdWide,
+    Ioam6TraceType::InterfaceIdsWide,
+    Ioam6TraceType::NamespaceDataWide,
+    Ioam6TraceType::BufferOccupancy,
+    Ioam6TraceType::OpaqueStateSnapshot,
+];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecIoam6TraceType(Vec<Ioam6TraceType>);
+
+impl From<u32> for VecIoam6TraceType {
+    fn from(d: u32) -> Self {
+        let mut got: u32 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_IOAM6_TRACE_TYPES {
+            if (d & (u32::from(flag))) > 0 {
+                ret.push(flag);
+                got += u32::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(Ioam6TraceType::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecIoam6TraceType> for u32 {
+    fn from(v: &VecIoam6TraceType) -> u32 {
+        let mut d: u32 = 0;
+        for flag in &v.0 {
+            d |= u32::from(*flag);
+        }
+        d
+    }
+}
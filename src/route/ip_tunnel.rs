@@ -0,0 +1,523 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16_be, parse_u32, parse_u32_be, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{emit_ip_addr, ip_addr_len, parse_ip_addr};
+
+const LWTUNNEL_IP_ID: u16 = 1;
+const LWTUNNEL_IP_DST: u16 = 2;
+const LWTUNNEL_IP_SRC: u16 = 3;
+const LWTUNNEL_IP_TTL: u16 = 4;
+const LWTUNNEL_IP_TOS: u16 = 5;
+const LWTUNNEL_IP_FLAGS: u16 = 6;
+// const LWTUNNEL_IP_PAD: u16 = 7;
+const LWTUNNEL_IP_OPTS: u16 = 8;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_IP` or `LWTUNNEL_ENCAP_IP6`, used for steering traffic
+/// into tunnel devices in `collect_metadata` mode.
+/// The `LWTUNNEL_IP6_*` attributes share the same value as
+/// `LWTUNNEL_IP_*`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteIpTunnel {
+    /// Tunnel ID, e.g. VNI of VXLAN
+    Id(u64),
+    Destination(IpAddr),
+    Source(IpAddr),
+    /// TTL of IPv4 or hop limit of IPv6
+    Ttl(u8),
+    /// TOS of IPv4 or traffic class of IPv6
+    Tos(u8),
+    Flags(Vec<RouteIpTunnelFlag>),
+    Options(Vec<RouteIpTunnelOption>),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteIpTunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) => 8,
+            Self::Destination(v) | Self::Source(v) => ip_addr_len(v),
+            Self::Ttl(_) | Self::Tos(_) => 1,
+            Self::Flags(_) => 2,
+            Self::Options(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => LWTUNNEL_IP_ID,
+            Self::Destination(_) => LWTUNNEL_IP_DST,
+            Self::Source(_) => LWTUNNEL_IP_SRC,
+            Self::Ttl(_) => LWTUNNEL_IP_TTL,
+            Self::Tos(_) => LWTUNNEL_IP_TOS,
+            Self::Flags(_) => LWTUNNEL_IP_FLAGS,
+            Self::Options(_) => LWTUNNEL_IP_OPTS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(v) => BigEndian::write_u64(buffer, *v),
+            Self::Destination(v) | Self::Source(v) => emit_ip_addr(v, buffer),
+            Self::Ttl(v) | Self::Tos(v) => buffer[0] = *v,
+            Self::Flags(v) => BigEndian::write_u16(
+                buffer,
+                u16::from(&VecRouteIpTunnelFlag(v.to_vec())),
+            ),
+            Self::Options(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteIpTunnel
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP_ID => Self::Id(u64::from_be(
+                parse_u64(payload).context("invalid LWTUNNEL_IP_ID value")?,
+            )),
+            LWTUNNEL_IP_DST => Self::Destination(
+                parse_ip_addr(payload)
+                    .context("invalid LWTUNNEL_IP_DST value")?,
+            ),
+            LWTUNNEL_IP_SRC => Self::Source(
+                parse_ip_addr(payload)
+                    .context("invalid LWTUNNEL_IP_SRC value")?,
+            ),
+            LWTUNNEL_IP_TTL => Self::Ttl(
+                parse_u8(payload).context("invalid LWTUNNEL_IP_TTL value")?,
+            ),
+            LWTUNNEL_IP_TOS => Self::Tos(
+                parse_u8(payload).context("invalid LWTUNNEL_IP_TOS value")?,
+            ),
+            LWTUNNEL_IP_FLAGS => Self::Flags(
+                VecRouteIpTunnelFlag::from(
+                    parse_u16_be(payload)
+                        .context("invalid LWTUNNEL_IP_FLAGS value")?,
+                )
+                .0,
+            ),
+            LWTUNNEL_IP_OPTS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context("invalid LWTUNNEL_IP_OPTS value")?;
+                    nlas.push(RouteIpTunnelOption::parse(nla)?);
+                }
+                Self::Options(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const TUNNEL_CSUM: u16 = 0x01;
+const TUNNEL_ROUTING: u16 = 0x02;
+const TUNNEL_KEY: u16 = 0x04;
+const TUNNEL_SEQ: u16 = 0x08;
+const TUNNEL_STRICT: u16 = 0x10;
+const TUNNEL_REC: u16 = 0x20;
+const TUNNEL_VERSION: u16 = 0x40;
+const TUNNEL_NO_KEY: u16 = 0x80;
+const TUNNEL_DONT_FRAGMENT: u16 = 0x0100;
+const TUNNEL_OAM: u16 = 0x0200;
+const TUNNEL_CRIT_OPT: u16 = 0x0400;
+const TUNNEL_GENEVE_OPT: u16 = 0x0800;
+const TUNNEL_VXLAN_OPT: u16 = 0x1000;
+const TUNNEL_NOCACHE: u16 = 0x2000;
+const TUNNEL_ERSPAN_OPT: u16 = 0x4000;
+const TUNNEL_GTP_OPT: u16 = 0x8000;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum RouteIpTunnelFlag {
+    Csum,
+    Routing,
+    Key,
+    Seq,
+    Strict,
+    Rec,
+    Version,
+    NoKey,
+    DontFragment,
+    Oam,
+    CritOpt,
+    GeneveOpt,
+    VxlanOpt,
+    NoCache,
+    ErspanOpt,
+    GtpOpt,
+    Other(u16),
+}
+
+impl From<RouteIpTunnelFlag> for u16 {
+    fn from(v: RouteIpTunnelFlag) -> u16 {
+        match v {
+            RouteIpTunnelFlag::Csum => TUNNEL_CSUM,
+            RouteIpTunnelFlag::Routing => TUNNEL_ROUTING,
+            RouteIpTunnelFlag::Key => TUNNEL_KEY,
+            RouteIpTunnelFlag::Seq => TUNNEL_SEQ,
+            RouteIpTunnelFlag::Strict => TUNNEL_STRICT,
+            RouteIpTunnelFlag::Rec => TUNNEL_REC,
+            RouteIpTunnelFlag::Version => TUNNEL_VERSION,
+            RouteIpTunnelFlag::NoKey => TUNNEL_NO_KEY,
+            RouteIpTunnelFlag::DontFragment => TUNNEL_DONT_FRAGMENT,
+            RouteIpTunnelFlag::Oam => TUNNEL_OAM,
+            RouteIpTunnelFlag::CritOpt => TUNNEL_CRIT_OPT,
+            RouteIpTunnelFlag::GeneveOpt => TUNNEL_GENEVE_OPT,
+            RouteIpTunnelFlag::VxlanOpt => TUNNEL_VXLAN_OPT,
+            RouteIpTunnelFlag::NoCache => TUNNEL_NOCACHE,
+            RouteIpTunnelFlag::ErspanOpt => TUNNEL_ERSPAN_OPT,
+            RouteIpTunnelFlag::GtpOpt => TUNNEL_GTP_OPT,
+            RouteIpTunnelFlag::Other(d) => d,
+        }
+    }
+}
+
+const ALL_IP_TUNNEL_FLAGS: [RouteIpTunnelFlag; 16] = [
+    RouteIpTunnelFlag::Csum,
+    RouteIpTunnelFlag::Routing,
+    RouteIpTunnelFlag::Key,
+    RouteIpTunnelFlag::Seq,
+    RouteIpTunnelFlag::Strict,
+    RouteIpTunnelFlag::Rec,
+    RouteIpTunnelFlag::Version,
+    RouteIpTunnelFlag::NoKey,
+    RouteIpTunnelFlag::DontFragment,
+    RouteIpTunnelFlag::Oam,
+    RouteIpTunnelFlag::CritOpt,
+    RouteIpTunnelFlag::GeneveOpt,
+    RouteIpTunnelFlag::VxlanOpt,
+    RouteIpTunnelFlag::NoCache,
+    RouteIpTunnelFlag::ErspanOpt,
+    RouteIpTunnelFlag::GtpOpt,
+];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecRouteIpTunnelFlag(Vec<RouteIpTunnelFlag>);
+
+impl From<u16> for VecRouteIpTunnelFlag {
+    fn from(d: u16) -> Self {
+        let mut got: u16 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_IP_TUNNEL_FLAGS {
+            if (d & (u16::from(flag))) > 0 {
+                ret.push(flag);
+                got += u16::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(RouteIpTunnelFlag::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecRouteIpTunnelFlag> for u16 {
+    fn from(v: &VecRouteIpTunnelFlag) -> u16 {
+        let mut d: u16 = 0;
+        for flag in &v.0 {
+            d |= u16::from(*flag);
+        }
+        d
+    }
+}
+
+const LWTUNNEL_IP_OPTS_GENEVE: u16 = 1;
+const LWTUNNEL_IP_OPTS_VXLAN: u16 = 2;
+const LWTUNNEL_IP_OPTS_ERSPAN: u16 = 3;
+
+/// Tunnel options of `LWTUNNEL_IP_OPTS`. Multiple `Geneve` options are
+/// allowed, while `Vxlan` and `Erspan` can only be used alone.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteIpTunnelOption {
+    Geneve(Vec<RouteIpTunnelGeneveOption>),
+    Vxlan(Vec<RouteIpTunnelVxlanOption>),
+    Erspan(Vec<RouteIpTunnelErspanOption>),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteIpTunnelOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Geneve(v) => v.as_slice().buffer_len(),
+            Self::Vxlan(v) => v.as_slice().buffer_len(),
+            Self::Erspan(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Geneve(_) => LWTUNNEL_IP_OPTS_GENEVE,
+            Self::Vxlan(_) => LWTUNNEL_IP_OPTS_VXLAN,
+            Self::Erspan(_) => LWTUNNEL_IP_OPTS_ERSPAN,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Geneve(v) => v.as_slice().emit(buffer),
+            Self::Vxlan(v) => v.as_slice().emit(buffer),
+            Self::Erspan(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteIpTunnelOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP_OPTS_GENEVE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        &nla.context("invalid LWTUNNEL_IP_OPTS_GENEVE value")?;
+                    nlas.push(RouteIpTunnelGeneveOption::parse(nla)?);
+                }
+                Self::Geneve(nlas)
+            }
+            LWTUNNEL_IP_OPTS_VXLAN => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        &nla.context("invalid LWTUNNEL_IP_OPTS_VXLAN value")?;
+                    nlas.push(RouteIpTunnelVxlanOption::parse(nla)?);
+                }
+                Self::Vxlan(nlas)
+            }
+            LWTUNNEL_IP_OPTS_ERSPAN => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        &nla.context("invalid LWTUNNEL_IP_OPTS_ERSPAN value")?;
+                    nlas.push(RouteIpTunnelErspanOption::parse(nla)?);
+                }
+                Self::Erspan(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const LWTUNNEL_IP_OPT_GENEVE_CLASS: u16 = 1;
+const LWTUNNEL_IP_OPT_GENEVE_TYPE: u16 = 2;
+const LWTUNNEL_IP_OPT_GENEVE_DATA: u16 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteIpTunnelGeneveOption {
+    Class(u16),
+    Type(u8),
+    /// Option data, kernel requires it to be multiple of 4 bytes and no
+    /// more than 124 bytes
+    Data(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteIpTunnelGeneveOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Class(_) => 2,
+            Self::Type(_) => 1,
+            Self::Data(v) => v.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Class(_) => LWTUNNEL_IP_OPT_GENEVE_CLASS,
+            Self::Type(_) => LWTUNNEL_IP_OPT_GENEVE_TYPE,
+            Self::Data(_) => LWTUNNEL_IP_OPT_GENEVE_DATA,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Class(v) => BigEndian::write_u16(buffer, *v),
+            Self::Type(v) => buffer[0] = *v,
+            Self::Data(v) => buffer.copy_from_slice(v.as_slice()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteIpTunnelGeneveOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP_OPT_GENEVE_CLASS => Self::Class(
+                parse_u16_be(payload)
+                    .context("invalid LWTUNNEL_IP_OPT_GENEVE_CLASS value")?,
+            ),
+            LWTUNNEL_IP_OPT_GENEVE_TYPE => Self::Type(
+                parse_u8(payload)
+                    .context("invalid LWTUNNEL_IP_OPT_GENEVE_TYPE value")?,
+            ),
+            LWTUNNEL_IP_OPT_GENEVE_DATA => Self::Data(payload.to_vec()),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const LWTUNNEL_IP_OPT_VXLAN_GBP: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteIpTunnelVxlanOption {
+    /// Group Based Policy
+    Gbp(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteIpTunnelVxlanOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Gbp(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Gbp(_) => LWTUNNEL_IP_OPT_VXLAN_GBP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Gbp(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteIpTunnelVxlanOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP_OPT_VXLAN_GBP => Self::Gbp(
+                parse_u32(payload)
+                    .context("invalid LWTUNNEL_IP_OPT_VXLAN_GBP value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const LWTUNNEL_IP_OPT_ERSPAN_VER: u16 = 1;
+const LWTUNNEL_IP_OPT_ERSPAN_INDEX: u16 = 2;
+const LWTUNNEL_IP_OPT_ERSPAN_DIR: u16 = 3;
+const LWTUNNEL_IP_OPT_ERSPAN_HWID: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteIpTunnelErspanOption {
+    Version(u8),
+    /// Only for ERSPAN version 1
+    Index(u32),
+    /// Only for ERSPAN version 2
+    Direction(u8),
+    /// Only for ERSPAN version 2
+    HardwareId(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteIpTunnelErspanOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Version(_) | Self::Direction(_) | Self::HardwareId(_) => 1,
+            Self::Index(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Version(_) => LWTUNNEL_IP_OPT_ERSPAN_VER,
+            Self::Index(_) => LWTUNNEL_IP_OPT_ERSPAN_INDEX,
+            Self::Direction(_) => LWTUNNEL_IP_OPT_ERSPAN_DIR,
+            Self::HardwareId(_) => LWTUNNEL_IP_OPT_ERSPAN_HWID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Version(v) | Self::Direction(v) | Self::HardwareId(v) => {
+                buffer[0] = *v
+            }
+            Self::Index(v) => BigEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteIpTunnelErspanOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP_OPT_ERSPAN_VER => Self::Version(
+                parse_u8(payload)
+                    .context("invalid LWTUNNEL_IP_OPT_ERSPAN_VER value")?,
+            ),
+            LWTUNNEL_IP_OPT_ERSPAN_INDEX => Self::Index(
+                parse_u32_be(payload)
+                    .context("invalid LWTUNNEL_IP_OPT_ERSPAN_INDEX value")?,
+            ),
+            LWTUNNEL_IP_OPT_ERSPAN_DIR => Self::Direction(
+                parse_u8(payload)
+                    .context("invalid LWTUNNEL_IP_OPT_ERSPAN_DIR value")?,
+            ),
+            LWTUNNEL_IP_OPT_ERSPAN_HWID => Self::HardwareId(
+                parse_u8(payload)
+                    .context("invalid LWTUNNEL_IP_OPT_ERSPAN_HWID value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
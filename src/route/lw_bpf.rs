@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const LWT_BPF_IN: u16 = 1;
+const LWT_BPF_OUT: u16 = 2;
+const LWT_BPF_XMIT: u16 = 3;
+const LWT_BPF_XMIT_HEADROOM: u16 = 4;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_BPF`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteLwBpf {
+    /// Program for ingress path
+    In(Vec<RouteLwBpfProgram>),
+    /// Program for egress path
+    Out(Vec<RouteLwBpfProgram>),
+    /// Program for transmit path, allowed to modify packet header
+    Xmit(Vec<RouteLwBpfProgram>),
+    /// Headroom reserved for `Xmit` program to push header, maximum 256
+    XmitHeadroom(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteLwBpf {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::In(v) | Self::Out(v) | Self::Xmit(v) => {
+                v.as_slice().buffer_len()
+            }
+            Self::XmitHeadroom(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::In(_) => LWT_BPF_IN,
+            Self::Out(_) => LWT_BPF_OUT,
+            Self::Xmit(_) => LWT_BPF_XMIT,
+            Self::XmitHeadroom(_) => LWT_BPF_XMIT_HEADROOM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::In(v) | Self::Out(v) | Self::Xmit(v) => {
+                v.as_slice().emit(buffer)
+            }
+            Self::XmitHeadroom(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouteLwBpf {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_IN => Self::In(
+                parse_programs(payload).context("invalid LWT_BPF_IN value")?,
+            ),
+            LWT_BPF_OUT => Self::Out(
+                parse_programs(payload).context("invalid LWT_BPF_OUT value")?,
+            ),
+            LWT_BPF_XMIT => Self::Xmit(
+                parse_programs(payload)
+                    .context("invalid LWT_BPF_XMIT value")?,
+            ),
+            LWT_BPF_XMIT_HEADROOM => Self::XmitHeadroom(
+                parse_u32(payload)
+                    .context("invalid LWT_BPF_XMIT_HEADROOM value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+fn parse_programs(
+    payload: &[u8],
+) -> Result<Vec<RouteLwBpfProgram>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        nlas.push(RouteLwBpfProgram::parse(&nla?)?);
+    }
+    Ok(nlas)
+}
+
+const LWT_BPF_PROG_FD: u16 = 1;
+const LWT_BPF_PROG_NAME: u16 = 2;
+
+/// Kernel requires both `Fd` and `Name` when creating route, but only
+/// reports `Name` when dumping.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteLwBpfProgram {
+    /// File descriptor of BPF program
+    Fd(u32),
+    Name(String),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteLwBpfProgram {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Fd(_) => 4,
+            Self::Name(v) => v.len() + 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Fd(_) => LWT_BPF_PROG_FD,
+            Self::Name(_) => LWT_BPF_PROG_NAME,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Fd(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Name(v) => {
+                buffer[..v.len()].copy_from_slice(v.as_bytes());
+                buffer[v.len()] = 0;
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteLwBpfProgram
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_PROG_FD => Self::Fd(
+                parse_u32(payload).context("invalid LWT_BPF_PROG_FD value")?,
+            ),
+            LWT_BPF_PROG_NAME => Self::Name(
+                parse_string(payload)
+                    .context("invalid LWT_BPF_PROG_NAME value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+const LWT_XFRM_IF_ID: u16 = 1;
+const LWT_XFRM_LINK: u16 = 2;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_XFRM`, used for routing into XFRM interface in
+/// `collect_metadata` mode.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteLwXfrm {
+    /// XFRM interface ID, should not be 0
+    IfId(u32),
+    /// Interface index of underlying device
+    Link(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteLwXfrm {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IfId(_) | Self::Link(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::IfId(_) => LWT_XFRM_IF_ID,
+            Self::Link(_) => LWT_XFRM_LINK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::IfId(v) | Self::Link(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouteLwXfrm {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_XFRM_IF_ID => Self::IfId(
+                parse_u32(payload).context("invalid LWT_XFRM_IF_ID value")?,
+            ),
+            LWT_XFRM_LINK => Self::Link(
+                parse_u32(payload).context("invalid LWT_XFRM_LINK value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
@@ -7,7 +7,11 @@ use netlink_packet_utils::{
     DecodeError,
 };
 
-use super::RouteMplsIpTunnel;
+use super::{
+    RouteIla, RouteIoam6IpTunnel, RouteIpTunnel, RouteLwBpf, RouteLwXfrm,
+    RouteMplsIpTunnel, RouteRplIpTunnel, RouteSeg6IpTunnel,
+    RouteSeg6LocalIpTunnel,
+};
 
 const LWTUNNEL_ENCAP_NONE: u16 = 0;
 const LWTUNNEL_ENCAP_MPLS: u16 = 1;
@@ -110,6 +114,15 @@ impl std::fmt::Display for RouteLwEnCapType {
 #[non_exhaustive]
 pub enum RouteLwTunnelEncap {
     Mpls(RouteMplsIpTunnel),
+    Ip(RouteIpTunnel),
+    Ip6(RouteIpTunnel),
+    Bpf(RouteLwBpf),
+    Seg6(RouteSeg6IpTunnel),
+    Seg6Local(RouteSeg6LocalIpTunnel),
+    Ila(RouteIla),
+    Rpl(RouteRplIpTunnel),
+    Ioam6(RouteIoam6IpTunnel),
+    Xfrm(RouteLwXfrm),
     Other(DefaultNla),
 }
 
@@ -117,6 +130,14 @@ impl Nla for RouteLwTunnelEncap {
     fn value_len(&self) -> usize {
         match self {
             Self::Mpls(v) => v.value_len(),
+            Self::Ip(v) | Self::Ip6(v) => v.value_len(),
+            Self::Bpf(v) => v.value_len(),
+            Self::Seg6(v) => v.value_len(),
+            Self::Seg6Local(v) => v.value_len(),
+            Self::Ila(v) => v.value_len(),
+            Self::Rpl(v) => v.value_len(),
+            Self::Ioam6(v) => v.value_len(),
+            Self::Xfrm(v) => v.value_len(),
             Self::Other(v) => v.value_len(),
         }
     }
@@ -124,6 +145,14 @@ impl Nla for RouteLwTunnelEncap {
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
             Self::Mpls(v) => v.emit_value(buffer),
+            Self::Ip(v) | Self::Ip6(v) => v.emit_value(buffer),
+            Self::Bpf(v) => v.emit_value(buffer),
+            Self::Seg6(v) => v.emit_value(buffer),
+            Self::Seg6Local(v) => v.emit_value(buffer),
+            Self::Ila(v) => v.emit_value(buffer),
+            Self::Rpl(v) => v.emit_value(buffer),
+            Self::Ioam6(v) => v.emit_value(buffer),
+            Self::Xfrm(v) => v.emit_value(buffer),
             Self::Other(v) => v.emit_value(buffer),
         }
     }
@@ -131,6 +160,14 @@ impl Nla for RouteLwTunnelEncap {
     fn kind(&self) -> u16 {
         match self {
             Self::Mpls(v) => v.kind(),
+            Self::Ip(v) | Self::Ip6(v) => v.kind(),
+            Self::Bpf(v) => v.kind(),
+            Self::Seg6(v) => v.kind(),
+            Self::Seg6Local(v) => v.kind(),
+            Self::Ila(v) => v.kind(),
+            Self::Rpl(v) => v.kind(),
+            Self::Ioam6(v) => v.kind(),
+            Self::Xfrm(v) => v.kind(),
             Self::Other(v) => v.kind(),
         }
     }
@@ -149,6 +186,21 @@ where
             RouteLwEnCapType::Mpls => {
                 Self::Mpls(RouteMplsIpTunnel::parse(buf)?)
             }
+            RouteLwEnCapType::Ip => Self::Ip(RouteIpTunnel::parse(buf)?),
+            RouteLwEnCapType::Ip6 => Self::Ip6(RouteIpTunnel::parse(buf)?),
+            RouteLwEnCapType::Bpf => Self::Bpf(RouteLwBpf::parse(buf)?),
+            RouteLwEnCapType::Seg6 => {
+                Self::Seg6(RouteSeg6IpTunnel::parse(buf)?)
+            }
+            RouteLwEnCapType::Seg6Local => {
+                Self::Seg6Local(RouteSeg6LocalIpTunnel::parse(buf)?)
+            }
+            RouteLwEnCapType::Ila => Self::Ila(RouteIla::parse(buf)?),
+            RouteLwEnCapType::Rpl => Self::Rpl(RouteRplIpTunnel::parse(buf)?),
+            RouteLwEnCapType::Ioam6 => {
+                Self::Ioam6(RouteIoam6IpTunnel::parse(buf)?)
+            }
+            RouteLwEnCapType::Xfrm => Self::Xfrm(RouteLwXfrm::parse(buf)?),
             _ => Self::Other(DefaultNla::parse(buf)?),
         })
     }
@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::IpAddr;
+
 use anyhow::Context;
 use netlink_packet_utils::{
     traits::{Emitable, Parseable, ParseableParametrized},
@@ -7,8 +9,10 @@ use netlink_packet_utils::{
 };
 
 use super::{
-    super::AddressFamily, attribute::RTA_ENCAP_TYPE, RouteAttribute,
-    RouteHeader, RouteLwEnCapType, RouteMessageBuffer, RouteType,
+    super::{AddressFamily, IpPrefix},
+    attribute::RTA_ENCAP_TYPE,
+    RouteAddress, RouteAttribute, RouteHeader, RouteLwEnCapType,
+    RouteMessageBuffer, RouteType,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
@@ -18,6 +22,190 @@ pub struct RouteMessage {
     pub attributes: Vec<RouteAttribute>,
 }
 
+impl RouteMessage {
+    /// Destination prefix from `RouteHeader::destination_prefix_length`
+    /// and `RouteAttribute::Destination`, default route if no destination
+    /// attribute. Return `None` for non-IP route.
+    pub fn destination_prefix(&self) -> Option<IpPrefix> {
+        let address = self.attributes.iter().find_map(|attr| {
+            if let RouteAttribute::Destination(v) = attr {
+                route_address_to_ip(v)
+            } else {
+                None
+            }
+        });
+        match (address, self.header.address_family) {
+            (Some(address), _) => {
+                IpPrefix::new(address, self.header.destination_prefix_length)
+                    .ok()
+            }
+            (None, AddressFamily::Inet) => Some(IpPrefix::default_route(false)),
+            (None, AddressFamily::Inet6) => Some(IpPrefix::default_route(true)),
+            _ => None,
+        }
+    }
+
+    /// Set address family, destination prefix length and
+    /// `RouteAttribute::Destination`. The destination attribute is removed
+    /// for default route. Return error if `RouteAttribute::Source` is in
+    /// different address family.
+    pub fn set_destination_prefix(
+        &mut self,
+        prefix: IpPrefix,
+    ) -> Result<(), DecodeError> {
+        self.check_prefix_family(&prefix, true)?;
+        self.header.address_family = prefix.address_family();
+        self.header.destination_prefix_length = prefix.prefix_length();
+        self.attributes
+            .retain(|attr| !matches!(attr, RouteAttribute::Destination(_)));
+        if prefix.prefix_length() != 0 {
+            self.attributes
+                .push(RouteAttribute::Destination(prefix.address().into()));
+        }
+        Ok(())
+    }
+
+    /// Source prefix from `RouteHeader::source_prefix_length` and
+    /// `RouteAttribute::Source`.
+    pub fn source_prefix(&self) -> Option<IpPrefix> {
+        self.attributes.iter().find_map(|attr| {
+            if let RouteAttribute::Source(v) = attr {
+                IpPrefix::new(
+                    route_address_to_ip(v)?,
+                    self.header.source_prefix_length,
+                )
+                .ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Set address family, source prefix length and `RouteAttribute::Source`.
+    /// Use `None` to remove source prefix. Return error if
+    /// `RouteAttribute::Destination` is in different address family.
+    pub fn set_source_prefix(
+        &mut self,
+        prefix: Option<IpPrefix>,
+    ) -> Result<(), DecodeError> {
+        if let Some(prefix) = prefix.as_ref() {
+            self.check_prefix_family(prefix, false)?;
+        }
+        self.attributes
+            .retain(|attr| !matches!(attr, RouteAttribute::Source(_)));
+        if let Some(prefix) = prefix {
+            self.header.address_family = prefix.address_family();
+            self.header.source_prefix_length = prefix.prefix_length();
+            self.attributes
+                .push(RouteAttribute::Source(prefix.address().into()));
+        } else {
+            self.header.source_prefix_length = 0;
+        }
+        Ok(())
+    }
+
+    // Check the address family against the other prefix attribute
+    fn check_prefix_family(
+        &self,
+        prefix: &IpPrefix,
+        is_destination: bool,
+    ) -> Result<(), DecodeError> {
+        let other = self.attributes.iter().find_map(|attr| match attr {
+            RouteAttribute::Source(v) if is_destination => {
+                route_address_to_ip(v)
+            }
+            RouteAttribute::Destination(v) if !is_destination => {
+                route_address_to_ip(v)
+            }
+            _ => None,
+        });
+        if let Some(other) = other {
+            if AddressFamily::from(&other) != prefix.address_family() {
+                return Err(format!(
+                    "Prefix {prefix} is not in the same address family as \
+                    {} {other}",
+                    if is_destination {
+                        "source"
+                    } else {
+                        "destination"
+                    }
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Routing table ID from `RouteAttribute::Table`, fallback to
+    /// `RouteHeader::table`.
+    pub fn table(&self) -> u32 {
+        self.attributes
+            .iter()
+            .find_map(|attr| {
+                if let RouteAttribute::Table(v) = attr {
+                    Some(*v)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(self.header.table.into())
+    }
+
+    /// Multicast forwarding cache entry of `AddressFamily::Ipmr` or
+    /// `AddressFamily::Ip6mr` route. Return `None` for other address
+    /// families or when origin or group address is missing.
+    #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
+    pub fn mfc_entry(&self) -> Option<super::RouteMfcEntry> {
+        use super::{RouteFlag, RouteMfcEntry, RouteMfcOif};
+
+        if !matches!(
+            self.header.address_family,
+            AddressFamily::Ipmr | AddressFamily::Ip6mr
+        ) {
+            return None;
+        }
+        let mut origin = None;
+        let mut group = None;
+        let mut iif = None;
+        let mut oifs = Vec::new();
+        let mut stats = None;
+        let mut last_use = None;
+        for attr in self.attributes.iter() {
+            match attr {
+                RouteAttribute::Source(v) => origin = route_address_to_ip(v),
+                RouteAttribute::Destination(v) => {
+                    group = route_address_to_ip(v)
+                }
+                RouteAttribute::Iif(v) => iif = Some(*v),
+                RouteAttribute::MultiPath(nhs) => {
+                    oifs = nhs.iter().map(RouteMfcOif::from).collect()
+                }
+                RouteAttribute::MfcStats(v) => stats = Some(*v),
+                RouteAttribute::MulticastExpires(v) => last_use = Some(*v),
+                _ => (),
+            }
+        }
+        Some(RouteMfcEntry {
+            origin: origin?,
+            group: group?,
+            table: self.table(),
+            iif,
+            oifs,
+            unresolved: self.header.flags.contains(&RouteFlag::Unresolved),
+            stats,
+            last_use,
+        })
+    }
+}
+
+fn route_address_to_ip(address: &RouteAddress) -> Option<IpAddr> {
+    match address {
+        RouteAddress::Inet(v) => Some((*v).into()),
+        RouteAddress::Inet6(v) => Some((*v).into()),
+        _ => None,
+    }
+}
+
 impl Emitable for RouteMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.attributes.as_slice().buffer_len()
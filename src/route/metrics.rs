@@ -6,7 +6,7 @@ use std::mem::size_of;
 
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
-    parsers::parse_u32,
+    parsers::{parse_string, parse_u32},
     traits::Parseable,
     DecodeError,
 };
@@ -32,7 +32,11 @@ const RTAX_FASTOPEN_NO_COOKIE: u16 = 17;
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum RouteMetric {
-    Lock(u32),
+    /// Metrics locked to be not changed by kernel, e.g. path MTU discovery.
+    /// The kernel stores this as a `1 << RTAX_*` bitmask, so
+    /// [RouteMetricKind::Other] above 31 cannot be represented and is
+    /// dropped when emitting. Use [RouteMetric::lock()] to reject them.
+    Lock(Vec<RouteMetricKind>),
     Mtu(u32),
     Window(u32),
     Rtt(u32),
@@ -43,20 +47,40 @@ pub enum RouteMetric {
     Reordering(u32),
     Hoplimit(u32),
     InitCwnd(u32),
-    Features(u32),
+    Features(Vec<RouteMetricFeature>),
     RtoMin(u32),
     InitRwnd(u32),
     QuickAck(u32),
-    CcAlgo(u32),
+    /// Name of TCP congestion control algorithm, e.g. `bbr`, `cubic`
+    CcAlgo(String),
     FastopenNoCookie(u32),
     Other(DefaultNla),
 }
 
+impl RouteMetric {
+    /// Create [RouteMetric::Lock], error out if any kind cannot be
+    /// represented in the RTAX_LOCK bitmask.
+    pub fn lock(kinds: Vec<RouteMetricKind>) -> Result<Self, DecodeError> {
+        if let Some(kind) = kinds
+            .iter()
+            .find(|k| u32::from(u16::from(**k)) >= u32::BITS)
+        {
+            return Err(format!(
+                "Route metric kind {} cannot be locked, only kinds below \
+                {} fit in the RTAX_LOCK bitmask",
+                u16::from(*kind),
+                u32::BITS
+            )
+            .into());
+        }
+        Ok(Self::Lock(kinds))
+    }
+}
+
 impl Nla for RouteMetric {
     fn value_len(&self) -> usize {
         match self {
-            Self::Lock(_)
-            | Self::Mtu(_)
+            Self::Mtu(_)
             | Self::Window(_)
             | Self::Rtt(_)
             | Self::RttVar(_)
@@ -66,12 +90,13 @@ impl Nla for RouteMetric {
             | Self::Reordering(_)
             | Self::Hoplimit(_)
             | Self::InitCwnd(_)
-            | Self::Features(_)
             | Self::RtoMin(_)
             | Self::InitRwnd(_)
             | Self::QuickAck(_)
-            | Self::CcAlgo(_)
-            | Self::FastopenNoCookie(_) => size_of::<u32>(),
+            | Self::FastopenNoCookie(_)
+            | Self::Lock(_)
+            | Self::Features(_) => size_of::<u32>(),
+            Self::CcAlgo(v) => v.len() + 1,
             Self::Other(attr) => attr.value_len(),
         }
     }
@@ -79,8 +104,7 @@ impl Nla for RouteMetric {
     #[rustfmt::skip]
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
-            Self::Lock(value)
-                 | Self:: Mtu(value)
+            Self:: Mtu(value)
                  | Self:: Window(value)
                  | Self:: Rtt(value)
                  | Self:: RttVar(value)
@@ -90,13 +114,23 @@ impl Nla for RouteMetric {
                  | Self:: Reordering(value)
                  | Self:: Hoplimit(value)
                  | Self:: InitCwnd(value)
-                 | Self:: Features(value)
                  | Self:: RtoMin(value)
                  | Self:: InitRwnd(value)
                  | Self:: QuickAck(value)
-                 | Self:: CcAlgo(value)
                  | Self:: FastopenNoCookie(value)
                 => NativeEndian::write_u32(buffer, *value),
+            Self::Lock(v) => NativeEndian::write_u32(
+                buffer,
+                u32::from(&VecRouteMetricKind(v.clone())),
+            ),
+            Self::Features(v) => NativeEndian::write_u32(
+                buffer,
+                u32::from(&VecRouteMetricFeature(v.clone())),
+            ),
+            Self::CcAlgo(v) => {
+                buffer[..v.len()].copy_from_slice(v.as_bytes());
+                buffer[v.len()] = 0;
+            }
 
             Self::Other(attr) => attr.emit_value(buffer),
         }
@@ -131,7 +165,10 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouteMetric {
         let payload = buf.value();
         Ok(match buf.kind() {
             RTAX_LOCK => Self::Lock(
-                parse_u32(payload).context("invalid RTAX_LOCK value")?,
+                VecRouteMetricKind::from(
+                    parse_u32(payload).context("invalid RTAX_LOCK value")?,
+                )
+                .0,
             ),
             RTAX_MTU => {
                 Self::Mtu(parse_u32(payload).context("invalid RTAX_MTU value")?)
@@ -164,7 +201,11 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouteMetric {
                 parse_u32(payload).context("invalid RTAX_INITCWND value")?,
             ),
             RTAX_FEATURES => Self::Features(
-                parse_u32(payload).context("invalid RTAX_FEATURES value")?,
+                VecRouteMetricFeature::from(
+                    parse_u32(payload)
+                        .context("invalid RTAX_FEATURES value")?,
+                )
+                .0,
             ),
             RTAX_RTO_MIN => Self::RtoMin(
                 parse_u32(payload).context("invalid RTAX_RTO_MIN value")?,
@@ -176,7 +217,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouteMetric {
                 parse_u32(payload).context("invalid RTAX_QUICKACK value")?,
             ),
             RTAX_CC_ALGO => Self::CcAlgo(
-                parse_u32(payload).context("invalid RTAX_CC_ALGO value")?,
+                parse_string(payload).context("invalid RTAX_CC_ALGO value")?,
             ),
             RTAX_FASTOPEN_NO_COOKIE => Self::FastopenNoCookie(
                 parse_u32(payload)
@@ -202,3 +243,173 @@ impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for VecRouteMetric {
         Ok(Self(nlas))
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum RouteMetricKind {
+    Lock,
+    Mtu,
+    Window,
+    Rtt,
+    RttVar,
+    SsThresh,
+    Cwnd,
+    Advmss,
+    Reordering,
+    Hoplimit,
+    InitCwnd,
+    Features,
+    RtoMin,
+    InitRwnd,
+    QuickAck,
+    CcAlgo,
+    FastopenNoCookie,
+    Other(u16),
+}
+
+impl From<u16> for RouteMetricKind {
+    fn from(d: u16) -> Self {
+        match d {
+            RTAX_LOCK => Self::Lock,
+            RTAX_MTU => Self::Mtu,
+            RTAX_WINDOW => Self::Window,
+            RTAX_RTT => Self::Rtt,
+            RTAX_RTTVAR => Self::RttVar,
+            RTAX_SSTHRESH => Self::SsThresh,
+            RTAX_CWND => Self::Cwnd,
+            RTAX_ADVMSS => Self::Advmss,
+            RTAX_REORDERING => Self::Reordering,
+            RTAX_HOPLIMIT => Self::Hoplimit,
+            RTAX_INITCWND => Self::InitCwnd,
+            RTAX_FEATURES => Self::Features,
+            RTAX_RTO_MIN => Self::RtoMin,
+            RTAX_INITRWND => Self::InitRwnd,
+            RTAX_QUICKACK => Self::QuickAck,
+            RTAX_CC_ALGO => Self::CcAlgo,
+            RTAX_FASTOPEN_NO_COOKIE => Self::FastopenNoCookie,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<RouteMetricKind> for u16 {
+    fn from(v: RouteMetricKind) -> u16 {
+        match v {
+            RouteMetricKind::Lock => RTAX_LOCK,
+            RouteMetricKind::Mtu => RTAX_MTU,
+            RouteMetricKind::Window => RTAX_WINDOW,
+            RouteMetricKind::Rtt => RTAX_RTT,
+            RouteMetricKind::RttVar => RTAX_RTTVAR,
+            RouteMetricKind::SsThresh => RTAX_SSTHRESH,
+            RouteMetricKind::Cwnd => RTAX_CWND,
+            RouteMetricKind::Advmss => RTAX_ADVMSS,
+            RouteMetricKind::Reordering => RTAX_REORDERING,
+            RouteMetricKind::Hoplimit => RTAX_HOPLIMIT,
+            RouteMetricKind::InitCwnd => RTAX_INITCWND,
+            RouteMetricKind::Features => RTAX_FEATURES,
+            RouteMetricKind::RtoMin => RTAX_RTO_MIN,
+            RouteMetricKind::InitRwnd => RTAX_INITRWND,
+            RouteMetricKind::QuickAck => RTAX_QUICKACK,
+            RouteMetricKind::CcAlgo => RTAX_CC_ALGO,
+            RouteMetricKind::FastopenNoCookie => RTAX_FASTOPEN_NO_COOKIE,
+            RouteMetricKind::Other(d) => d,
+        }
+    }
+}
+
+// The RTAX_LOCK is a bitmask of `1 << RTAX_*`
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecRouteMetricKind(Vec<RouteMetricKind>);
+
+impl From<u32> for VecRouteMetricKind {
+    fn from(d: u32) -> Self {
+        let mut ret = Vec::new();
+        for i in 0..u32::BITS {
+            if d & (1 << i) > 0 {
+                ret.push(RouteMetricKind::from(i as u16));
+            }
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecRouteMetricKind> for u32 {
+    fn from(v: &VecRouteMetricKind) -> u32 {
+        let mut d: u32 = 0;
+        for kind in &v.0 {
+            d |= 1u32
+                .checked_shl(u16::from(*kind).into())
+                .unwrap_or_default();
+        }
+        d
+    }
+}
+
+const RTAX_FEATURE_ECN: u32 = 1 << 0;
+const RTAX_FEATURE_SACK: u32 = 1 << 1;
+const RTAX_FEATURE_TIMESTAMP: u32 = 1 << 2;
+const RTAX_FEATURE_ALLFRAG: u32 = 1 << 3;
+const RTAX_FEATURE_TCP_USEC_TS: u32 = 1 << 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum RouteMetricFeature {
+    Ecn,
+    Sack,
+    Timestamp,
+    Allfrag,
+    /// Use microsecond resolution for TCP timestamps
+    TcpUsecTs,
+    Other(u32),
+}
+
+impl From<RouteMetricFeature> for u32 {
+    fn from(v: RouteMetricFeature) -> u32 {
+        match v {
+            RouteMetricFeature::Ecn => RTAX_FEATURE_ECN,
+            RouteMetricFeature::Sack => RTAX_FEATURE_SACK,
+            RouteMetricFeature::Timestamp => RTAX_FEATURE_TIMESTAMP,
+            RouteMetricFeature::Allfrag => RTAX_FEATURE_ALLFRAG,
+            RouteMetricFeature::TcpUsecTs => RTAX_FEATURE_TCP_USEC_TS,
+            RouteMetricFeature::Other(d) => d,
+        }
+    }
+}
+
+const ALL_ROUTE_METRIC_FEATURES: [RouteMetricFeature; 5] = [
+    RouteMetricFeature::Ecn,
+    RouteMetricFeature::Sack,
+    RouteMetricFeature::Timestamp,
+    RouteMetricFeature::Allfrag,
+    RouteMetricFeature::TcpUsecTs,
+];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecRouteMetricFeature(Vec<RouteMetricFeature>);
+
+impl From<u32> for VecRouteMetricFeature {
+    fn from(d: u32) -> Self {
+        let mut got: u32 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_ROUTE_METRIC_FEATURES {
+            if (d & (u32::from(flag))) > 0 {
+                ret.push(flag);
+                got += u32::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(RouteMetricFeature::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecRouteMetricFeature> for u32 {
+    fn from(v: &VecRouteMetricFeature) -> u32 {
+        let mut d: u32 = 0;
+        for flag in &v.0 {
+            d |= u32::from(*flag);
+        }
+        d
+    }
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use crate::route::{RouteMfcStats, RouteNextHop};
+
+/// Outgoing interface of multicast forwarding cache entry, stored in
+/// `RouteNextHop` of `RouteAttribute::MultiPath`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct RouteMfcOif {
+    /// Interface index of the VIF device
+    pub interface_index: u32,
+    /// Packets with TTL bigger than this threshold are forwarded to this
+    /// interface, stored in `RouteNextHop::hops` by kernel.
+    pub ttl_threshold: u8,
+}
+
+impl RouteMfcOif {
+    pub fn new(interface_index: u32, ttl_threshold: u8) -> Self {
+        Self {
+            interface_index,
+            ttl_threshold,
+        }
+    }
+}
+
+impl From<&RouteNextHop> for RouteMfcOif {
+    fn from(nh: &RouteNextHop) -> Self {
+        Self {
+            interface_index: nh.interface_index,
+            ttl_threshold: nh.hops,
+        }
+    }
+}
+
+impl From<RouteMfcOif> for RouteNextHop {
+    fn from(v: RouteMfcOif) -> Self {
+        Self {
+            hops: v.ttl_threshold,
+            interface_index: v.interface_index,
+            ..Default::default()
+        }
+    }
+}
+
+/// Multicast forwarding cache entry of `AddressFamily::Ipmr` or
+/// `AddressFamily::Ip6mr` route, equivalent to a line of `ip mroute show`.
+/// Created by `RouteMessage::mfc_entry()`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RouteMfcEntry {
+    /// Origin(source) address, unspecified address for `(*, G)` entry
+    pub origin: IpAddr,
+    /// Multicast group address
+    pub group: IpAddr,
+    pub table: u32,
+    /// Interface index of the incoming VIF, `None` for unresolved entry
+    pub iif: Option<u32>,
+    pub oifs: Vec<RouteMfcOif>,
+    /// Not resolved by multicast routing daemon yet
+    pub unresolved: bool,
+    pub stats: Option<RouteMfcStats>,
+    /// Time since the entry was last used, in 1/100 seconds
+    pub last_use: Option<u64>,
+}
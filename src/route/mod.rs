@@ -3,16 +3,28 @@
 mod address;
 mod attribute;
 mod cache_info;
+mod config;
 pub(crate) mod flags;
+mod get_request;
 mod header;
+mod ila;
+mod ioam6;
+mod ip_tunnel;
+mod lw_bpf;
+mod lw_xfrm;
 mod lwtunnel;
 mod message;
 pub(crate) mod metrics;
+mod mfc;
 mod mfc_stats;
 mod mpls;
+mod multipath;
 pub(crate) mod next_hops;
 mod preference;
 mod realm;
+mod rpl;
+mod seg6;
+mod seg6_local;
 mod via;
 
 #[cfg(test)]
@@ -21,16 +33,43 @@ mod tests;
 pub use self::address::RouteAddress;
 pub use self::attribute::RouteAttribute;
 pub use self::cache_info::{RouteCacheInfo, RouteCacheInfoBuffer};
+pub use self::config::RouteConfig;
 pub use self::flags::RouteFlag;
+pub use self::get_request::RouteGetRequest;
 pub use self::header::{
     RouteHeader, RouteMessageBuffer, RouteProtocol, RouteScope, RouteType,
 };
+pub use self::ila::{
+    IlaChecksumMode, IlaHookType, IlaIdentifierType, RouteIla,
+};
+pub use self::ioam6::{
+    Ioam6Mode, Ioam6Trace, Ioam6TraceBuffer, Ioam6TraceType, RouteIoam6IpTunnel,
+};
+pub use self::ip_tunnel::{
+    RouteIpTunnel, RouteIpTunnelErspanOption, RouteIpTunnelFlag,
+    RouteIpTunnelGeneveOption, RouteIpTunnelOption, RouteIpTunnelVxlanOption,
+};
+pub use self::lw_bpf::{RouteLwBpf, RouteLwBpfProgram};
+pub use self::lw_xfrm::RouteLwXfrm;
 pub use self::lwtunnel::{RouteLwEnCapType, RouteLwTunnelEncap};
 pub use self::message::RouteMessage;
-pub use self::metrics::RouteMetric;
+pub use self::metrics::{RouteMetric, RouteMetricFeature, RouteMetricKind};
+pub use self::mfc::{RouteMfcEntry, RouteMfcOif};
 pub use self::mfc_stats::{RouteMfcStats, RouteMfcStatsBuffer};
-pub use self::mpls::{MplsLabel, RouteMplsIpTunnel, RouteMplsTtlPropagation};
+pub use self::mpls::{
+    MplsLabel, MplsLabelStack, RouteMplsIpTunnel, RouteMplsTtlPropagation,
+};
+pub use self::multipath::RouteNextHopConfig;
 pub use self::next_hops::{RouteNextHop, RouteNextHopBuffer, RouteNextHopFlag};
 pub use self::preference::RoutePreference;
 pub use self::realm::RouteRealm;
+pub use self::rpl::{RouteRplIpTunnel, RplHeader, RplHeaderBuffer};
+pub use self::seg6::{
+    RouteSeg6IpTunnel, Seg6Header, Seg6HeaderBuffer, Seg6HeaderFlag, Seg6Hmac,
+    Seg6IpTunnelEncap, Seg6IpTunnelEncapBuffer, Seg6Mode, Seg6Tlv,
+};
+pub use self::seg6_local::{
+    RouteSeg6LocalIpTunnel, Seg6LocalAction, Seg6LocalBpf, Seg6LocalCounter,
+    Seg6LocalFlavor, Seg6LocalFlavorOperation,
+};
 pub use self::via::{RouteVia, RouteViaBuffer};
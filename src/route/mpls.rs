@@ -171,6 +171,186 @@ impl Emitable for VecMplsLabel {
     }
 }
 
+const MPLS_LABEL_MAX: u32 = (1 << 20) - 1;
+// Labels 0-15 are reserved by RFC 3032 and RFC 7274
+const MPLS_LABEL_FIRST_UNRESERVED: u32 = 16;
+const MPLS_LABEL_IMPLNULL: u32 = 3;
+// Same as `MAX_NEW_LABELS` of kernel `net/mpls/af_mpls.c`
+const MPLS_LABEL_STACK_MAX_DEPTH: usize = 30;
+
+impl MplsLabel {
+    /// Whether label value is in reserved range 0-15.
+    pub fn is_reserved(&self) -> bool {
+        self.label < MPLS_LABEL_FIRST_UNRESERVED
+    }
+
+    /// Incoming label used in `RouteAddress::Mpls` of `AddressFamily::Mpls`
+    /// route, reserved labels are rejected by kernel.
+    pub fn route_destination(label: u32) -> Result<Self, DecodeError> {
+        if label > MPLS_LABEL_MAX {
+            return Err(format!(
+                "MPLS label {label} exceeds maximum {MPLS_LABEL_MAX}"
+            )
+            .into());
+        }
+        if label < MPLS_LABEL_FIRST_UNRESERVED {
+            return Err(format!(
+                "MPLS label {label} is reserved, route destination label \
+                should be {MPLS_LABEL_FIRST_UNRESERVED} or higher"
+            )
+            .into());
+        }
+        Ok(Self {
+            label,
+            traffic_class: 0,
+            bottom_of_stack: true,
+            ttl: 0,
+        })
+    }
+}
+
+/// MPLS label stack used in `RouteAttribute::NewDestination`,
+/// `RouteMplsIpTunnel::Destination` and `RouteNextHopConfig` of MPLS route.
+/// The first label is the outermost. Kernel requires the bottom of stack
+/// bit only set on last label and traffic class and TTL of every label
+/// to be 0, which is handled by `MplsLabelStack::to_labels()`.
+/// The TTL of pushed labels is controlled by `RouteMplsIpTunnel::Ttl`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MplsLabelStack(Vec<u32>);
+
+impl MplsLabelStack {
+    /// Validate the label values. Besides the 20 bits limitation, kernel
+    /// rejects empty stack, stack deeper than 30 labels and the implicit
+    /// NULL label(3) which should never appear in a packet.
+    pub fn new(labels: &[u32]) -> Result<Self, DecodeError> {
+        if labels.is_empty() {
+            return Err("MPLS label stack should not be empty".into());
+        }
+        if labels.len() > MPLS_LABEL_STACK_MAX_DEPTH {
+            return Err(format!(
+                "MPLS label stack depth {} exceeds maximum \
+                {MPLS_LABEL_STACK_MAX_DEPTH}",
+                labels.len()
+            )
+            .into());
+        }
+        for label in labels {
+            if *label > MPLS_LABEL_MAX {
+                return Err(format!(
+                    "MPLS label {label} exceeds maximum {MPLS_LABEL_MAX}"
+                )
+                .into());
+            }
+            if *label == MPLS_LABEL_IMPLNULL {
+                return Err(format!(
+                    "Implicit NULL MPLS label {MPLS_LABEL_IMPLNULL} cannot \
+                    be used in label stack"
+                )
+                .into());
+            }
+        }
+        Ok(Self(labels.to_vec()))
+    }
+
+    /// Validate label entries with the same rules as kernel, including
+    /// the bottom of stack bit, traffic class and TTL.
+    pub fn from_labels(labels: &[MplsLabel]) -> Result<Self, DecodeError> {
+        for (i, label) in labels.iter().enumerate() {
+            if label.bottom_of_stack != (i + 1 == labels.len()) {
+                return Err(format!(
+                    "MPLS label {} has invalid bottom of stack bit, should \
+                    only be set on last label",
+                    label.label
+                )
+                .into());
+            }
+            if label.traffic_class != 0 || label.ttl != 0 {
+                return Err(format!(
+                    "MPLS label {} should have traffic class and TTL set to 0",
+                    label.label
+                )
+                .into());
+            }
+        }
+        Self::new(
+            labels
+                .iter()
+                .map(|l| l.label)
+                .collect::<Vec<u32>>()
+                .as_slice(),
+        )
+    }
+
+    /// Label values from outermost to innermost
+    pub fn labels(&self) -> &[u32] {
+        self.0.as_slice()
+    }
+
+    pub fn to_labels(&self) -> Vec<MplsLabel> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(i, label)| MplsLabel {
+                label: *label,
+                traffic_class: 0,
+                bottom_of_stack: i + 1 == self.0.len(),
+                ttl: 0,
+            })
+            .collect()
+    }
+
+    /// Attributes of `RouteLwTunnelEncap::Mpls` pushing this label stack
+    pub fn encap(&self, ttl: Option<u8>) -> Vec<RouteMplsIpTunnel> {
+        let mut ret = vec![RouteMplsIpTunnel::Destination(self.to_labels())];
+        if let Some(ttl) = ttl {
+            ret.push(RouteMplsIpTunnel::Ttl(ttl));
+        }
+        ret
+    }
+}
+
+impl From<&MplsLabelStack> for Vec<MplsLabel> {
+    fn from(v: &MplsLabelStack) -> Self {
+        v.to_labels()
+    }
+}
+
+impl From<MplsLabelStack> for Vec<MplsLabel> {
+    fn from(v: MplsLabelStack) -> Self {
+        v.to_labels()
+    }
+}
+
+impl std::str::FromStr for MplsLabelStack {
+    type Err = DecodeError;
+
+    /// Parse labels separated by `/` like `100/200/300`, the same format
+    /// used by iproute2.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut labels = Vec::new();
+        for label in s.split('/') {
+            labels.push(u32::from_str(label.trim()).map_err(|e| {
+                DecodeError::from(format!(
+                    "Invalid MPLS label {label:?} in {s:?}: {e}"
+                ))
+            })?);
+        }
+        Self::new(labels.as_slice())
+    }
+}
+
+impl std::fmt::Display for MplsLabelStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, label) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, "/")?;
+            }
+            write!(f, "{label}")?;
+        }
+        Ok(())
+    }
+}
+
 const MPLS_TTL_PROP_DEFAULT: u8 = 0;
 const MPLS_TTL_PROP_ENABLED: u8 = 1;
 const MPLS_TTL_PROP_DISABLED: u8 = 2;
@@ -0,0 +1,228 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    route::{
+        MplsLabelStack, RouteAttribute, RouteLwEnCapType, RouteLwTunnelEncap,
+        RouteNextHop, RouteNextHopFlag, RouteVia,
+    },
+    AddressFamily,
+};
+
+const NEXT_HOP_WEIGHT_MAX: u16 = 256;
+
+/// Configuration of a next-hop in ECMP route used for generating
+/// `RouteAttribute::MultiPath`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RouteNextHopConfig {
+    pub gateway: Option<IpAddr>,
+    /// Gateway in different address family than the route, e.g. IPv6
+    /// gateway for IPv4 route or next-hop of MPLS route
+    pub via: Option<RouteVia>,
+    pub interface_index: u32,
+    /// Weight in range of 1 to 256, stored as `hops + 1` by kernel
+    pub weight: u16,
+    pub flags: Vec<RouteNextHopFlag>,
+    pub encap: Option<(RouteLwEnCapType, Vec<RouteLwTunnelEncap>)>,
+    /// Outgoing label stack replacing incoming label, MPLS route only
+    pub new_destination: Option<MplsLabelStack>,
+}
+
+impl Default for RouteNextHopConfig {
+    fn default() -> Self {
+        Self {
+            gateway: None,
+            via: None,
+            interface_index: 0,
+            weight: 1,
+            flags: Vec::new(),
+            encap: None,
+            new_destination: None,
+        }
+    }
+}
+
+impl RouteNextHopConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn gateway(mut self, gateway: IpAddr) -> Self {
+        self.gateway = Some(gateway);
+        self
+    }
+
+    pub fn via(mut self, via: RouteVia) -> Self {
+        self.via = Some(via);
+        self
+    }
+
+    pub fn interface(mut self, index: u32) -> Self {
+        self.interface_index = index;
+        self
+    }
+
+    pub fn weight(mut self, weight: u16) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn flag(mut self, flag: RouteNextHopFlag) -> Self {
+        if !self.flags.contains(&flag) {
+            self.flags.push(flag);
+        }
+        self
+    }
+
+    /// Treat gateway as directly connected even it is not in any subnet of
+    /// the interface.
+    pub fn onlink(self) -> Self {
+        self.flag(RouteNextHopFlag::Onlink)
+    }
+
+    pub fn encap(
+        mut self,
+        encap_type: RouteLwEnCapType,
+        encap: Vec<RouteLwTunnelEncap>,
+    ) -> Self {
+        self.encap = Some((encap_type, encap));
+        self
+    }
+
+    /// Push MPLS label stack to IP packet forwarded to this next-hop,
+    /// optionally with the TTL of the pushed labels.
+    pub fn mpls_encap(self, labels: &MplsLabelStack, ttl: Option<u8>) -> Self {
+        self.encap(
+            RouteLwEnCapType::Mpls,
+            labels
+                .encap(ttl)
+                .into_iter()
+                .map(RouteLwTunnelEncap::Mpls)
+                .collect(),
+        )
+    }
+
+    pub fn new_destination(mut self, labels: MplsLabelStack) -> Self {
+        self.new_destination = Some(labels);
+        self
+    }
+
+    fn validate(
+        &self,
+        address_family: AddressFamily,
+    ) -> Result<(), DecodeError> {
+        if self.weight == 0 || self.weight > NEXT_HOP_WEIGHT_MAX {
+            return Err(format!(
+                "Invalid next-hop weight {}, should be in range of \
+                1-{NEXT_HOP_WEIGHT_MAX}",
+                self.weight
+            )
+            .into());
+        }
+        if self.gateway.is_some() && self.via.is_some() {
+            return Err("Next-hop cannot have both gateway and via".into());
+        }
+        if let Some(gateway) = self.gateway {
+            if AddressFamily::from(&gateway) != address_family {
+                return Err(format!(
+                    "Next-hop gateway {gateway} is not in route address \
+                    family {address_family:?}, please use via instead"
+                )
+                .into());
+            }
+        }
+        if let Some(via) = self.via.as_ref() {
+            if address_family == AddressFamily::Inet6 {
+                return Err(format!(
+                    "Next-hop via {via:?} is not supported by IPv6 route"
+                )
+                .into());
+            }
+        }
+        if self.gateway.is_none()
+            && self.via.is_none()
+            && self.interface_index == 0
+        {
+            return Err(
+                "Next-hop should have gateway, via or interface defined".into(),
+            );
+        }
+        if let Some((encap_type, encap)) = self.encap.as_ref() {
+            if !matches!(
+                address_family,
+                AddressFamily::Inet | AddressFamily::Inet6
+            ) {
+                return Err(format!(
+                    "Next-hop encap is not supported by route address \
+                    family {address_family:?}"
+                )
+                .into());
+            }
+            if encap.is_empty() {
+                return Err(format!(
+                    "Next-hop encap {encap_type} should not be empty"
+                )
+                .into());
+            }
+        }
+        if self.new_destination.is_some()
+            && address_family != AddressFamily::Mpls
+        {
+            return Err(format!(
+                "Next-hop new destination label stack is not supported by \
+                route address family {address_family:?}"
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Validate and convert into `RouteNextHop` of specified route address
+    /// family.
+    pub fn build(
+        &self,
+        address_family: AddressFamily,
+    ) -> Result<RouteNextHop, DecodeError> {
+        self.validate(address_family)?;
+        let mut nh = RouteNextHop::default();
+        nh.flags.clone_from(&self.flags);
+        nh.hops = (self.weight - 1) as u8;
+        nh.interface_index = self.interface_index;
+        if let Some(gateway) = self.gateway {
+            nh.attributes.push(RouteAttribute::Gateway(gateway.into()));
+        }
+        if let Some(labels) = self.new_destination.as_ref() {
+            nh.attributes
+                .push(RouteAttribute::NewDestination(labels.to_labels()));
+        }
+        if let Some(via) = self.via.as_ref() {
+            nh.attributes.push(RouteAttribute::Via(via.clone()));
+        }
+        if let Some((encap_type, encap)) = self.encap.as_ref() {
+            nh.attributes.push(RouteAttribute::Encap(encap.clone()));
+            nh.attributes.push(RouteAttribute::EncapType(*encap_type));
+        }
+        Ok(nh)
+    }
+
+    /// Validate and convert into `RouteAttribute::MultiPath`.
+    pub fn multipath(
+        address_family: AddressFamily,
+        next_hops: &[Self],
+    ) -> Result<RouteAttribute, DecodeError> {
+        if next_hops.is_empty() {
+            return Err(
+                "Multipath route should have at least one next-hop".into()
+            );
+        }
+        let mut nhs = Vec::new();
+        for next_hop in next_hops {
+            nhs.push(next_hop.build(address_family)?);
+        }
+        Ok(RouteAttribute::MultiPath(nhs))
+    }
+}
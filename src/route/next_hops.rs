@@ -8,7 +8,8 @@ use netlink_packet_utils::{
 };
 
 use super::{
-    super::AddressFamily, RouteAttribute, RouteLwEnCapType, RouteType,
+    super::AddressFamily, attribute::RTA_ENCAP_TYPE, RouteAttribute,
+    RouteLwEnCapType, RouteType,
 };
 
 pub(crate) const RTNH_F_DEAD: u8 = 1;
@@ -191,6 +192,22 @@ impl<'a, T: AsRef<[u8]> + 'a>
         ),
     ) -> Result<Self, DecodeError> {
         let mut nlas = vec![];
+        // Each next-hop could hold its own RTA_ENCAP_TYPE which is placed
+        // after RTA_ENCAP, use it instead of the route one if found.
+        let mut encap_type = encap_type;
+        for nla_buf in buf.attributes().flatten() {
+            if nla_buf.kind() == RTA_ENCAP_TYPE {
+                if let Ok(RouteAttribute::EncapType(v)) =
+                    RouteAttribute::parse_with_param(
+                        &nla_buf,
+                        (address_family, route_type, encap_type),
+                    )
+                {
+                    encap_type = v;
+                    break;
+                }
+            }
+        }
         for nla_buf in buf.attributes() {
             nlas.push(RouteAttribute::parse_with_param(
                 &nla_buf?,
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const RPL_IPTUNNEL_SRH: u16 = 1;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_RPL`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteRplIpTunnel {
+    Srh(RplHeader),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteRplIpTunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Srh(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Srh(_) => RPL_IPTUNNEL_SRH,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Srh(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteRplIpTunnel
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            RPL_IPTUNNEL_SRH => Self::Srh(
+                RplHeader::parse(
+                    &RplHeaderBuffer::new_checked(payload)
+                        .context("invalid RPL_IPTUNNEL_SRH value")?,
+                )
+                .context("invalid RPL_IPTUNNEL_SRH value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const IPV6_SRCRT_TYPE_3: u8 = 3;
+const RPL_HEADER_LEN: usize = 8;
+// The `header_length` is u8 in the unit of 8 octets excluding the first 8
+// octets, hence at most 127 uncompressed IPv6 addresses.
+const RPL_SEGMENTS_MAX: usize =
+    (u8::MAX as usize * 8 + 8 - RPL_HEADER_LEN) / IPV6_ADDR_LEN;
+
+buffer!(RplHeaderBuffer(RPL_HEADER_LEN) {
+    next_header: (u8, 0),
+    header_length: (u8, 1),
+    routing_type: (u8, 2),
+    segments_left: (u8, 3),
+    compression: (u8, 4),
+    pad: (u8, 5),
+    payload: (slice, RPL_HEADER_LEN..),
+});
+
+/// IPv6 Routing Header for Source Routes with RPL defined in RFC 6554, the
+/// `struct ipv6_rpl_sr_hdr` of kernel. Kernel only supports uncompressed
+/// addresses in netlink, hence compressed header is treated as invalid.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct RplHeader {
+    pub next_header: u8,
+    pub segments_left: u8,
+    /// The Pad and Reserved bits, ignored by kernel
+    pub pad: u8,
+    /// Segment list in the order of visit, at most 127 addresses. Please
+    /// use [RplHeader::new] to check the limitation.
+    pub segments: Vec<Ipv6Addr>,
+}
+
+impl RplHeader {
+    pub fn new(segments: &[Ipv6Addr]) -> Result<Self, DecodeError> {
+        if segments.len() > RPL_SEGMENTS_MAX {
+            return Err(format!(
+                "Too many RPL SRH segments {}, maximum is {RPL_SEGMENTS_MAX}",
+                segments.len()
+            )
+            .into());
+        }
+        Ok(Self {
+            segments_left: segments.len() as u8,
+            segments: segments.to_vec(),
+            ..Default::default()
+        })
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<RplHeaderBuffer<&T>> for RplHeader {
+    fn parse(buf: &RplHeaderBuffer<&T>) -> Result<Self, DecodeError> {
+        if buf.routing_type() != IPV6_SRCRT_TYPE_3 {
+            return Err(format!(
+                "Invalid RPL SRH routing type {}, expecting \
+                {IPV6_SRCRT_TYPE_3}",
+                buf.routing_type()
+            )
+            .into());
+        }
+        let len = buf.inner().len();
+        if (buf.header_length() as usize + 1) * 8 != len {
+            return Err(format!(
+                "Invalid RPL SRH header length {}, SRH is {len} bytes",
+                buf.header_length()
+            )
+            .into());
+        }
+        if buf.compression() != 0 {
+            return Err(format!(
+                "Compressed RPL SRH is not supported, CmprI/CmprE {:#x}",
+                buf.compression(),
+            )
+            .into());
+        }
+        let payload = buf.payload();
+        let chunks = payload.chunks_exact(IPV6_ADDR_LEN);
+        if !chunks.remainder().is_empty() {
+            return Err(format!(
+                "Invalid RPL SRH segments length {}, should be multiple \
+                of {IPV6_ADDR_LEN}",
+                payload.len()
+            )
+            .into());
+        }
+        let mut segments = Vec::new();
+        for raw in chunks {
+            segments.push(parse_ipv6_addr(raw)?);
+        }
+        Ok(Self {
+            next_header: buf.next_header(),
+            segments_left: buf.segments_left(),
+            pad: buf.pad(),
+            segments,
+        })
+    }
+}
+
+impl Emitable for RplHeader {
+    fn buffer_len(&self) -> usize {
+        RPL_HEADER_LEN + self.segments.len() * IPV6_ADDR_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        let mut buffer = RplHeaderBuffer::new(&mut buffer[..len]);
+        buffer.set_next_header(self.next_header);
+        buffer.set_header_length((len / 8 - 1) as u8);
+        buffer.set_routing_type(IPV6_SRCRT_TYPE_3);
+        buffer.set_segments_left(self.segments_left);
+        buffer.set_compression(0);
+        buffer.set_pad(self.pad);
+        buffer.inner_mut()[6..RPL_HEADER_LEN].fill(0);
+        for (i, segment) in self.segments.iter().enumerate() {
+            buffer.payload_mut()[i * IPV6_ADDR_LEN..(i + 1) * IPV6_ADDR_LEN]
+                .copy_from_slice(&segment.octets());
+        }
+    }
+}
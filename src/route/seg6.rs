@@ -0,0 +1,488 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const SEG6_IPTUNNEL_SRH: u16 = 1;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_SEG6`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteSeg6IpTunnel {
+    Srh(Seg6IpTunnelEncap),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteSeg6IpTunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Srh(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Srh(_) => SEG6_IPTUNNEL_SRH,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Srh(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteSeg6IpTunnel
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_IPTUNNEL_SRH => Self::Srh(
+                Seg6IpTunnelEncap::parse(
+                    &Seg6IpTunnelEncapBuffer::new_checked(payload)
+                        .context("invalid SEG6_IPTUNNEL_SRH value")?,
+                )
+                .context("invalid SEG6_IPTUNNEL_SRH value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const SEG6_IPTUN_MODE_INLINE: u32 = 0;
+const SEG6_IPTUN_MODE_ENCAP: u32 = 1;
+const SEG6_IPTUN_MODE_L2ENCAP: u32 = 2;
+const SEG6_IPTUN_MODE_ENCAP_RED: u32 = 3;
+const SEG6_IPTUN_MODE_L2ENCAP_RED: u32 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum Seg6Mode {
+    /// Insert SRH into the existing IPv6 header
+    Inline,
+    /// Encapsulate the packet in an outer IPv6 header with SRH
+    #[default]
+    Encap,
+    /// Encapsulate the L2 frame in an outer IPv6 header with SRH
+    L2Encap,
+    /// Like `Encap` but with reduced SRH, the first segment is omitted
+    EncapRed,
+    /// Like `L2Encap` but with reduced SRH, the first segment is omitted
+    L2EncapRed,
+    Other(u32),
+}
+
+impl From<u32> for Seg6Mode {
+    fn from(d: u32) -> Self {
+        match d {
+            SEG6_IPTUN_MODE_INLINE => Self::Inline,
+            SEG6_IPTUN_MODE_ENCAP => Self::Encap,
+            SEG6_IPTUN_MODE_L2ENCAP => Self::L2Encap,
+            SEG6_IPTUN_MODE_ENCAP_RED => Self::EncapRed,
+            SEG6_IPTUN_MODE_L2ENCAP_RED => Self::L2EncapRed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Seg6Mode> for u32 {
+    fn from(v: Seg6Mode) -> u32 {
+        match v {
+            Seg6Mode::Inline => SEG6_IPTUN_MODE_INLINE,
+            Seg6Mode::Encap => SEG6_IPTUN_MODE_ENCAP,
+            Seg6Mode::L2Encap => SEG6_IPTUN_MODE_L2ENCAP,
+            Seg6Mode::EncapRed => SEG6_IPTUN_MODE_ENCAP_RED,
+            Seg6Mode::L2EncapRed => SEG6_IPTUN_MODE_L2ENCAP_RED,
+            Seg6Mode::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for Seg6Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Inline => write!(f, "inline"),
+            Self::Encap => write!(f, "encap"),
+            Self::L2Encap => write!(f, "l2encap"),
+            Self::EncapRed => write!(f, "encap.red"),
+            Self::L2EncapRed => write!(f, "l2encap.red"),
+            Self::Other(d) => write!(f, "other({d})"),
+        }
+    }
+}
+
+const SEG6_IPTUNNEL_ENCAP_HEADER_LEN: usize = 4;
+
+buffer!(Seg6IpTunnelEncapBuffer(
+    SEG6_IPTUNNEL_ENCAP_HEADER_LEN + SEG6_HEADER_LEN
+) {
+    mode: (u32, 0..4),
+    srh: (slice, SEG6_IPTUNNEL_ENCAP_HEADER_LEN..),
+});
+
+/// The `struct seg6_iptunnel_encap` of kernel
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Seg6IpTunnelEncap {
+    pub mode: Seg6Mode,
+    pub header: Seg6Header,
+}
+
+impl Seg6IpTunnelEncap {
+    pub fn new(mode: Seg6Mode, header: Seg6Header) -> Self {
+        Self { mode, header }
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<Seg6IpTunnelEncapBuffer<&T>>
+    for Seg6IpTunnelEncap
+{
+    fn parse(buf: &Seg6IpTunnelEncapBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mode: buf.mode().into(),
+            header: Seg6Header::parse(&Seg6HeaderBuffer::new_checked(
+                buf.srh(),
+            )?)?,
+        })
+    }
+}
+
+impl Emitable for Seg6IpTunnelEncap {
+    fn buffer_len(&self) -> usize {
+        SEG6_IPTUNNEL_ENCAP_HEADER_LEN + self.header.buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = Seg6IpTunnelEncapBuffer::new(buffer);
+        buffer.set_mode(self.mode.into());
+        self.header.emit(buffer.srh_mut());
+    }
+}
+
+const IPV6_SRCRT_TYPE_4: u8 = 4;
+const SEG6_HEADER_LEN: usize = 8;
+
+buffer!(Seg6HeaderBuffer(SEG6_HEADER_LEN) {
+    next_header: (u8, 0),
+    header_length: (u8, 1),
+    routing_type: (u8, 2),
+    segments_left: (u8, 3),
+    last_entry: (u8, 4),
+    flags: (u8, 5),
+    tag: (slice, 6..8),
+    payload: (slice, SEG6_HEADER_LEN..),
+});
+
+/// IPv6 Segment Routing Header(SRH) defined in RFC 8754, the
+/// `struct ipv6_sr_hdr` of kernel.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Seg6Header {
+    pub next_header: u8,
+    /// Index of the segment in `segments` the packet will be forwarded to
+    pub segments_left: u8,
+    pub flags: Vec<Seg6HeaderFlag>,
+    pub tag: u16,
+    /// Segment list in SRH order, the last segment of the path comes first
+    pub segments: Vec<Ipv6Addr>,
+    /// The total length of SRH must be multiple of 8 bytes, use
+    /// `Seg6Tlv::Padding` to align custom TLVs.
+    pub tlvs: Vec<Seg6Tlv>,
+}
+
+impl Seg6Header {
+    /// Create SRH from segments in the order of path, the first
+    /// segment to visit comes first.
+    pub fn new(segments: &[Ipv6Addr]) -> Self {
+        Self {
+            segments_left: segments.len().saturating_sub(1) as u8,
+            segments: segments.iter().rev().copied().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Set `Seg6HeaderFlag::Hmac` and append the HMAC TLV with all zero
+    /// HMAC, kernel will compute the HMAC using the key with specified ID.
+    pub fn hmac(mut self, key_id: u32) -> Self {
+        if !self.flags.contains(&Seg6HeaderFlag::Hmac) {
+            self.flags.push(Seg6HeaderFlag::Hmac);
+        }
+        self.tlvs.push(Seg6Tlv::Hmac(Seg6Hmac::new(key_id)));
+        self
+    }
+
+    fn last_entry(&self) -> u8 {
+        self.segments.len().saturating_sub(1) as u8
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<Seg6HeaderBuffer<&T>> for Seg6Header {
+    fn parse(buf: &Seg6HeaderBuffer<&T>) -> Result<Self, DecodeError> {
+        if buf.routing_type() != IPV6_SRCRT_TYPE_4 {
+            return Err(format!(
+                "Invalid SRH routing type {}, expecting {IPV6_SRCRT_TYPE_4}",
+                buf.routing_type()
+            )
+            .into());
+        }
+        let len = buf.inner().len();
+        if (buf.header_length() as usize + 1) * 8 != len {
+            return Err(format!(
+                "Invalid SRH header length {}, SRH is {len} bytes",
+                buf.header_length()
+            )
+            .into());
+        }
+        let payload = buf.payload();
+        let segments_len = (buf.last_entry() as usize + 1) * IPV6_ADDR_LEN;
+        if payload.len() < segments_len {
+            return Err(format!(
+                "Invalid SRH last entry {}, only {} bytes for segments",
+                buf.last_entry(),
+                payload.len()
+            )
+            .into());
+        }
+        let mut segments = Vec::new();
+        for raw in payload[..segments_len].chunks(IPV6_ADDR_LEN) {
+            segments.push(parse_ipv6_addr(raw)?);
+        }
+        let mut tlvs = Vec::new();
+        let mut offset = segments_len;
+        while offset < payload.len() {
+            let tlv = Seg6Tlv::parse(&payload[offset..])?;
+            offset += tlv.buffer_len();
+            tlvs.push(tlv);
+        }
+
+        Ok(Self {
+            next_header: buf.next_header(),
+            segments_left: buf.segments_left(),
+            flags: VecSeg6HeaderFlag::from(buf.flags()).0,
+            tag: u16::from_be_bytes([buf.tag()[0], buf.tag()[1]]),
+            segments,
+            tlvs,
+        })
+    }
+}
+
+impl Emitable for Seg6Header {
+    fn buffer_len(&self) -> usize {
+        SEG6_HEADER_LEN
+            + self.segments.len() * IPV6_ADDR_LEN
+            + self.tlvs.iter().map(|t| t.buffer_len()).sum::<usize>()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        let mut buffer = Seg6HeaderBuffer::new(&mut buffer[..len]);
+        buffer.set_next_header(self.next_header);
+        buffer.set_header_length((len / 8).saturating_sub(1) as u8);
+        buffer.set_routing_type(IPV6_SRCRT_TYPE_4);
+        buffer.set_segments_left(self.segments_left);
+        buffer.set_last_entry(self.last_entry());
+        buffer.set_flags(u8::from(&VecSeg6HeaderFlag(self.flags.clone())));
+        buffer.tag_mut().copy_from_slice(&self.tag.to_be_bytes());
+        let payload = buffer.payload_mut();
+        let mut offset = 0;
+        for segment in &self.segments {
+            payload[offset..offset + IPV6_ADDR_LEN]
+                .copy_from_slice(&segment.octets());
+            offset += IPV6_ADDR_LEN;
+        }
+        for tlv in &self.tlvs {
+            tlv.emit(&mut payload[offset..]);
+            offset += tlv.buffer_len();
+        }
+    }
+}
+
+const SR6_FLAG1_PROTECTED: u8 = 1 << 6;
+const SR6_FLAG1_OAM: u8 = 1 << 5;
+const SR6_FLAG1_ALERT: u8 = 1 << 4;
+const SR6_FLAG1_HMAC: u8 = 1 << 3;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Seg6HeaderFlag {
+    Protected,
+    Oam,
+    Alert,
+    Hmac,
+    Other(u8),
+}
+
+impl From<Seg6HeaderFlag> for u8 {
+    fn from(v: Seg6HeaderFlag) -> u8 {
+        match v {
+            Seg6HeaderFlag::Protected => SR6_FLAG1_PROTECTED,
+            Seg6HeaderFlag::Oam => SR6_FLAG1_OAM,
+            Seg6HeaderFlag::Alert => SR6_FLAG1_ALERT,
+            Seg6HeaderFlag::Hmac => SR6_FLAG1_HMAC,
+            Seg6HeaderFlag::Other(d) => d,
+        }
+    }
+}
+
+const ALL_SEG6_HEADER_FLAGS: [Seg6HeaderFlag; 4] = [
+    Seg6HeaderFlag::Protected,
+    Seg6HeaderFlag::Oam,
+    Seg6HeaderFlag::Alert,
+    Seg6HeaderFlag::Hmac,
+];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecSeg6HeaderFlag(Vec<Seg6HeaderFlag>);
+
+impl From<u8> for VecSeg6HeaderFlag {
+    fn from(d: u8) -> Self {
+        let mut got: u8 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_SEG6_HEADER_FLAGS {
+            if (d & (u8::from(flag))) > 0 {
+                ret.push(flag);
+                got += u8::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(Seg6HeaderFlag::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecSeg6HeaderFlag> for u8 {
+    fn from(v: &VecSeg6HeaderFlag) -> u8 {
+        let mut d: u8 = 0;
+        for flag in &v.0 {
+            d |= u8::from(*flag);
+        }
+        d
+    }
+}
+
+const SR6_TLV_PADDING: u8 = 4;
+const SR6_TLV_HMAC: u8 = 5;
+
+const SR6_TLV_HEADER_LEN: usize = 2;
+const SEG6_HMAC_FIELD_LEN: usize = 32;
+// reserved(2) + key_id(4) + hmac(32)
+const SR6_TLV_HMAC_LEN: usize = 6 + SEG6_HMAC_FIELD_LEN;
+
+/// TLV following the segment list in SRH
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Seg6Tlv {
+    /// PadN TLV with specified length of zeros as data
+    Padding(u8),
+    Hmac(Seg6Hmac),
+    /// Tuple of (type, data)
+    Other(u8, Vec<u8>),
+}
+
+impl Seg6Tlv {
+    fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < SR6_TLV_HEADER_LEN {
+            return Err(format!("Incomplete SRH TLV {payload:?}").into());
+        }
+        let kind = payload[0];
+        let len = payload[1] as usize;
+        let data = payload.get(SR6_TLV_HEADER_LEN..SR6_TLV_HEADER_LEN + len);
+        let data = match data {
+            Some(d) => d,
+            None => {
+                return Err(format!(
+                    "Invalid SRH TLV length {len}, only {} bytes left",
+                    payload.len() - SR6_TLV_HEADER_LEN
+                )
+                .into())
+            }
+        };
+        Ok(match kind {
+            SR6_TLV_PADDING => Self::Padding(len as u8),
+            SR6_TLV_HMAC if len == SR6_TLV_HMAC_LEN => {
+                let mut hmac = [0u8; SEG6_HMAC_FIELD_LEN];
+                hmac.copy_from_slice(&data[6..]);
+                Self::Hmac(Seg6Hmac {
+                    key_id: u32::from_be_bytes([
+                        data[2], data[3], data[4], data[5],
+                    ]),
+                    hmac,
+                })
+            }
+            SR6_TLV_HMAC => {
+                return Err(format!(
+                    "Invalid SRH HMAC TLV length {len}, \
+                    expecting {SR6_TLV_HMAC_LEN}"
+                )
+                .into())
+            }
+            _ => Self::Other(kind, data.to_vec()),
+        })
+    }
+}
+
+impl Emitable for Seg6Tlv {
+    fn buffer_len(&self) -> usize {
+        SR6_TLV_HEADER_LEN
+            + match self {
+                Self::Padding(len) => *len as usize,
+                Self::Hmac(_) => SR6_TLV_HMAC_LEN,
+                Self::Other(_, data) => data.len(),
+            }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        buffer[0] = match self {
+            Self::Padding(_) => SR6_TLV_PADDING,
+            Self::Hmac(_) => SR6_TLV_HMAC,
+            Self::Other(kind, _) => *kind,
+        };
+        buffer[1] = (len - SR6_TLV_HEADER_LEN) as u8;
+        let data = &mut buffer[SR6_TLV_HEADER_LEN..len];
+        match self {
+            Self::Padding(_) => data.fill(0),
+            Self::Hmac(v) => {
+                data[..2].fill(0);
+                data[2..6].copy_from_slice(&v.key_id.to_be_bytes());
+                data[6..].copy_from_slice(&v.hmac);
+            }
+            Self::Other(_, v) => data.copy_from_slice(v.as_slice()),
+        }
+    }
+}
+
+/// The `struct sr6_tlv_hmac` of kernel
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct Seg6Hmac {
+    pub key_id: u32,
+    pub hmac: [u8; SEG6_HMAC_FIELD_LEN],
+}
+
+impl Seg6Hmac {
+    pub fn new(key_id: u32) -> Self {
+        Self {
+            key_id,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,582 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_string, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{Seg6Header, Seg6HeaderBuffer};
+use crate::ip::{
+    parse_ipv4_addr, parse_ipv6_addr, IPV4_ADDR_LEN, IPV6_ADDR_LEN,
+};
+
+const SEG6_LOCAL_ACTION: u16 = 1;
+const SEG6_LOCAL_SRH: u16 = 2;
+const SEG6_LOCAL_TABLE: u16 = 3;
+const SEG6_LOCAL_NH4: u16 = 4;
+const SEG6_LOCAL_NH6: u16 = 5;
+const SEG6_LOCAL_IIF: u16 = 6;
+const SEG6_LOCAL_OIF: u16 = 7;
+const SEG6_LOCAL_BPF: u16 = 8;
+const SEG6_LOCAL_VRFTABLE: u16 = 9;
+const SEG6_LOCAL_COUNTERS: u16 = 10;
+const SEG6_LOCAL_FLAVORS: u16 = 11;
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_SEG6_LOCAL`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteSeg6LocalIpTunnel {
+    Action(Seg6LocalAction),
+    /// SRH to push for `End.B6` and `End.B6.Encaps`
+    Srh(Seg6Header),
+    Table(u32),
+    Nh4(Ipv4Addr),
+    Nh6(Ipv6Addr),
+    Iif(u32),
+    Oif(u32),
+    Bpf(Vec<Seg6LocalBpf>),
+    VrfTable(u32),
+    /// Use empty `Vec` to enable counters when creating route
+    Counters(Vec<Seg6LocalCounter>),
+    Flavors(Vec<Seg6LocalFlavor>),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteSeg6LocalIpTunnel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Action(_)
+            | Self::Table(_)
+            | Self::Iif(_)
+            | Self::Oif(_)
+            | Self::VrfTable(_) => 4,
+            Self::Srh(v) => v.buffer_len(),
+            Self::Nh4(_) => IPV4_ADDR_LEN,
+            Self::Nh6(_) => IPV6_ADDR_LEN,
+            Self::Bpf(v) => v.as_slice().buffer_len(),
+            Self::Counters(v) => v.as_slice().buffer_len(),
+            Self::Flavors(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Action(_) => SEG6_LOCAL_ACTION,
+            Self::Srh(_) => SEG6_LOCAL_SRH,
+            Self::Table(_) => SEG6_LOCAL_TABLE,
+            Self::Nh4(_) => SEG6_LOCAL_NH4,
+            Self::Nh6(_) => SEG6_LOCAL_NH6,
+            Self::Iif(_) => SEG6_LOCAL_IIF,
+            Self::Oif(_) => SEG6_LOCAL_OIF,
+            Self::Bpf(_) => SEG6_LOCAL_BPF,
+            Self::VrfTable(_) => SEG6_LOCAL_VRFTABLE,
+            Self::Counters(_) => SEG6_LOCAL_COUNTERS | NLA_F_NESTED,
+            Self::Flavors(_) => SEG6_LOCAL_FLAVORS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Action(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Table(v)
+            | Self::Iif(v)
+            | Self::Oif(v)
+            | Self::VrfTable(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Srh(v) => v.emit(buffer),
+            Self::Nh4(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Nh6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Bpf(v) => v.as_slice().emit(buffer),
+            Self::Counters(v) => v.as_slice().emit(buffer),
+            Self::Flavors(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for RouteSeg6LocalIpTunnel
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_ACTION => Self::Action(
+                parse_u32(payload)
+                    .context("invalid SEG6_LOCAL_ACTION value")?
+                    .into(),
+            ),
+            SEG6_LOCAL_SRH => Self::Srh(
+                Seg6Header::parse(
+                    &Seg6HeaderBuffer::new_checked(payload)
+                        .context("invalid SEG6_LOCAL_SRH value")?,
+                )
+                .context("invalid SEG6_LOCAL_SRH value")?,
+            ),
+            SEG6_LOCAL_TABLE => Self::Table(
+                parse_u32(payload).context("invalid SEG6_LOCAL_TABLE value")?,
+            ),
+            SEG6_LOCAL_NH4 => Self::Nh4(
+                parse_ipv4_addr(payload)
+                    .context("invalid SEG6_LOCAL_NH4 value")?,
+            ),
+            SEG6_LOCAL_NH6 => Self::Nh6(
+                parse_ipv6_addr(payload)
+                    .context("invalid SEG6_LOCAL_NH6 value")?,
+            ),
+            SEG6_LOCAL_IIF => Self::Iif(
+                parse_u32(payload).context("invalid SEG6_LOCAL_IIF value")?,
+            ),
+            SEG6_LOCAL_OIF => Self::Oif(
+                parse_u32(payload).context("invalid SEG6_LOCAL_OIF value")?,
+            ),
+            SEG6_LOCAL_BPF => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context("invalid SEG6_LOCAL_BPF value")?;
+                    nlas.push(Seg6LocalBpf::parse(nla)?);
+                }
+                Self::Bpf(nlas)
+            }
+            SEG6_LOCAL_VRFTABLE => Self::VrfTable(
+                parse_u32(payload)
+                    .context("invalid SEG6_LOCAL_VRFTABLE value")?,
+            ),
+            SEG6_LOCAL_COUNTERS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        &nla.context("invalid SEG6_LOCAL_COUNTERS value")?;
+                    nlas.push(Seg6LocalCounter::parse(nla)?);
+                }
+                Self::Counters(nlas)
+            }
+            SEG6_LOCAL_FLAVORS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla =
+                        &nla.context("invalid SEG6_LOCAL_FLAVORS value")?;
+                    nlas.push(Seg6LocalFlavor::parse(nla)?);
+                }
+                Self::Flavors(nlas)
+            }
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const SEG6_LOCAL_ACTION_UNSPEC: u32 = 0;
+const SEG6_LOCAL_ACTION_END: u32 = 1;
+const SEG6_LOCAL_ACTION_END_X: u32 = 2;
+const SEG6_LOCAL_ACTION_END_T: u32 = 3;
+const SEG6_LOCAL_ACTION_END_DX2: u32 = 4;
+const SEG6_LOCAL_ACTION_END_DX6: u32 = 5;
+const SEG6_LOCAL_ACTION_END_DX4: u32 = 6;
+const SEG6_LOCAL_ACTION_END_DT6: u32 = 7;
+const SEG6_LOCAL_ACTION_END_DT4: u32 = 8;
+const SEG6_LOCAL_ACTION_END_B6: u32 = 9;
+const SEG6_LOCAL_ACTION_END_B6_ENCAP: u32 = 10;
+const SEG6_LOCAL_ACTION_END_BM: u32 = 11;
+const SEG6_LOCAL_ACTION_END_S: u32 = 12;
+const SEG6_LOCAL_ACTION_END_AS: u32 = 13;
+const SEG6_LOCAL_ACTION_END_AM: u32 = 14;
+const SEG6_LOCAL_ACTION_END_BPF: u32 = 15;
+const SEG6_LOCAL_ACTION_END_DT46: u32 = 16;
+
+/// SRv6 endpoint behaviors defined in RFC 8986
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum Seg6LocalAction {
+    #[default]
+    Unspec,
+    /// Endpoint
+    End,
+    /// Endpoint with L3 cross-connect to `Nh4` or `Nh6`
+    EndX,
+    /// Endpoint with specific IPv6 table lookup
+    EndT,
+    /// Decapsulation with L2 cross-connect to `Oif`
+    EndDx2,
+    /// Decapsulation with IPv6 cross-connect to `Nh6`
+    EndDx6,
+    /// Decapsulation with IPv4 cross-connect to `Nh4`
+    EndDx4,
+    /// Decapsulation with specific IPv6 table lookup
+    EndDt6,
+    /// Decapsulation with specific IPv4 table lookup
+    EndDt4,
+    /// Endpoint bound to SRv6 policy by inserting `Srh`
+    EndB6,
+    /// Endpoint bound to SRv6 policy with encapsulation of `Srh`
+    EndB6Encap,
+    /// Endpoint bound to SR-MPLS policy
+    EndBm,
+    /// Endpoint in search of a target in table
+    EndS,
+    /// Static proxy
+    EndAs,
+    /// Masquerading proxy
+    EndAm,
+    /// Custom behavior implemented in BPF program
+    EndBpf,
+    /// Decapsulation with specific IP table lookup
+    EndDt46,
+    Other(u32),
+}
+
+impl From<u32> for Seg6LocalAction {
+    fn from(d: u32) -> Self {
+        match d {
+            SEG6_LOCAL_ACTION_UNSPEC => Self::Unspec,
+            SEG6_LOCAL_ACTION_END => Self::End,
+            SEG6_LOCAL_ACTION_END_X => Self::EndX,
+            SEG6_LOCAL_ACTION_END_T => Self::EndT,
+            SEG6_LOCAL_ACTION_END_DX2 => Self::EndDx2,
+            SEG6_LOCAL_ACTION_END_DX6 => Self::EndDx6,
+            SEG6_LOCAL_ACTION_END_DX4 => Self::EndDx4,
+            SEG6_LOCAL_ACTION_END_DT6 => Self::EndDt6,
+            SEG6_LOCAL_ACTION_END_DT4 => Self::EndDt4,
+            SEG6_LOCAL_ACTION_END_B6 => Self::EndB6,
+            SEG6_LOCAL_ACTION_END_B6_ENCAP => Self::EndB6Encap,
+            SEG6_LOCAL_ACTION_END_BM => Self::EndBm,
+            SEG6_LOCAL_ACTION_END_S => Self::EndS,
+            SEG6_LOCAL_ACTION_END_AS => Self::EndAs,
+            SEG6_LOCAL_ACTION_END_AM => Self::EndAm,
+            SEG6_LOCAL_ACTION_END_BPF => Self::EndBpf,
+            SEG6_LOCAL_ACTION_END_DT46 => Self::EndDt46,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Seg6LocalAction> for u32 {
+    fn from(v: Seg6LocalAction) -> u32 {
+        match v {
+            Seg6LocalAction::Unspec => SEG6_LOCAL_ACTION_UNSPEC,
+            Seg6LocalAction::End => SEG6_LOCAL_ACTION_END,
+            Seg6LocalAction::EndX => SEG6_LOCAL_ACTION_END_X,
+            Seg6LocalAction::EndT => SEG6_LOCAL_ACTION_END_T,
+            Seg6LocalAction::EndDx2 => SEG6_LOCAL_ACTION_END_DX2,
+            Seg6LocalAction::EndDx6 => SEG6_LOCAL_ACTION_END_DX6,
+            Seg6LocalAction::EndDx4 => SEG6_LOCAL_ACTION_END_DX4,
+            Seg6LocalAction::EndDt6 => SEG6_LOCAL_ACTION_END_DT6,
+            Seg6LocalAction::EndDt4 => SEG6_LOCAL_ACTION_END_DT4,
+            Seg6LocalAction::EndB6 => SEG6_LOCAL_ACTION_END_B6,
+            Seg6LocalAction::EndB6Encap => SEG6_LOCAL_ACTION_END_B6_ENCAP,
+            Seg6LocalAction::EndBm => SEG6_LOCAL_ACTION_END_BM,
+            Seg6LocalAction::EndS => SEG6_LOCAL_ACTION_END_S,
+            Seg6LocalAction::EndAs => SEG6_LOCAL_ACTION_END_AS,
+            Seg6LocalAction::EndAm => SEG6_LOCAL_ACTION_END_AM,
+            Seg6LocalAction::EndBpf => SEG6_LOCAL_ACTION_END_BPF,
+            Seg6LocalAction::EndDt46 => SEG6_LOCAL_ACTION_END_DT46,
+            Seg6LocalAction::Other(d) => d,
+        }
+    }
+}
+
+impl std::fmt::Display for Seg6LocalAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unspec => write!(f, "unspec"),
+            Self::End => write!(f, "End"),
+            Self::EndX => write!(f, "End.X"),
+            Self::EndT => write!(f, "End.T"),
+            Self::EndDx2 => write!(f, "End.DX2"),
+            Self::EndDx6 => write!(f, "End.DX6"),
+            Self::EndDx4 => write!(f, "End.DX4"),
+            Self::EndDt6 => write!(f, "End.DT6"),
+            Self::EndDt4 => write!(f, "End.DT4"),
+            Self::EndB6 => write!(f, "End.B6"),
+            Self::EndB6Encap => write!(f, "End.B6.Encaps"),
+            Self::EndBm => write!(f, "End.BM"),
+            Self::EndS => write!(f, "End.S"),
+            Self::EndAs => write!(f, "End.AS"),
+            Self::EndAm => write!(f, "End.AM"),
+            Self::EndBpf => write!(f, "End.BPF"),
+            Self::EndDt46 => write!(f, "End.DT46"),
+            Self::Other(d) => write!(f, "other({d})"),
+        }
+    }
+}
+
+const SEG6_LOCAL_BPF_PROG: u16 = 1;
+const SEG6_LOCAL_BPF_PROG_NAME: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Seg6LocalBpf {
+    /// File descriptor of BPF program when creating route, BPF program ID
+    /// when dumping
+    Prog(u32),
+    Name(String),
+    Other(DefaultNla),
+}
+
+impl Nla for Seg6LocalBpf {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Prog(_) => 4,
+            Self::Name(v) => v.len() + 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Prog(_) => SEG6_LOCAL_BPF_PROG,
+            Self::Name(_) => SEG6_LOCAL_BPF_PROG_NAME,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Prog(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Name(v) => {
+                buffer[..v.len()].copy_from_slice(v.as_bytes());
+                buffer[v.len()] = 0;
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Seg6LocalBpf {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_BPF_PROG => Self::Prog(
+                parse_u32(payload)
+                    .context("invalid SEG6_LOCAL_BPF_PROG value")?,
+            ),
+            SEG6_LOCAL_BPF_PROG_NAME => Self::Name(
+                parse_string(payload)
+                    .context("invalid SEG6_LOCAL_BPF_PROG_NAME value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const SEG6_LOCAL_CNT_PACKETS: u16 = 2;
+const SEG6_LOCAL_CNT_BYTES: u16 = 3;
+const SEG6_LOCAL_CNT_ERRORS: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Seg6LocalCounter {
+    Packets(u64),
+    Bytes(u64),
+    Errors(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for Seg6LocalCounter {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Packets(_) | Self::Bytes(_) | Self::Errors(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Packets(_) => SEG6_LOCAL_CNT_PACKETS,
+            Self::Bytes(_) => SEG6_LOCAL_CNT_BYTES,
+            Self::Errors(_) => SEG6_LOCAL_CNT_ERRORS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Packets(v) | Self::Bytes(v) | Self::Errors(v) => {
+                NativeEndian::write_u64(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Seg6LocalCounter
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_CNT_PACKETS => Self::Packets(
+                parse_u64(payload)
+                    .context("invalid SEG6_LOCAL_CNT_PACKETS value")?,
+            ),
+            SEG6_LOCAL_CNT_BYTES => Self::Bytes(
+                parse_u64(payload)
+                    .context("invalid SEG6_LOCAL_CNT_BYTES value")?,
+            ),
+            SEG6_LOCAL_CNT_ERRORS => Self::Errors(
+                parse_u64(payload)
+                    .context("invalid SEG6_LOCAL_CNT_ERRORS value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const SEG6_LOCAL_FLV_OPERATION: u16 = 1;
+const SEG6_LOCAL_FLV_LCBLOCK_BITS: u16 = 2;
+const SEG6_LOCAL_FLV_LCNODE_FN_BITS: u16 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Seg6LocalFlavor {
+    Operation(Vec<Seg6LocalFlavorOperation>),
+    /// Locator-Block length in bits for NEXT-C-SID
+    LocatorBlockBits(u8),
+    /// Locator-Node Function length in bits for NEXT-C-SID
+    LocatorNodeFunctionBits(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for Seg6LocalFlavor {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Operation(_) => 4,
+            Self::LocatorBlockBits(_) | Self::LocatorNodeFunctionBits(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Operation(_) => SEG6_LOCAL_FLV_OPERATION,
+            Self::LocatorBlockBits(_) => SEG6_LOCAL_FLV_LCBLOCK_BITS,
+            Self::LocatorNodeFunctionBits(_) => SEG6_LOCAL_FLV_LCNODE_FN_BITS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Operation(v) => NativeEndian::write_u32(
+                buffer,
+                u32::from(&VecSeg6LocalFlavorOperation(v.to_vec())),
+            ),
+            Self::LocatorBlockBits(v) | Self::LocatorNodeFunctionBits(v) => {
+                buffer[0] = *v
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Seg6LocalFlavor
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_FLV_OPERATION => Self::Operation(
+                VecSeg6LocalFlavorOperation::from(
+                    parse_u32(payload)
+                        .context("invalid SEG6_LOCAL_FLV_OPERATION value")?,
+                )
+                .0,
+            ),
+            SEG6_LOCAL_FLV_LCBLOCK_BITS => Self::LocatorBlockBits(
+                parse_u8(payload)
+                    .context("invalid SEG6_LOCAL_FLV_LCBLOCK_BITS value")?,
+            ),
+            SEG6_LOCAL_FLV_LCNODE_FN_BITS => Self::LocatorNodeFunctionBits(
+                parse_u8(payload)
+                    .context("invalid SEG6_LOCAL_FLV_LCNODE_FN_BITS value")?,
+            ),
+            _ => Self::Other(
+                DefaultNla::parse(buf)
+                    .context("invalid NLA value (unknown type) value")?,
+            ),
+        })
+    }
+}
+
+const SEG6_LOCAL_FLV_OP_PSP: u32 = 1 << 1;
+const SEG6_LOCAL_FLV_OP_USP: u32 = 1 << 2;
+const SEG6_LOCAL_FLV_OP_USD: u32 = 1 << 3;
+const SEG6_LOCAL_FLV_OP_NEXT_CSID: u32 = 1 << 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Seg6LocalFlavorOperation {
+    /// Penultimate Segment Pop
+    Psp,
+    /// Ultimate Segment Pop
+    Usp,
+    /// Ultimate Segment Decapsulation
+    Usd,
+    /// NEXT-C-SID of compressed SID
+    NextCsid,
+    Other(u32),
+}
+
+impl From<Seg6LocalFlavorOperation> for u32 {
+    fn from(v: Seg6LocalFlavorOperation) -> u32 {
+        match v {
+            Seg6LocalFlavorOperation::Psp => SEG6_LOCAL_FLV_OP_PSP,
+            Seg6LocalFlavorOperation::Usp => SEG6_LOCAL_FLV_OP_USP,
+            Seg6LocalFlavorOperation::Usd => SEG6_LOCAL_FLV_OP_USD,
+            Seg6LocalFlavorOperation::NextCsid => SEG6_LOCAL_FLV_OP_NEXT_CSID,
+            Seg6LocalFlavorOperation::Other(d) => d,
+        }
+    }
+}
+
+const ALL_SEG6_LOCAL_FLAVOR_OPERATIONS: [Seg6LocalFlavorOperation; 4] = [
+    Seg6LocalFlavorOperation::Psp,
+    Seg6LocalFlavorOperation::Usp,
+    Seg6LocalFlavorOperation::Usd,
+    Seg6LocalFlavorOperation::NextCsid,
+];
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct VecSeg6LocalFlavorOperation(Vec<Seg6LocalFlavorOperation>);
+
+impl From<u32> for VecSeg6LocalFlavorOperation {
+    fn from(d: u32) -> Self {
+        let mut got: u32 = 0;
+        let mut ret = Vec::new();
+        for flag in ALL_SEG6_LOCAL_FLAVOR_OPERATIONS {
+            if (d & (u32::from(flag))) > 0 {
+                ret.push(flag);
+                got += u32::from(flag);
+            }
+        }
+        if got != d {
+            ret.push(Seg6LocalFlavorOperation::Other(d - got));
+        }
+        Self(ret)
+    }
+}
+
+impl From<&VecSeg6LocalFlavorOperation> for u32 {
+    fn from(v: &VecSeg6LocalFlavorOperation) -> u32 {
+        let mut d: u32 = 0;
+        for flag in &v.0 {
+            d |= u32::from(*flag);
+        }
+        d
+    }
+}
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::{
+    RouteAttribute, RouteConfig, RouteHeader, RouteMessage, RouteMessageBuffer,
+    RouteMetric, RoutePreference, RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+// Equivalent to command:
+//      ip route add 198.51.100.0/24 via 192.0.2.1 dev eth1 metric 100 \
+//          table 1000 proto static src 192.0.2.10 mtu 1400
+#[test]
+fn test_route_config_ipv4_gateway() {
+    let raw = vec![
+        0x02, 0x18, 0x00, 0x00, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xe8, 0x03, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0xc6, 0x33, 0x64, 0x00, 0x08, 0x00, 0x05, 0x00, 0xc0, 0x00, 0x02, 0x01,
+        0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x0a, 0x08, 0x00, 0x04, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x64, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x08, 0x00, 0x08, 0x00, 0x02, 0x00, 0x78, 0x05, 0x00, 0x00,
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 24,
+            source_prefix_length: 0,
+            tos: 0,
+            table: RouteHeader::RT_TABLE_UNSPEC,
+            protocol: RouteProtocol::Static,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: vec![],
+        },
+        attributes: vec![
+            RouteAttribute::Table(1000),
+            RouteAttribute::Destination(Ipv4Addr::new(198, 51, 100, 0).into()),
+            RouteAttribute::Gateway(Ipv4Addr::new(192, 0, 2, 1).into()),
+            RouteAttribute::PrefSource(Ipv4Addr::new(192, 0, 2, 10).into()),
+            RouteAttribute::Oif(2),
+            RouteAttribute::Priority(100),
+            RouteAttribute::Metrics(vec![RouteMetric::Mtu(1400)]),
+        ],
+    };
+
+    let message = RouteConfig::new(Ipv4Addr::new(198, 51, 100, 0).into(), 24)
+        .gateway(Ipv4Addr::new(192, 0, 2, 1).into())
+        .oif(2)
+        .priority(100)
+        .table(1000)
+        .protocol(RouteProtocol::Static)
+        .source(Ipv4Addr::new(192, 0, 2, 10).into())
+        .metrics(vec![RouteMetric::Mtu(1400)])
+        .build()
+        .unwrap();
+
+    assert_eq!(message, expected);
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_route_config_scope_and_table() {
+    let message = RouteConfig::new(
+        Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1).into(),
+        128,
+    )
+    .local()
+    .oif(1)
+    .build()
+    .unwrap();
+    assert_eq!(message.header.table, RouteHeader::RT_TABLE_LOCAL);
+    assert_eq!(message.header.scope, RouteScope::Host);
+    assert_eq!(message.header.kind, RouteType::Local);
+
+    let message = RouteConfig::new(Ipv6Addr::UNSPECIFIED.into(), 0)
+        .preference(RoutePreference::High)
+        .oif(1)
+        .build()
+        .unwrap();
+    assert_eq!(message.header.table, RouteHeader::RT_TABLE_MAIN);
+    assert_eq!(message.header.scope, RouteScope::Link);
+    assert_eq!(
+        message.attributes,
+        vec![
+            RouteAttribute::Oif(1),
+            RouteAttribute::Preference(RoutePreference::High),
+        ]
+    );
+
+    let message = RouteConfig::new(Ipv4Addr::new(192, 0, 2, 0).into(), 24)
+        .blackhole()
+        .build()
+        .unwrap();
+    assert_eq!(message.header.scope, RouteScope::Universe);
+    assert_eq!(message.header.kind, RouteType::BlackHole);
+}
+
+#[test]
+fn test_route_config_invalid() {
+    assert!(RouteConfig::new(Ipv4Addr::new(192, 0, 2, 0).into(), 33)
+        .build()
+        .is_err());
+    assert!(RouteConfig::new(Ipv4Addr::new(192, 0, 2, 1).into(), 24)
+        .build()
+        .is_err());
+    assert!(RouteConfig::new(Ipv4Addr::new(192, 0, 2, 0).into(), 24)
+        .unreachable()
+        .oif(1)
+        .build()
+        .is_err());
+    assert!(RouteConfig::new(Ipv4Addr::new(192, 0, 2, 0).into(), 24)
+        .gateway(Ipv6Addr::LOCALHOST.into())
+        .build()
+        .is_err());
+    assert!(RouteConfig::new(Ipv4Addr::new(192, 0, 2, 0).into(), 24)
+        .expires(10)
+        .build()
+        .is_err());
+}
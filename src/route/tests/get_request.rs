@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::{
+    RouteAttribute, RouteFlag, RouteGetRequest, RouteHeader, RouteMessage,
+    RouteMessageBuffer, RouteProtocol, RouteScope, RouteType,
+};
+use crate::{AddressFamily, IpProtocol};
+
+// Equivalent to command:
+//      ip route get 192.0.2.1 from 198.51.100.1 ipproto tcp sport 1234 \
+//          dport 80 mark 0x10 uid 1000 oif eth1 fibmatch
+#[test]
+fn test_route_get_request_flow() {
+    let raw = vec![
+        0x02, 0x20, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0xc6, 0x33, 0x64, 0x01, 0x08, 0x00, 0x01, 0x00,
+        0xc0, 0x00, 0x02, 0x01, 0x08, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x10, 0x00, 0x10, 0x00, 0x00, 0x00, 0x08, 0x00, 0x19, 0x00,
+        0xe8, 0x03, 0x00, 0x00, 0x05, 0x00, 0x1b, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x1c, 0x00, 0x04, 0xd2, 0x00, 0x00, 0x06, 0x00, 0x1d, 0x00,
+        0x00, 0x50, 0x00, 0x00,
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 32,
+            source_prefix_length: 32,
+            tos: 0,
+            table: RouteHeader::RT_TABLE_UNSPEC,
+            protocol: RouteProtocol::Unspec,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unspec,
+            flags: vec![RouteFlag::FibMatch],
+        },
+        attributes: vec![
+            RouteAttribute::Source(Ipv4Addr::new(198, 51, 100, 1).into()),
+            RouteAttribute::Destination(Ipv4Addr::new(192, 0, 2, 1).into()),
+            RouteAttribute::Oif(2),
+            RouteAttribute::Mark(0x10),
+            RouteAttribute::Uid(1000),
+            RouteAttribute::IpProtocol(IpProtocol::Tcp),
+            RouteAttribute::SourcePort(1234),
+            RouteAttribute::DestinationPort(80),
+        ],
+    };
+
+    let request = RouteGetRequest::new(Ipv4Addr::new(192, 0, 2, 1).into())
+        .source(Ipv4Addr::new(198, 51, 100, 1).into())
+        .ip_protocol(IpProtocol::Tcp)
+        .source_port(1234)
+        .destination_port(80)
+        .mark(0x10)
+        .uid(1000)
+        .oif(2)
+        .fib_match(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(request, expected);
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_route_get_request_mixed_address_family() {
+    let request = RouteGetRequest::new(IpAddr::V6(Ipv6Addr::LOCALHOST))
+        .source(IpAddr::V4(Ipv4Addr::LOCALHOST))
+        .lookup_table(true);
+
+    assert!(request.build().is_err());
+}
+
+#[test]
+fn test_route_get_request_unsupported_ip_protocol() {
+    let v4 = RouteGetRequest::new(IpAddr::V4(Ipv4Addr::LOCALHOST));
+    let v6 = RouteGetRequest::new(IpAddr::V6(Ipv6Addr::LOCALHOST));
+
+    assert!(v4.clone().ip_protocol(IpProtocol::Icmp).build().is_ok());
+    assert!(v6
+        .clone()
+        .ip_protocol(IpProtocol::Other(58))
+        .build()
+        .is_ok());
+    assert!(v6.clone().ip_protocol(IpProtocol::Udp).build().is_ok());
+
+    assert!(v6.clone().ip_protocol(IpProtocol::Icmp).build().is_err());
+    assert!(v4
+        .clone()
+        .ip_protocol(IpProtocol::Other(58))
+        .build()
+        .is_err());
+    assert!(v4.clone().ip_protocol(IpProtocol::Sctp).build().is_err());
+    assert!(v4.ip_protocol(IpProtocol::Mptcp).build().is_err());
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, ParseableParametrized},
+};
+
+use crate::route::{
+    IlaChecksumMode, IlaHookType, IlaIdentifierType, RouteAttribute, RouteIla,
+    RouteLwEnCapType, RouteLwTunnelEncap, RouteType,
+};
+use crate::AddressFamily;
+
+#[test]
+fn test_ila_encap() {
+    let raw = vec![
+        0x28, 0x00, 0x16, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x01, 0x00, 0x02, 0x05, 0x00, 0x07, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteAttribute::Encap(vec![
+        RouteLwTunnelEncap::Ila(RouteIla::Locator(0x20010db800010002)),
+        RouteLwTunnelEncap::Ila(RouteIla::ChecksumMode(
+            IlaChecksumMode::NeutralMapAuto,
+        )),
+        RouteLwTunnelEncap::Ila(RouteIla::IdentifierType(
+            IlaIdentifierType::Iid,
+        )),
+        RouteLwTunnelEncap::Ila(RouteIla::HookType(IlaHookType::RouteInput)),
+    ]);
+
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet6,
+                RouteType::Unicast,
+                RouteLwEnCapType::Ila
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, ParseableParametrized},
+};
+
+use crate::route::{
+    Ioam6Mode, Ioam6Trace, Ioam6TraceType, RouteAttribute, RouteIoam6IpTunnel,
+    RouteLwEnCapType, RouteLwTunnelEncap, RouteType,
+};
+use crate::AddressFamily;
+
+#[test]
+fn test_ioam6_encap() {
+    let raw = vec![
+        0x50, 0x00, 0x16, 0x00, 0x05, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x03, 0x00, 0x00, 0x7b, 0x00, 0x0c, 0xe0, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x14, 0x00, 0x06, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let expected = RouteAttribute::Encap(vec![
+        RouteLwTunnelEncap::Ioam6(RouteIoam6IpTunnel::Mode(Ioam6Mode::Encap)),
+        RouteLwTunnelEncap::Ioam6(RouteIoam6IpTunnel::Trace(Ioam6Trace::new(
+            123,
+            &[
+                Ioam6TraceType::HopLimitNodeId,
+                Ioam6TraceType::InterfaceIds,
+                Ioam6TraceType::TimestampSeconds,
+            ],
+            12,
+        ))),
+        RouteLwTunnelEncap::Ioam6(RouteIoam6IpTunnel::FrequencyK(1)),
+        RouteLwTunnelEncap::Ioam6(RouteIoam6IpTunnel::FrequencyN(10)),
+        RouteLwTunnelEncap::Ioam6(RouteIoam6IpTunnel::Destination(
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2),
+        )),
+        RouteLwTunnelEncap::Ioam6(RouteIoam6IpTunnel::Source(Ipv6Addr::new(
+            0x2001, 0xdb8, 0, 0, 0, 0, 0, 1,
+        ))),
+    ]);
+
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet6,
+                RouteType::Unicast,
+                RouteLwEnCapType::Ioam6
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
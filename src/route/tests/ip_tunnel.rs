@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, Parseable, ParseableParametrized},
+};
+
+use crate::route::{
+    RouteAttribute, RouteHeader, RouteIpTunnel, RouteIpTunnelFlag,
+    RouteIpTunnelGeneveOption, RouteIpTunnelOption, RouteIpTunnelVxlanOption,
+    RouteLwEnCapType, RouteLwTunnelEncap, RouteMessage, RouteMessageBuffer,
+    RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+// Route equivalent to:
+//      ip link add vxlan0 type vxlan external gbp
+//      ip route add 198.51.100.0/24 encap ip id 100 dst 192.0.2.2 \
+//          src 192.0.2.1 ttl 64 key dev vxlan0
+// with VXLAN GBP option 0x200.
+#[test]
+fn test_ip_tunnel_vxlan_route() {
+    let raw = vec![
+        0x02, 0x18, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0xc6, 0x33, 0x64, 0x00, 0x08, 0x00, 0x04, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x48, 0x00, 0x16, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x64, 0x08, 0x00, 0x02, 0x00, 0xc0, 0x00, 0x02, 0x02,
+        0x08, 0x00, 0x03, 0x00, 0xc0, 0x00, 0x02, 0x01, 0x05, 0x00, 0x04, 0x00,
+        0x40, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x06, 0x00, 0x00, 0x04, 0x00, 0x00, 0x10, 0x00, 0x08, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x06, 0x00, 0x15, 0x00, 0x02, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 24,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: vec![],
+        },
+        attributes: vec![
+            RouteAttribute::Table(254),
+            RouteAttribute::Destination(
+                Ipv4Addr::from_str("198.51.100.0").unwrap().into(),
+            ),
+            RouteAttribute::Oif(5),
+            RouteAttribute::Encap(vec![
+                RouteLwTunnelEncap::Ip(RouteIpTunnel::Id(100)),
+                RouteLwTunnelEncap::Ip(RouteIpTunnel::Destination(
+                    Ipv4Addr::from_str("192.0.2.2").unwrap().into(),
+                )),
+                RouteLwTunnelEncap::Ip(RouteIpTunnel::Source(
+                    Ipv4Addr::from_str("192.0.2.1").unwrap().into(),
+                )),
+                RouteLwTunnelEncap::Ip(RouteIpTunnel::Ttl(64)),
+                RouteLwTunnelEncap::Ip(RouteIpTunnel::Tos(0)),
+                RouteLwTunnelEncap::Ip(RouteIpTunnel::Flags(vec![
+                    RouteIpTunnelFlag::Key,
+                ])),
+                RouteLwTunnelEncap::Ip(RouteIpTunnel::Options(vec![
+                    RouteIpTunnelOption::Vxlan(vec![
+                        RouteIpTunnelVxlanOption::Gbp(0x200),
+                    ]),
+                ])),
+            ]),
+            RouteAttribute::EncapType(RouteLwEnCapType::Ip),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_ip6_tunnel_geneve_options() {
+    let raw = vec![
+        0x6c, 0x00, 0x16, 0x00, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x12, 0x34, 0x14, 0x00, 0x02, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x06, 0x00, 0x06, 0x00, 0x00, 0x05, 0x00, 0x00, 0x40, 0x00, 0x08, 0x00,
+        0x1c, 0x00, 0x01, 0x00, 0x06, 0x00, 0x01, 0x00, 0x01, 0x02, 0x00, 0x00,
+        0x05, 0x00, 0x02, 0x00, 0x80, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x01, 0x02, 0x03, 0x04, 0x20, 0x00, 0x01, 0x00, 0x06, 0x00, 0x01, 0x00,
+        0xff, 0xff, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x03, 0x00, 0xaa, 0xbb, 0xcc, 0xdd, 0x00, 0x11, 0x22, 0x33,
+    ];
+
+    let expected = RouteAttribute::Encap(vec![
+        RouteLwTunnelEncap::Ip6(RouteIpTunnel::Id(0x1234)),
+        RouteLwTunnelEncap::Ip6(RouteIpTunnel::Destination(
+            Ipv6Addr::from_str("2001:db8::2").unwrap().into(),
+        )),
+        RouteLwTunnelEncap::Ip6(RouteIpTunnel::Flags(vec![
+            RouteIpTunnelFlag::Csum,
+            RouteIpTunnelFlag::Key,
+        ])),
+        RouteLwTunnelEncap::Ip6(RouteIpTunnel::Options(vec![
+            RouteIpTunnelOption::Geneve(vec![
+                RouteIpTunnelGeneveOption::Class(0x0102),
+                RouteIpTunnelGeneveOption::Type(0x80),
+                RouteIpTunnelGeneveOption::Data(vec![0x01, 0x02, 0x03, 0x04]),
+            ]),
+            RouteIpTunnelOption::Geneve(vec![
+                RouteIpTunnelGeneveOption::Class(0xffff),
+                RouteIpTunnelGeneveOption::Type(0x01),
+                RouteIpTunnelGeneveOption::Data(vec![
+                    0xaa, 0xbb, 0xcc, 0xdd, 0x00, 0x11, 0x22, 0x33,
+                ]),
+            ]),
+        ])),
+    ]);
+
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet6,
+                RouteType::Unicast,
+                RouteLwEnCapType::Ip6
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
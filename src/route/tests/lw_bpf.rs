@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, ParseableParametrized},
+};
+
+use crate::route::{
+    RouteAttribute, RouteLwBpf, RouteLwBpfProgram, RouteLwEnCapType,
+    RouteLwTunnelEncap, RouteType,
+};
+use crate::AddressFamily;
+
+#[test]
+fn test_lw_bpf_encap() {
+    let raw = vec![
+        0x38, 0x00, 0x16, 0x00, 0x18, 0x00, 0x01, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x02, 0x00, 0x6c, 0x77, 0x74, 0x5f,
+        0x69, 0x6e, 0x00, 0x00, 0x14, 0x00, 0x03, 0x00, 0x0d, 0x00, 0x02, 0x00,
+        0x6c, 0x77, 0x74, 0x5f, 0x78, 0x6d, 0x69, 0x74, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x0e, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteAttribute::Encap(vec![
+        RouteLwTunnelEncap::Bpf(RouteLwBpf::In(vec![
+            RouteLwBpfProgram::Fd(7),
+            RouteLwBpfProgram::Name("lwt_in".to_string()),
+        ])),
+        RouteLwTunnelEncap::Bpf(RouteLwBpf::Xmit(vec![
+            RouteLwBpfProgram::Name("lwt_xmit".to_string()),
+        ])),
+        RouteLwTunnelEncap::Bpf(RouteLwBpf::XmitHeadroom(14)),
+    ]);
+
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet,
+                RouteType::Unicast,
+                RouteLwEnCapType::Bpf
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
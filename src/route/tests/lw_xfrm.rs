@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, ParseableParametrized},
+};
+
+use crate::route::{
+    RouteAttribute, RouteLwEnCapType, RouteLwTunnelEncap, RouteLwXfrm,
+    RouteType,
+};
+use crate::AddressFamily;
+
+#[test]
+fn test_lw_xfrm_encap() {
+    let raw = vec![
+        0x14, 0x00, 0x16, 0x00, 0x08, 0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteAttribute::Encap(vec![
+        RouteLwTunnelEncap::Xfrm(RouteLwXfrm::IfId(10)),
+        RouteLwTunnelEncap::Xfrm(RouteLwXfrm::Link(3)),
+    ]);
+
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet,
+                RouteType::Unicast,
+                RouteLwEnCapType::Xfrm
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, ParseableParametrized},
+};
+
+use crate::route::{
+    RouteAttribute, RouteLwEnCapType, RouteMetric, RouteMetricFeature,
+    RouteMetricKind, RouteType,
+};
+use crate::AddressFamily;
+
+// RTA_METRICS of command:
+//      ip route add 192.0.2.0/24 dev eth1 mtu lock 1400 cwnd lock 10 \
+//          features ecn congctl bbr
+// with RTAX_FEATURE_TCP_USEC_TS also set.
+#[test]
+fn test_route_metrics_lock_features_cc_algo() {
+    let raw = vec![
+        0x2c, 0x00, 0x08, 0x00, 0x08, 0x00, 0x01, 0x00, 0x84, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x78, 0x05, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0x11, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x10, 0x00, 0x62, 0x62, 0x72, 0x00,
+    ];
+
+    let expected = RouteAttribute::Metrics(vec![
+        RouteMetric::Lock(vec![RouteMetricKind::Mtu, RouteMetricKind::Cwnd]),
+        RouteMetric::Mtu(1400),
+        RouteMetric::Cwnd(10),
+        RouteMetric::Features(vec![
+            RouteMetricFeature::Ecn,
+            RouteMetricFeature::TcpUsecTs,
+        ]),
+        RouteMetric::CcAlgo("bbr".to_string()),
+    ]);
+
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet,
+                RouteType::Unicast,
+                RouteLwEnCapType::None
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_route_metrics_lock_reject_unrepresentable_kind() {
+    assert_eq!(
+        RouteMetric::lock(vec![RouteMetricKind::Mtu, RouteMetricKind::Cwnd])
+            .unwrap(),
+        RouteMetric::Lock(vec![RouteMetricKind::Mtu, RouteMetricKind::Cwnd])
+    );
+    assert!(RouteMetric::lock(vec![RouteMetricKind::Other(31)]).is_ok());
+    assert!(RouteMetric::lock(vec![
+        RouteMetricKind::Mtu,
+        RouteMetricKind::Other(32)
+    ])
+    .is_err());
+
+    // Kinds not fitting in the bitmask are dropped when emitting
+    let metric = RouteMetric::Lock(vec![
+        RouteMetricKind::Mtu,
+        RouteMetricKind::Other(40),
+    ]);
+    let mut buf = vec![0; metric.buffer_len()];
+    metric.emit(&mut buf);
+    assert_eq!(buf, vec![0x08, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00]);
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::{
+    RouteAddress, RouteAttribute, RouteFlag, RouteHeader, RouteMessage,
+    RouteMessageBuffer, RouteMfcEntry, RouteMfcOif, RouteMfcStats,
+    RouteNextHop, RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      smcroute -d
+//      smcroute -a eth1 192.0.2.1 239.1.1.1 eth2 eth3
+//      (eth1 index 2, eth2 index 3 with TTL threshold 1,
+//       eth3 index 4 with TTL threshold 64)
+// Equivalent to `RTM_NEWROUTE` dumped by command:
+//      ip mroute show
+#[test]
+fn test_ipmr_route() {
+    let raw = vec![
+        0x80, 0x20, 0x20, 0x00, 0xfd, 0x11, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfd, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0xc0, 0x00, 0x02, 0x01, 0x08, 0x00, 0x01, 0x00, 0xef, 0x01, 0x01, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, 0x14, 0x00, 0x09, 0x00,
+        0x08, 0x00, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x40,
+        0x04, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x11, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xe8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x17, 0x00,
+        0xfa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let stats = RouteMfcStats {
+        packets: 10,
+        bytes: 1000,
+        wrong_if: 0,
+    };
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Ipmr,
+            destination_prefix_length: 32,
+            source_prefix_length: 32,
+            tos: 0,
+            table: RouteHeader::RT_TABLE_DEFAULT,
+            protocol: RouteProtocol::Mrouted,
+            scope: RouteScope::Universe,
+            kind: RouteType::Multicast,
+            flags: vec![],
+        },
+        attributes: vec![
+            RouteAttribute::Table(253),
+            RouteAttribute::Source(RouteAddress::Inet(Ipv4Addr::new(
+                192, 0, 2, 1,
+            ))),
+            RouteAttribute::Destination(RouteAddress::Inet(Ipv4Addr::new(
+                239, 1, 1, 1,
+            ))),
+            RouteAttribute::Iif(2),
+            RouteAttribute::MultiPath(vec![
+                RouteMfcOif::new(3, 1).into(),
+                RouteMfcOif::new(4, 64).into(),
+            ]),
+            RouteAttribute::MfcStats(stats),
+            RouteAttribute::MulticastExpires(250),
+        ],
+    };
+
+    let parsed = RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(expected, parsed);
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+
+    assert_eq!(
+        parsed.mfc_entry(),
+        Some(RouteMfcEntry {
+            origin: Ipv4Addr::new(192, 0, 2, 1).into(),
+            group: Ipv4Addr::new(239, 1, 1, 1).into(),
+            table: 253,
+            iif: Some(2),
+            oifs: vec![
+                RouteMfcOif {
+                    interface_index: 3,
+                    ttl_threshold: 1,
+                },
+                RouteMfcOif {
+                    interface_index: 4,
+                    ttl_threshold: 64,
+                },
+            ],
+            unresolved: false,
+            stats: Some(stats),
+            last_use: Some(250),
+        })
+    );
+}
+
+// Unresolved (*, ff0e::1) entry waiting for multicast routing daemon, the
+// kernel does not include incoming/outgoing interfaces and statistics.
+#[test]
+fn test_ip6mr_unresolved_route() {
+    let raw = vec![
+        0x81, 0x80, 0x80, 0x00, 0xfd, 0x11, 0x00, 0x05, 0x20, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfd, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00, 0xff, 0x0e, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Ip6mr,
+            destination_prefix_length: 128,
+            source_prefix_length: 128,
+            tos: 0,
+            table: RouteHeader::RT_TABLE_DEFAULT,
+            protocol: RouteProtocol::Mrouted,
+            scope: RouteScope::Universe,
+            kind: RouteType::Multicast,
+            flags: vec![RouteFlag::Unresolved],
+        },
+        attributes: vec![
+            RouteAttribute::Table(253),
+            RouteAttribute::Source(RouteAddress::Inet6(Ipv6Addr::UNSPECIFIED)),
+            RouteAttribute::Destination(RouteAddress::Inet6(Ipv6Addr::new(
+                0xff0e, 0, 0, 0, 0, 0, 0, 1,
+            ))),
+        ],
+    };
+
+    let parsed = RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap();
+    assert_eq!(expected, parsed);
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+
+    let entry = parsed.mfc_entry().unwrap();
+    assert!(entry.unresolved);
+    assert_eq!(entry.origin, Ipv6Addr::UNSPECIFIED);
+    assert_eq!(entry.iif, None);
+    assert!(entry.oifs.is_empty());
+    assert_eq!(entry.stats, None);
+}
+
+#[test]
+fn test_mfc_entry_non_multicast_route() {
+    let mut message = RouteMessage::default();
+    message.header.address_family = AddressFamily::Inet;
+    assert_eq!(message.mfc_entry(), None);
+
+    let nh = RouteNextHop::from(RouteMfcOif::new(5, 2));
+    assert_eq!(nh.hops, 2);
+    assert_eq!(nh.interface_index, 5);
+}
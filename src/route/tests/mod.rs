@@ -3,18 +3,44 @@
 #[cfg(test)]
 mod cache_info;
 #[cfg(test)]
+mod config;
+#[cfg(test)]
 mod expires;
 #[cfg(test)]
+mod get_request;
+#[cfg(test)]
+mod ila;
+#[cfg(test)]
+mod ioam6;
+#[cfg(test)]
+mod ip_tunnel;
+#[cfg(test)]
 mod loopback;
 #[cfg(test)]
+mod lw_bpf;
+#[cfg(test)]
+mod lw_xfrm;
+#[cfg(test)]
+mod metrics;
+#[cfg(test)]
+mod mfc;
+#[cfg(test)]
 mod mpls;
 #[cfg(test)]
 mod multipath;
 #[cfg(test)]
+mod prefix;
+#[cfg(test)]
 mod realm;
 #[cfg(test)]
 mod route_flags;
 #[cfg(test)]
+mod rpl;
+#[cfg(test)]
+mod seg6;
+#[cfg(test)]
+mod seg6_local;
+#[cfg(test)]
 mod uid;
 #[cfg(test)]
 mod via;
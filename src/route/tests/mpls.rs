@@ -6,10 +6,10 @@ use std::str::FromStr;
 use netlink_packet_utils::traits::{Emitable, Parseable};
 
 use crate::route::{
-    MplsLabel, RouteAddress, RouteAttribute, RouteCacheInfo, RouteHeader,
-    RouteLwEnCapType, RouteLwTunnelEncap, RouteMessage, RouteMessageBuffer,
-    RouteMplsIpTunnel, RouteMplsTtlPropagation, RoutePreference, RouteProtocol,
-    RouteScope, RouteType,
+    MplsLabel, MplsLabelStack, RouteAddress, RouteAttribute, RouteCacheInfo,
+    RouteHeader, RouteLwEnCapType, RouteLwTunnelEncap, RouteMessage,
+    RouteMessageBuffer, RouteMplsIpTunnel, RouteMplsTtlPropagation,
+    RouteNextHopConfig, RoutePreference, RouteProtocol, RouteScope, RouteType,
 };
 use crate::AddressFamily;
 
@@ -351,3 +351,129 @@ fn test_mpls_ttl_propagate() {
 
     assert_eq!(buf, raw);
 }
+
+#[test]
+fn test_mpls_label_stack_from_str() {
+    let stack = MplsLabelStack::from_str("100/200/300").unwrap();
+
+    assert_eq!(stack.labels(), &[100, 200, 300]);
+    assert_eq!(stack.to_string(), "100/200/300");
+    assert_eq!(
+        stack.to_labels(),
+        vec![
+            MplsLabel::from(0x00064000),
+            MplsLabel::from(0x000c8000),
+            MplsLabel::from(0x0012c100),
+        ]
+    );
+    assert_eq!(
+        MplsLabelStack::from_labels(&stack.to_labels()).unwrap(),
+        stack
+    );
+}
+
+#[test]
+fn test_mpls_label_stack_invalid() {
+    assert!(MplsLabelStack::from_str("").is_err());
+    assert!(MplsLabelStack::from_str("100/abc").is_err());
+    assert!(MplsLabelStack::from_str("100/1048576").is_err());
+    assert!(MplsLabelStack::from_str("100/3").is_err());
+    assert!(MplsLabelStack::new(&[16; 31]).is_err());
+    // Explicit NULL is allowed to be pushed
+    assert!(MplsLabelStack::from_str("100/0").is_ok());
+
+    // Bottom of stack bit only on last label, TTL and traffic class zeroed
+    assert!(MplsLabelStack::from_labels(&[
+        MplsLabel::from(0x00064100),
+        MplsLabel::from(0x000c8100),
+    ])
+    .is_err());
+    assert!(
+        MplsLabelStack::from_labels(&[MplsLabel::from(0x00064140)]).is_err()
+    );
+    assert!(
+        MplsLabelStack::from_labels(&[MplsLabel::from(0x00064300)]).is_err()
+    );
+}
+
+#[test]
+fn test_mpls_route_destination_reserved() {
+    assert!(MplsLabel::route_destination(15).is_err());
+    assert!(MplsLabel::route_destination(1 << 20).is_err());
+    let label = MplsLabel::route_destination(16).unwrap();
+    assert!(!label.is_reserved());
+    assert!(label.bottom_of_stack);
+    assert!(MplsLabel::from(0x00003100).is_reserved());
+}
+
+// Same as `test_mpls_route_relable_new_dst()` but generated with
+// `MplsLabelStack` and `RouteNextHopConfig`:
+//      ip -f mpls route add 100 as 200 via inet6 2001:db8:1::2 dev dummy1
+#[test]
+fn test_mpls_route_relabel_by_label_stack() {
+    let raw = vec![
+        0x1c, 0x14, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x06, 0x41, 0x00, 0x08, 0x00, 0x13, 0x00,
+        0x00, 0x0c, 0x81, 0x00, 0x16, 0x00, 0x12, 0x00, 0x0a, 0x00, 0x20, 0x01,
+        0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x09, 0x00, 0x00, 0x00,
+    ];
+
+    let next_hop = RouteNextHopConfig::new()
+        .new_destination(MplsLabelStack::from_str("200").unwrap())
+        .via(Ipv6Addr::from_str("2001:db8:1::2").unwrap().into())
+        .interface(9)
+        .build(AddressFamily::Mpls)
+        .unwrap();
+
+    let mut message = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Mpls,
+            destination_prefix_length: 20,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: vec![],
+        },
+        attributes: Vec::new(),
+    };
+    message
+        .attributes
+        .push(RouteAttribute::Destination(RouteAddress::Mpls(
+            MplsLabel::route_destination(100).unwrap(),
+        )));
+    message.attributes.extend(next_hop.attributes);
+    message
+        .attributes
+        .push(RouteAttribute::Oif(next_hop.interface_index));
+
+    let mut buf = vec![0; message.buffer_len()];
+
+    message.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_mpls_label_stack_encap() {
+    let stack = MplsLabelStack::new(&[100, 200]).unwrap();
+
+    assert_eq!(
+        stack.encap(Some(25)),
+        vec![
+            RouteMplsIpTunnel::Destination(vec![
+                MplsLabel::from(0x00064000),
+                MplsLabel::from(0x000c8100),
+            ]),
+            RouteMplsIpTunnel::Ttl(25),
+        ]
+    );
+    assert!(RouteNextHopConfig::new()
+        .interface(1)
+        .new_destination(stack)
+        .build(AddressFamily::Inet)
+        .is_err());
+}
@@ -1 +1,122 @@
 // SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, ParseableParametrized},
+};
+
+use crate::route::{
+    MplsLabel, MplsLabelStack, RouteAttribute, RouteLwEnCapType,
+    RouteLwTunnelEncap, RouteMplsIpTunnel, RouteNextHopConfig, RouteType,
+    RouteVia,
+};
+use crate::AddressFamily;
+
+// RTA_MULTIPATH of command:
+//      ip route add 198.51.100.0/24 \
+//          nexthop via 192.0.2.1 dev eth1 weight 1 \
+//          nexthop via inet6 2001:db8::1 dev eth2 weight 10 onlink \
+//              encap mpls 100
+#[test]
+fn test_route_multipath_builder() {
+    let raw = vec![
+        0x48, 0x00, 0x09, 0x00, 0x10, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0xc0, 0x00, 0x02, 0x01, 0x34, 0x00, 0x04, 0x09,
+        0x03, 0x00, 0x00, 0x00, 0x16, 0x00, 0x12, 0x00, 0x0a, 0x00, 0x20, 0x01,
+        0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x0c, 0x00, 0x16, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x06, 0x41, 0x00, 0x06, 0x00, 0x15, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteNextHopConfig::multipath(
+        AddressFamily::Inet,
+        &[
+            RouteNextHopConfig::new()
+                .gateway(Ipv4Addr::new(192, 0, 2, 1).into())
+                .interface(2),
+            RouteNextHopConfig::new()
+                .via(RouteVia::Inet6(Ipv6Addr::new(
+                    0x2001, 0xdb8, 0, 0, 0, 0, 0, 1,
+                )))
+                .interface(3)
+                .weight(10)
+                .onlink()
+                .mpls_encap(&MplsLabelStack::new(&[100]).unwrap(), None),
+        ],
+    )
+    .unwrap();
+
+    // The encap type of each next-hop is stored in itself, not in route
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet,
+                RouteType::Unicast,
+                RouteLwEnCapType::None
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_route_multipath_mpls_encap_label_stack() {
+    let labels: MplsLabelStack = "100/200".parse().unwrap();
+    let config = RouteNextHopConfig::new()
+        .gateway(Ipv4Addr::new(192, 0, 2, 1).into())
+        .mpls_encap(&labels, Some(10));
+
+    assert_eq!(
+        config.encap,
+        Some((
+            RouteLwEnCapType::Mpls,
+            vec![
+                RouteLwTunnelEncap::Mpls(RouteMplsIpTunnel::Destination(vec![
+                    MplsLabel {
+                        label: 100,
+                        traffic_class: 0,
+                        bottom_of_stack: false,
+                        ttl: 0,
+                    },
+                    MplsLabel {
+                        label: 200,
+                        traffic_class: 0,
+                        bottom_of_stack: true,
+                        ttl: 0,
+                    },
+                ])),
+                RouteLwTunnelEncap::Mpls(RouteMplsIpTunnel::Ttl(10)),
+            ]
+        ))
+    );
+}
+
+#[test]
+fn test_route_multipath_builder_invalid() {
+    // IPv6 gateway for IPv4 route should use via
+    assert!(RouteNextHopConfig::new()
+        .gateway(Ipv6Addr::LOCALHOST.into())
+        .build(AddressFamily::Inet)
+        .is_err());
+    // IPv6 route does not support RTA_VIA
+    assert!(RouteNextHopConfig::new()
+        .via(RouteVia::Inet(Ipv4Addr::LOCALHOST))
+        .build(AddressFamily::Inet6)
+        .is_err());
+    assert!(RouteNextHopConfig::new()
+        .interface(1)
+        .weight(257)
+        .build(AddressFamily::Inet)
+        .is_err());
+    assert!(RouteNextHopConfig::multipath(AddressFamily::Inet, &[]).is_err());
+}
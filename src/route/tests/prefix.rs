@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use crate::route::{RouteAttribute, RouteMessage};
+use crate::{AddressFamily, IpPrefix};
+
+#[test]
+fn test_ip_prefix_parse() {
+    let prefix = IpPrefix::from_str("10.0.0.0/8").unwrap();
+    assert_eq!(prefix.address(), IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)));
+    assert_eq!(prefix.prefix_length(), 8);
+    assert_eq!(prefix.to_string(), "10.0.0.0/8");
+
+    assert_eq!(
+        IpPrefix::from_str("2001:db8::1").unwrap().prefix_length(),
+        128
+    );
+
+    // Host bits set
+    assert!(IpPrefix::from_str("10.0.0.1/8").is_err());
+    assert!(IpPrefix::from_str("2001:db8::1/64").is_err());
+    // Prefix length too big
+    assert!(IpPrefix::from_str("10.0.0.0/33").is_err());
+    assert!(IpPrefix::from_str("10.0.0.0/abc").is_err());
+    assert!(IpPrefix::from_str("10.0.0/8").is_err());
+
+    assert_eq!(
+        IpPrefix::new_truncate(Ipv4Addr::new(192, 0, 2, 1).into(), 24)
+            .unwrap()
+            .to_string(),
+        "192.0.2.0/24"
+    );
+}
+
+#[test]
+fn test_ip_prefix_contains() {
+    let prefix = IpPrefix::from_str("10.0.0.0/8").unwrap();
+    assert!(prefix.contains_addr(&Ipv4Addr::new(10, 1, 2, 3).into()));
+    assert!(!prefix.contains_addr(&Ipv4Addr::new(11, 0, 0, 0).into()));
+    assert!(!prefix.contains_addr(&Ipv6Addr::UNSPECIFIED.into()));
+    assert!(prefix.contains(&IpPrefix::from_str("10.1.0.0/16").unwrap()));
+    assert!(prefix.contains(&prefix));
+    assert!(!prefix.contains(&IpPrefix::from_str("0.0.0.0/0").unwrap()));
+    assert!(IpPrefix::default_route(true)
+        .contains(&IpPrefix::from_str("2001:db8::/32").unwrap()));
+}
+
+#[test]
+fn test_route_message_prefix() {
+    let mut message = RouteMessage::default();
+    message.header.address_family = AddressFamily::Inet;
+    assert_eq!(
+        message.destination_prefix(),
+        Some(IpPrefix::default_route(false))
+    );
+    assert_eq!(message.source_prefix(), None);
+
+    let prefix = IpPrefix::from_str("2001:db8:1::/48").unwrap();
+    message.set_destination_prefix(prefix).unwrap();
+    message
+        .set_source_prefix(Some(IpPrefix::from_str("2001:db8:2::/64").unwrap()))
+        .unwrap();
+    assert_eq!(message.header.address_family, AddressFamily::Inet6);
+    assert_eq!(message.header.destination_prefix_length, 48);
+    assert_eq!(message.header.source_prefix_length, 64);
+    assert_eq!(
+        message.attributes,
+        vec![
+            RouteAttribute::Destination(
+                Ipv6Addr::new(0x2001, 0xdb8, 1, 0, 0, 0, 0, 0).into()
+            ),
+            RouteAttribute::Source(
+                Ipv6Addr::new(0x2001, 0xdb8, 2, 0, 0, 0, 0, 0).into()
+            ),
+        ]
+    );
+    assert_eq!(message.destination_prefix(), Some(prefix));
+
+    // Address family mismatch between source and destination
+    assert!(message
+        .set_source_prefix(Some(IpPrefix::from_str("192.0.2.0/24").unwrap()))
+        .is_err());
+    assert!(message
+        .set_destination_prefix(IpPrefix::from_str("192.0.2.0/24").unwrap())
+        .is_err());
+    assert_eq!(message.header.address_family, AddressFamily::Inet6);
+    assert_eq!(message.destination_prefix(), Some(prefix));
+
+    message.set_source_prefix(None).unwrap();
+    message
+        .set_destination_prefix(IpPrefix::default_route(true))
+        .unwrap();
+    assert_eq!(message.header.destination_prefix_length, 0);
+    assert_eq!(message.header.source_prefix_length, 0);
+    assert!(message.attributes.is_empty());
+}
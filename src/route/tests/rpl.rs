@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, ParseableParametrized},
+};
+
+use crate::route::{
+    RouteAttribute, RouteLwEnCapType, RouteLwTunnelEncap, RouteRplIpTunnel,
+    RouteType, RplHeader,
+};
+use crate::AddressFamily;
+
+#[test]
+fn test_rpl_encap() {
+    let raw = vec![
+        0x30, 0x00, 0x16, 0x00, 0x2c, 0x00, 0x01, 0x00, 0x00, 0x04, 0x03, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    ];
+
+    let expected = RouteAttribute::Encap(vec![RouteLwTunnelEncap::Rpl(
+        RouteRplIpTunnel::Srh(
+            RplHeader::new(&[
+                Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+                Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2),
+            ])
+            .unwrap(),
+        ),
+    )]);
+
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet6,
+                RouteType::Unicast,
+                RouteLwEnCapType::Rpl
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Kernel does not validate the Pad and Reserved bits
+#[test]
+fn test_rpl_encap_with_pad() {
+    let raw = vec![
+        0x20, 0x00, 0x16, 0x00, 0x1c, 0x00, 0x01, 0x00, 0x00, 0x02, 0x03, 0x01,
+        0x00, 0x30, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let mut srh =
+        RplHeader::new(&[Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)])
+            .unwrap();
+    srh.pad = 0x30;
+    let expected = RouteAttribute::Encap(vec![RouteLwTunnelEncap::Rpl(
+        RouteRplIpTunnel::Srh(srh),
+    )]);
+
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet6,
+                RouteType::Unicast,
+                RouteLwEnCapType::Rpl
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_rpl_header_too_many_segments() {
+    let segments = vec![Ipv6Addr::LOCALHOST; 128];
+    assert!(RplHeader::new(&segments[..127]).is_ok());
+    assert!(RplHeader::new(&segments).is_err());
+}
@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::{
+    RouteAttribute, RouteHeader, RouteLwEnCapType, RouteLwTunnelEncap,
+    RouteMessage, RouteMessageBuffer, RouteProtocol, RouteScope,
+    RouteSeg6IpTunnel, RouteType, Seg6Header, Seg6HeaderBuffer, Seg6HeaderFlag,
+    Seg6IpTunnelEncap, Seg6IpTunnelEncapBuffer, Seg6Mode, Seg6Tlv,
+};
+use crate::AddressFamily;
+
+// Route equivalent to:
+//      ip -6 route add 2001:db8:2::/64 encap seg6 mode encap \
+//          segs 2001:db8:a::1,2001:db8:b::1 hmac 1 dev dummy1
+#[test]
+fn test_seg6_encap_route() {
+    let raw = vec![
+        0x0a, 0x40, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x5c, 0x00, 0x16, 0x00, 0x58, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x09, 0x04, 0x01, 0x01, 0x08, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x05, 0x26, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x15, 0x00,
+        0x05, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet6,
+            destination_prefix_length: 64,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: vec![],
+        },
+        attributes: vec![
+            RouteAttribute::Table(254),
+            RouteAttribute::Destination(
+                Ipv6Addr::from_str("2001:db8:2::").unwrap().into(),
+            ),
+            RouteAttribute::Oif(2),
+            RouteAttribute::Encap(vec![RouteLwTunnelEncap::Seg6(
+                RouteSeg6IpTunnel::Srh(Seg6IpTunnelEncap::new(
+                    Seg6Mode::Encap,
+                    Seg6Header::new(&[
+                        Ipv6Addr::from_str("2001:db8:a::1").unwrap(),
+                        Ipv6Addr::from_str("2001:db8:b::1").unwrap(),
+                    ])
+                    .hmac(1),
+                )),
+            )]),
+            RouteAttribute::EncapType(RouteLwEnCapType::Seg6),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_seg6_inline_srh_with_tlvs() {
+    let raw = vec![
+        0x00, 0x00, 0x00, 0x00, 0x29, 0x03, 0x04, 0x00, 0x00, 0x61, 0x12, 0x34,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x04, 0x02, 0x00, 0x00, 0x80, 0x02, 0xab, 0xcd,
+    ];
+
+    let mut header =
+        Seg6Header::new(&[Ipv6Addr::from_str("2001:db8:c::1").unwrap()]);
+    header.next_header = 41;
+    header.tag = 0x1234;
+    header.flags = vec![
+        Seg6HeaderFlag::Protected,
+        Seg6HeaderFlag::Oam,
+        Seg6HeaderFlag::Other(1),
+    ];
+    header.tlvs =
+        vec![Seg6Tlv::Padding(2), Seg6Tlv::Other(0x80, vec![0xab, 0xcd])];
+    let expected = Seg6IpTunnelEncap::new(Seg6Mode::Inline, header);
+
+    assert_eq!(
+        expected,
+        Seg6IpTunnelEncap::parse(&Seg6IpTunnelEncapBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+
+    // Routing type other than 4 is not SRH
+    let mut raw_srh = raw[4..].to_vec();
+    raw_srh[2] = 3;
+    assert!(
+        Seg6Header::parse(&Seg6HeaderBuffer::new(&raw_srh.as_slice())).is_err()
+    );
+}
@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::{
+    nla::NlaBuffer,
+    traits::{Emitable, Parseable, ParseableParametrized},
+};
+
+use crate::route::{
+    RouteAttribute, RouteHeader, RouteLwEnCapType, RouteLwTunnelEncap,
+    RouteMessage, RouteMessageBuffer, RouteProtocol, RouteScope,
+    RouteSeg6LocalIpTunnel, RouteType, Seg6Header, Seg6LocalAction,
+    Seg6LocalBpf, Seg6LocalCounter, Seg6LocalFlavor, Seg6LocalFlavorOperation,
+};
+use crate::AddressFamily;
+
+// Route equivalent to:
+//      ip -6 route add 2001:db8:ff::1/128 encap seg6local action End.X \
+//          nh6 2001:db8:1::2 count flavors psp dev dummy1
+// with counters reported by kernel.
+#[test]
+fn test_seg6_local_end_x_route() {
+    let raw = vec![
+        0x0a, 0x80, 0x00, 0x00, 0xfe, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x08, 0x00, 0x04, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x54, 0x00, 0x16, 0x00, 0x08, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x05, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x28, 0x00, 0x0a, 0x80,
+        0x0c, 0x00, 0x02, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x03, 0x00, 0x10, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x0b, 0x80, 0x08, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x15, 0x00, 0x07, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet6,
+            destination_prefix_length: 128,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Boot,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: vec![],
+        },
+        attributes: vec![
+            RouteAttribute::Table(254),
+            RouteAttribute::Destination(
+                Ipv6Addr::from_str("2001:db8:ff::1").unwrap().into(),
+            ),
+            RouteAttribute::Oif(3),
+            RouteAttribute::Encap(vec![
+                RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::Action(
+                    Seg6LocalAction::EndX,
+                )),
+                RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::Nh6(
+                    Ipv6Addr::from_str("2001:db8:1::2").unwrap(),
+                )),
+                RouteLwTunnelEncap::Seg6Local(
+                    RouteSeg6LocalIpTunnel::Counters(vec![
+                        Seg6LocalCounter::Packets(10),
+                        Seg6LocalCounter::Bytes(1040),
+                        Seg6LocalCounter::Errors(0),
+                    ]),
+                ),
+                RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::Flavors(
+                    vec![Seg6LocalFlavor::Operation(vec![
+                        Seg6LocalFlavorOperation::Psp,
+                    ])],
+                )),
+            ]),
+            RouteAttribute::EncapType(RouteLwEnCapType::Seg6Local),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_seg6_local_bpf_srh_and_next_csid() {
+    let raw = vec![
+        0x64, 0x00, 0x16, 0x00, 0x08, 0x00, 0x01, 0x00, 0x0f, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x08, 0x00, 0x08, 0x00, 0x01, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x73, 0x72, 0x76, 0x36, 0x5f, 0x66, 0x6e, 0x00,
+        0x08, 0x00, 0x09, 0x00, 0x64, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x00,
+        0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x1c, 0x00, 0x0b, 0x80, 0x08, 0x00, 0x01, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x02, 0x00, 0x20, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x10, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteAttribute::Encap(vec![
+        RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::Action(
+            Seg6LocalAction::EndBpf,
+        )),
+        RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::Bpf(vec![
+            Seg6LocalBpf::Prog(12),
+            Seg6LocalBpf::Name("srv6_fn".to_string()),
+        ])),
+        RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::VrfTable(100)),
+        RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::Srh(
+            Seg6Header::new(&[Ipv6Addr::from_str("2001:db8:c::1").unwrap()]),
+        )),
+        RouteLwTunnelEncap::Seg6Local(RouteSeg6LocalIpTunnel::Flavors(vec![
+            Seg6LocalFlavor::Operation(vec![
+                Seg6LocalFlavorOperation::NextCsid,
+            ]),
+            Seg6LocalFlavor::LocatorBlockBits(32),
+            Seg6LocalFlavor::LocatorNodeFunctionBits(16),
+        ])),
+    ]);
+
+    assert_eq!(
+        expected,
+        RouteAttribute::parse_with_param(
+            &NlaBuffer::new(&raw),
+            (
+                AddressFamily::Inet6,
+                RouteType::Unicast,
+                RouteLwEnCapType::Seg6Local
+            )
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -6,7 +6,7 @@ use netlink_packet_utils::{
     DecodeError,
 };
 
-use super::{RuleAttribute, RuleHeader, RuleMessageBuffer};
+use super::{super::IpPrefix, RuleAttribute, RuleHeader, RuleMessageBuffer};
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
 #[non_exhaustive]
@@ -15,6 +15,107 @@ pub struct RuleMessage {
     pub attributes: Vec<RuleAttribute>,
 }
 
+impl RuleMessage {
+    /// Destination prefix from `RuleHeader::dst_len` and
+    /// `RuleAttribute::Destination`.
+    pub fn destination_prefix(&self) -> Option<IpPrefix> {
+        self.attributes.iter().find_map(|attr| {
+            if let RuleAttribute::Destination(v) = attr {
+                IpPrefix::new(*v, self.header.dst_len).ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Set address family, `RuleHeader::dst_len` and
+    /// `RuleAttribute::Destination`. Use `None` to match all destinations.
+    /// Return error if `RuleAttribute::Source` is in different address
+    /// family.
+    pub fn set_destination_prefix(
+        &mut self,
+        prefix: Option<IpPrefix>,
+    ) -> Result<(), DecodeError> {
+        if let Some(prefix) = prefix.as_ref() {
+            self.check_prefix_family(prefix, true)?;
+        }
+        self.attributes
+            .retain(|attr| !matches!(attr, RuleAttribute::Destination(_)));
+        if let Some(prefix) = prefix {
+            self.header.family = prefix.address_family();
+            self.header.dst_len = prefix.prefix_length();
+            self.attributes
+                .push(RuleAttribute::Destination(prefix.address()));
+        } else {
+            self.header.dst_len = 0;
+        }
+        Ok(())
+    }
+
+    /// Source prefix from `RuleHeader::src_len` and `RuleAttribute::Source`.
+    pub fn source_prefix(&self) -> Option<IpPrefix> {
+        self.attributes.iter().find_map(|attr| {
+            if let RuleAttribute::Source(v) = attr {
+                IpPrefix::new(*v, self.header.src_len).ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Set address family, `RuleHeader::src_len` and
+    /// `RuleAttribute::Source`. Use `None` to match all sources.
+    /// Return error if `RuleAttribute::Destination` is in different address
+    /// family.
+    pub fn set_source_prefix(
+        &mut self,
+        prefix: Option<IpPrefix>,
+    ) -> Result<(), DecodeError> {
+        if let Some(prefix) = prefix.as_ref() {
+            self.check_prefix_family(prefix, false)?;
+        }
+        self.attributes
+            .retain(|attr| !matches!(attr, RuleAttribute::Source(_)));
+        if let Some(prefix) = prefix {
+            self.header.family = prefix.address_family();
+            self.header.src_len = prefix.prefix_length();
+            self.attributes
+                .push(RuleAttribute::Source(prefix.address()));
+        } else {
+            self.header.src_len = 0;
+        }
+        Ok(())
+    }
+
+    // Check the address family against the other prefix attribute
+    fn check_prefix_family(
+        &self,
+        prefix: &IpPrefix,
+        is_destination: bool,
+    ) -> Result<(), DecodeError> {
+        let other = self.attributes.iter().find_map(|attr| match attr {
+            RuleAttribute::Source(v) if is_destination => Some(*v),
+            RuleAttribute::Destination(v) if !is_destination => Some(*v),
+            _ => None,
+        });
+        if let Some(other) = other {
+            if other.is_ipv4() != prefix.is_ipv4() {
+                return Err(format!(
+                    "Prefix {prefix} is not in the same address family as \
+                    {} {other}",
+                    if is_destination {
+                        "source"
+                    } else {
+                        "destination"
+                    }
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Emitable for RuleMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.attributes.as_slice().buffer_len()
@@ -10,7 +10,7 @@ use crate::{
     rule::{
         RuleAction, RuleAttribute, RuleHeader, RuleMessage, RuleMessageBuffer,
     },
-    AddressFamily,
+    AddressFamily, IpPrefix,
 };
 
 // Setup:
@@ -115,3 +115,40 @@ fn test_ipv6_src_dst_goto() {
 
     assert_eq!(buf, raw);
 }
+
+#[test]
+fn test_rule_message_prefix() {
+    let mut message = RuleMessage::default();
+    assert_eq!(message.destination_prefix(), None);
+
+    message
+        .set_source_prefix(Some(IpPrefix::from_str("192.0.2.0/24").unwrap()))
+        .unwrap();
+    message
+        .set_destination_prefix(Some(
+            IpPrefix::from_str("203.0.113.1/32").unwrap(),
+        ))
+        .unwrap();
+    assert_eq!(message.header.family, AddressFamily::Inet);
+    assert_eq!(message.header.src_len, 24);
+    assert_eq!(message.header.dst_len, 32);
+    assert_eq!(
+        message.attributes,
+        vec![
+            RuleAttribute::Source(Ipv4Addr::new(192, 0, 2, 0).into()),
+            RuleAttribute::Destination(Ipv4Addr::new(203, 0, 113, 1).into()),
+        ]
+    );
+    assert_eq!(
+        message.source_prefix(),
+        Some(IpPrefix::from_str("192.0.2.0/24").unwrap())
+    );
+
+    assert!(message
+        .set_source_prefix(Some(IpPrefix::from_str("2001:db8::/32").unwrap()))
+        .is_err());
+
+    message.set_source_prefix(None).unwrap();
+    assert_eq!(message.header.src_len, 0);
+    assert_eq!(message.source_prefix(), None);
+}